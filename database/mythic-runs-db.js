@@ -35,7 +35,7 @@ const DB_PATH = path.join(DB_DIR, 'mythic_runs.db');
 /**
  * Database schema version for migrations
  */
-const SCHEMA_VERSION = 6;
+const SCHEMA_VERSION = 9;
 
 /**
  * MythicRunsDatabase class
@@ -328,6 +328,79 @@ class MythicRunsDatabase {
 
             logger.info('Migration 5 -> 6 completed: Added beta_channel column');
         }
+
+        // Migration 6 -> 7: Add token_region column to bot_settings
+        if (fromVersion < 7) {
+            logger.info('Applying migration 6 -> 7: Adding token_region column');
+
+            // The desktop app may already have added this column
+            const hasTokenRegion = this.db.prepare(
+                "SELECT COUNT(*) AS count FROM pragma_table_info('bot_settings') WHERE name = 'token_region'"
+            ).get().count > 0;
+
+            if (!hasTokenRegion) {
+                this.db.exec(`
+                    -- Add token_region column to bot_settings
+                    ALTER TABLE bot_settings ADD COLUMN token_region TEXT NOT NULL DEFAULT 'us';
+                `);
+            }
+
+            // Record schema version
+            this.db.prepare(
+                'INSERT INTO schema_info (version, applied_at) VALUES (?, ?)'
+            ).run(7, Date.now());
+
+            logger.info('Migration 6 -> 7 completed: Added token_region column');
+        }
+
+        // Migration 7 -> 8: Add report_schedule column to bot_settings
+        if (fromVersion < 8) {
+            logger.info('Applying migration 7 -> 8: Adding report_schedule column');
+
+            // The desktop app may already have added this column
+            const hasReportSchedule = this.db.prepare(
+                "SELECT COUNT(*) AS count FROM pragma_table_info('bot_settings') WHERE name = 'report_schedule'"
+            ).get().count > 0;
+
+            if (!hasReportSchedule) {
+                this.db.exec(`
+                    -- Add report_schedule column to bot_settings (NULL = no scheduled reports)
+                    ALTER TABLE bot_settings ADD COLUMN report_schedule TEXT;
+                `);
+            }
+
+            // Record schema version
+            this.db.prepare(
+                'INSERT INTO schema_info (version, applied_at) VALUES (?, ?)'
+            ).run(8, Date.now());
+
+            logger.info('Migration 7 -> 8 completed: Added report_schedule column');
+        }
+
+        // Migration 8 -> 9: Add token alert columns to bot_settings
+        if (fromVersion < 9) {
+            logger.info('Applying migration 8 -> 9: Adding token alert columns');
+
+            // The desktop app may already have added these columns
+            const hasTokenAlert = this.db.prepare(
+                "SELECT COUNT(*) AS count FROM pragma_table_info('bot_settings') WHERE name = 'token_alert_threshold'"
+            ).get().count > 0;
+
+            if (!hasTokenAlert) {
+                this.db.exec(`
+                    -- NULL threshold = use the guild threshold from /token set-threshold
+                    ALTER TABLE bot_settings ADD COLUMN token_alert_threshold INTEGER;
+                    ALTER TABLE bot_settings ADD COLUMN token_alert_direction TEXT NOT NULL DEFAULT 'above';
+                `);
+            }
+
+            // Record schema version
+            this.db.prepare(
+                'INSERT INTO schema_info (version, applied_at) VALUES (?, ?)'
+            ).run(9, Date.now());
+
+            logger.info('Migration 8 -> 9 completed: Added token alert columns');
+        }
     }
 
     /**
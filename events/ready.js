@@ -11,9 +11,83 @@
  * - Logging connection success
  */
 
+const fs = require('fs');
 const wait = require("timers/promises").setTimeout;
 const { ActivityType } = require('discord.js');
 const logger = require('../utils/logger');
+const { getPresencePath, getConfigPath, getReloadConfigPath, getReloadConfigAckPath } = require('../utils/app-paths');
+
+// Used when the desktop app hasn't written a presence.json
+const DEFAULT_PRESENCE = { text: 'for daes 50 alts', type: 'Watching' };
+
+/**
+ * Reads the desired presence from presence.json, falling back to the default
+ * @returns {{text: string, type: string}} Presence text and ActivityType name
+ */
+function loadPresence() {
+    const presencePath = getPresencePath();
+    try {
+        if (fs.existsSync(presencePath)) {
+            const presence = JSON.parse(fs.readFileSync(presencePath, 'utf8'));
+            if (presence.text && ActivityType[presence.type] !== undefined) {
+                return presence;
+            }
+            logger.warn('Ignoring invalid presence.json', { presencePath });
+        }
+    } catch (error) {
+        logger.warn('Failed to read presence.json', { error: error.message });
+    }
+    return DEFAULT_PRESENCE;
+}
+
+/**
+ * Sets the bot activity from presence.json
+ * @param {Client} client - The Discord.js client instance
+ */
+async function applyPresence(client) {
+    const presence = loadPresence();
+    try {
+        await client.user.setActivity(presence.text, {
+            type: ActivityType[presence.type]
+        });
+        logger.info('Bot activity status set successfully', { presence });
+    } catch (error) {
+        logger.error('Failed to set bot activity', { error: error.message });
+    }
+}
+
+/**
+ * Handles a reload request from the desktop app: drops cached settings and
+ * character data so the next use re-reads config.json, then writes an ack
+ * @param {Client} client - The Discord.js client instance
+ */
+function reloadConfig(client) {
+    const requestPath = getReloadConfigPath();
+    try {
+        if (!fs.existsSync(requestPath)) {
+            return;
+        }
+        const request = JSON.parse(fs.readFileSync(requestPath, 'utf8'));
+
+        const { getConfigService } = require('../services/config-service');
+        const { getCharacterCacheManager } = require('../utils/cache-manager');
+        getConfigService().invalidateCache();
+        getCharacterCacheManager().clear();
+
+        // The login token can't change without reconnecting
+        const config = JSON.parse(fs.readFileSync(getConfigPath(), 'utf8'));
+        const restartRequired = Boolean(config.token) && config.token !== client.storedToken;
+
+        fs.writeFileSync(getReloadConfigAckPath(), JSON.stringify({
+            nonce: request.nonce,
+            restartRequired,
+            reloadedAt: Date.now()
+        }));
+        logger.info('Configuration reloaded', { restartRequired });
+    } catch (error) {
+        logger.error('Failed to reload configuration', { error: error.message });
+    }
+}
 
 module.exports = {
     name: 'clientReady',
@@ -35,15 +109,14 @@ module.exports = {
         });
 
         // Set bot activity status
-        // This appears in Discord as "Watching for daes 50 alts"
-        try {
-            await client.user.setActivity('for daes 50 alts', {
-                type: ActivityType.Watching
-            });
-            logger.info('Bot activity status set successfully');
-        } catch (error) {
-            logger.error('Failed to set bot activity', { error: error.message });
-        }
+        // Defaults to "Watching for daes 50 alts" unless the desktop app set one
+        await applyPresence(client);
+
+        // Re-apply whenever the desktop app updates presence.json
+        fs.watchFile(getPresencePath(), { interval: 5000 }, () => applyPresence(client));
+
+        // The desktop app writes reload-config.json after saving config
+        fs.watchFile(getReloadConfigPath(), { interval: 2000 }, () => reloadConfig(client));
 
         // Initialize character command auto-refresh system
         // This sets up periodic data updates for active character menus
@@ -94,5 +167,8 @@ module.exports = {
             guildCount: client.guilds.cache.size,
             guilds: guildInfo
         });
+
+        // Tells the desktop app's startup watchdog that init finished
+        console.log('[DAEBOT_READY]');
     }
 };
\ No newline at end of file
@@ -18,7 +18,7 @@ const { Collection } = require('discord.js');
 const path = require('node:path');
 const { client } = require('./global_vars/vars');
 const logger = require('./utils/logger');
-const { getConfigPath, getEnvPath } = require('./utils/app-paths');
+const { getConfigPath, getEnvPath, getShutdownRequestPath } = require('./utils/app-paths');
 
 // Load environment variables from .env file in AppData
 const envPath = getEnvPath();
@@ -43,11 +43,18 @@ try {
 
     const configContent = fs.readFileSync(configPath, 'utf8');
     const config = JSON.parse(configContent);
-    token = config.token;
+
+    // The desktop app encrypts the token in config.json and passes it in DAEBOT_TOKEN
+    token = process.env.DAEBOT_TOKEN || config.token;
+    // Stored form, so a config reload can tell whether the token changed
+    client.storedToken = config.token;
 
     if (!token) {
         throw new Error('Bot token is missing from config.json');
     }
+    if (token.startsWith('dpapi:')) {
+        throw new Error('Bot token in config.json is encrypted; start the bot from the DaeBot app');
+    }
 
     logger.info('Configuration loaded successfully from AppData', {
         path: configPath,
@@ -266,6 +273,19 @@ process.on('SIGTERM', () => {
     process.emit('SIGINT'); // Reuse SIGINT handler
 });
 
+/**
+ * The desktop app can't signal a windowless process on Windows, so it writes
+ * shutdown-requested instead and force-kills only if we don't exit in time
+ */
+fs.watchFile(getShutdownRequestPath(), { interval: 1000 }, (current) => {
+    // mtimeMs is 0 when the file was removed
+    if (current.mtimeMs === 0) {
+        return;
+    }
+    logger.info('Shutdown requested by the desktop app, initiating graceful shutdown');
+    process.emit('SIGINT');
+});
+
 /**
  * Initialize image cache for faster character image generation
  */
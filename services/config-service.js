@@ -45,6 +45,10 @@ class ConfigService {
                     default_region,
                     default_realm,
                     active_dungeons,
+                    token_region,
+                    report_schedule,
+                    token_alert_threshold,
+                    token_alert_direction,
                     updated_at
                 FROM bot_settings
                 WHERE id = 1
@@ -64,6 +68,10 @@ class ConfigService {
                 defaultRegion: settings.default_region,
                 defaultRealm: settings.default_realm || 'thrall', // Fallback for existing databases
                 activeDungeons: JSON.parse(settings.active_dungeons || '[]'),
+                tokenRegion: settings.token_region || 'us', // Fallback for existing databases
+                reportSchedule: settings.report_schedule || null,
+                tokenAlertThreshold: settings.token_alert_threshold ?? null,
+                tokenAlertDirection: settings.token_alert_direction || 'above',
                 updatedAt: settings.updated_at
             };
 
@@ -107,6 +115,10 @@ class ConfigService {
                 'Tazavesh: So\'leah\'s Gambit',
                 'Tazavesh: Streets of Wonder'
             ],
+            tokenRegion: 'us',
+            reportSchedule: null,
+            tokenAlertThreshold: null,
+            tokenAlertDirection: 'above',
             updatedAt: Date.now()
         };
     }
@@ -167,6 +179,42 @@ class ConfigService {
         return settings.defaultRegion;
     }
 
+    /**
+     * Get region used for WoW token prices (independent of character regions)
+     * @returns {string} Region code (e.g., 'us', 'eu')
+     */
+    getTokenRegion() {
+        const settings = this._getSettings();
+        return settings.tokenRegion.toLowerCase();
+    }
+
+    /**
+     * Get scheduled report setting as stored by the desktop app
+     * @returns {string|null} 'daily HH:MM', 'weekly <day> HH:MM', 'every <N>h', or null if disabled
+     */
+    getReportSchedule() {
+        const settings = this._getSettings();
+        return settings.reportSchedule || null;
+    }
+
+    /**
+     * Get token alert threshold configured in the desktop app
+     * @returns {number|null} Threshold in gold, or null to use the /token set-threshold value
+     */
+    getTokenAlertThreshold() {
+        const settings = this._getSettings();
+        return settings.tokenAlertThreshold ?? null;
+    }
+
+    /**
+     * Get whether token alerts fire above or below the threshold
+     * @returns {string} 'above' or 'below'
+     */
+    getTokenAlertDirection() {
+        const settings = this._getSettings();
+        return settings.tokenAlertDirection === 'below' ? 'below' : 'above';
+    }
+
     /**
      * Get default realm (normalized to lowercase)
      * @returns {string} Realm name (e.g., 'thrall')
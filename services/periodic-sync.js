@@ -6,10 +6,12 @@
  * Also populates the character command cache to prevent re-fetching after sync.
  */
 
+const fs = require('fs');
 const { RunCollector } = require('./run-collector');
 const logger = require('../utils/logger');
 const { getCharacterCacheManager } = require('../utils/cache-manager');
 const { getDatabase } = require('../database/mythic-runs-db');
+const { getSyncPausedPath } = require('../utils/app-paths');
 
 // Sync interval in milliseconds (default: 1 hour)
 const SYNC_INTERVAL = 60 * 60 * 1000; // 1 hour
@@ -69,6 +71,12 @@ function stopPeriodicSync() {
 async function runSync(collector) {
     const statusTracker = global.statusTracker;
 
+    // The desktop app drops this flag to pause API-heavy syncing without stopping the bot
+    if (fs.existsSync(getSyncPausedPath())) {
+        logger.info('Skipping scheduled run sync: sync is paused');
+        return;
+    }
+
     try {
         logger.info('Starting scheduled run sync');
 
@@ -2,10 +2,10 @@
  * WoW Token Price Tracking Service
  *
  * Monitors WoW token prices from wowtoken.app API and sends notifications
- * when the price exceeds the configured threshold.
+ * when the price crosses the configured threshold (above or below).
  *
  * Features:
- * - Fetches US token prices every 20 minutes (aligned to hour)
+ * - Fetches token prices for the configured region every 20 minutes (aligned to hour)
  * - Tracks price changes and sends notifications
  * - Supports both channel notifications and user DMs
  * - Stores price history in database
@@ -16,6 +16,7 @@ const { EmbedBuilder } = require('discord.js');
 const logger = require('../utils/logger');
 const { getTokenDatabase } = require('../database/token-db');
 const { getConfigPath } = require('../utils/app-paths');
+const { getConfigService } = require('./config-service');
 
 // Token API configuration
 const TOKEN_API_URL = 'https://data.wowtoken.app/v2/current/retail.json';
@@ -111,14 +112,17 @@ class TokenTracker {
 
             const data = await response.json();
 
-            // Extract US price data
-            if (!data.us || !Array.isArray(data.us) || data.us.length < 2) {
-                throw new Error('Invalid API response format');
+            // Extract price data for the configured token region
+            const region = getConfigService().getTokenRegion();
+            const regionData = data[region];
+            if (!regionData || !Array.isArray(regionData) || regionData.length < 2) {
+                throw new Error(`Invalid API response format for region '${region}'`);
             }
 
-            const [timestamp, price] = data.us;
+            const [timestamp, price] = regionData;
 
             logger.info('Fetched token price', {
+                region,
                 price,
                 timestamp,
                 priceFormatted: this.formatGold(price)
@@ -162,17 +166,22 @@ class TokenTracker {
             }
 
             // Check if we should send notifications
-            const threshold = this.db.getThreshold();
+            // Threshold set in the desktop app takes precedence over /token set-threshold
+            const config = getConfigService();
+            const threshold = config.getTokenAlertThreshold() ?? this.db.getThreshold();
+            const direction = config.getTokenAlertDirection();
+            const meetsThreshold = direction === 'below' ? price <= threshold : price >= threshold;
 
             logger.info('Price check complete', {
                 currentPrice: price,
                 previousPrice: previousPrice?.price || 'none',
                 threshold,
-                aboveThreshold: price >= threshold
+                direction,
+                meetsThreshold
             });
 
             // Only send notifications if:
-            // 1. Price is at or above threshold
+            // 1. Price is at or above threshold (at or below when direction is 'below')
             // 2. Price has crossed a 5k boundary (315k, 320k, 325k, etc.)
             const BRACKET_SIZE = 5000;
 
@@ -183,7 +192,7 @@ class TokenTracker {
             // Only notify if we've crossed into a different 5k bracket
             const hasCrossedBracket = !previousPrice || currentBracket !== previousBracket;
 
-            const shouldNotify = price >= threshold && hasCrossedBracket;
+            const shouldNotify = meetsThreshold && hasCrossedBracket;
 
             if (shouldNotify) {
                 logger.info('Sending token price notifications', {
@@ -194,12 +203,13 @@ class TokenTracker {
                     previousBracket: previousBracket ? previousBracket * BRACKET_SIZE : 'none'
                 });
 
-                await this.sendNotifications(price, previousPrice?.price || null, threshold);
+                await this.sendNotifications(price, previousPrice?.price || null, threshold, direction);
             } else {
-                if (price < threshold) {
-                    logger.info('Price below threshold, no notification sent', {
+                if (!meetsThreshold) {
+                    logger.info('Price has not reached threshold, no notification sent', {
                         price,
-                        threshold
+                        threshold,
+                        direction
                     });
                 } else if (previousPrice && !hasCrossedBracket) {
                     logger.info('Price has not crossed 5k bracket, no notification sent', {
@@ -226,9 +236,10 @@ class TokenTracker {
      * @param {number} currentPrice - Current token price
      * @param {number|null} previousPrice - Previous token price
      * @param {number} threshold - Current threshold
+     * @param {string} direction - 'above' or 'below'
      */
-    async sendNotifications(currentPrice, previousPrice, threshold) {
-        const embed = this.createPriceEmbed(currentPrice, previousPrice, threshold);
+    async sendNotifications(currentPrice, previousPrice, threshold, direction) {
+        const embed = this.createPriceEmbed(currentPrice, previousPrice, threshold, direction);
 
         // Send to configured channel
         await this.sendChannelNotification(embed);
@@ -242,9 +253,10 @@ class TokenTracker {
      * @param {number} currentPrice - Current token price
      * @param {number|null} previousPrice - Previous token price
      * @param {number} threshold - Current threshold
+     * @param {string} direction - 'above' or 'below'
      * @returns {EmbedBuilder} Discord embed
      */
-    createPriceEmbed(currentPrice, previousPrice, threshold) {
+    createPriceEmbed(currentPrice, previousPrice, threshold, direction = 'above') {
         const priceChange = previousPrice ? currentPrice - previousPrice : 0;
         const changePercent = previousPrice ? ((priceChange / previousPrice) * 100).toFixed(2) : 0;
 
@@ -284,7 +296,7 @@ class TokenTracker {
             ? '✅ Token price has increased!'
             : priceChange < 0
                 ? '⚠️ Token price has decreased!'
-                : 'ℹ️ Token price remains above threshold.';
+                : `ℹ️ Token price remains ${direction} threshold.`;
 
         embed.setDescription(description);
 
@@ -301,16 +313,25 @@ class TokenTracker {
             const configContent = fs.readFileSync(configPath, 'utf8');
             const config = JSON.parse(configContent);
 
-            if (!config.tokenChannel) {
-                logger.warn('No tokenChannel configured, skipping channel notification');
+            // tokenChannel maps region -> channel id ('default' covers unmapped regions);
+            // older configs stored a single channel id string
+            const region = getConfigService().getTokenRegion();
+            const channels = typeof config.tokenChannel === 'string'
+                ? { default: config.tokenChannel }
+                : (config.tokenChannel || {});
+            const channelId = channels[region] || channels.default;
+
+            if (!channelId) {
+                logger.warn('No tokenChannel configured for region, skipping channel notification', { region });
                 return;
             }
 
-            const channel = await this.client.channels.fetch(config.tokenChannel);
+            const channel = await this.client.channels.fetch(channelId);
 
             if (!channel) {
                 logger.error('Could not find token notification channel', {
-                    channelId: config.tokenChannel
+                    channelId,
+                    region
                 });
                 return;
             }
@@ -318,7 +339,8 @@ class TokenTracker {
             await channel.send({ embeds: [embed] });
 
             logger.info('Sent token price notification to channel', {
-                channelId: config.tokenChannel
+                channelId,
+                region
             });
 
         } catch (error) {
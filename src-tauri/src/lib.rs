@@ -1,13 +1,16 @@
-use std::sync::Mutex;
-use std::process::{Child, Command};
+use std::collections::VecDeque;
+use std::sync::{Mutex, OnceLock};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::process::{Child, Command, Stdio};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
-use std::io::{BufRead, BufReader, Write};
-use tauri::Manager;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::io::{BufRead, BufReader, Read, Write};
+use tauri::{Emitter, Manager};
 use tauri::{menu::{Menu, MenuItem}, tray::{TrayIconBuilder, TrayIconEvent}};
 use tauri_plugin_updater::UpdaterExt;
-use rusqlite::Connection;
+use rusqlite::{Connection, OptionalExtension};
 use chrono::DateTime;
 use url::Url;
 
@@ -26,11 +29,105 @@ struct Config {
     client_id: String,
     #[serde(rename = "guildId")]
     guild_id: String,
-    #[serde(rename = "tokenChannel")]
-    token_channel: String,
+    #[serde(rename = "tokenChannel", default)]
+    token_channel: TokenChannels,
     characters: Vec<Character>,
 }
 
+// Key in TokenChannels used for regions without a channel of their own
+const TOKEN_CHANNEL_DEFAULT: &str = "default";
+
+// WoW token channels keyed by region ("us", "eu", ...) or TOKEN_CHANNEL_DEFAULT.
+// Older configs stored a single channel id string, which loads as the default.
+#[derive(Clone, Default, Serialize)]
+#[serde(transparent)]
+struct TokenChannels(std::collections::BTreeMap<String, String>);
+
+impl<'de> Deserialize<'de> for TokenChannels {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum TokenChannelsRepr {
+            Single(String),
+            PerRegion(std::collections::BTreeMap<String, String>),
+        }
+
+        Ok(match TokenChannelsRepr::deserialize(deserializer)? {
+            TokenChannelsRepr::Single(channel_id) if channel_id.trim().is_empty() => TokenChannels::default(),
+            TokenChannelsRepr::Single(channel_id) => {
+                TokenChannels(std::collections::BTreeMap::from([(TOKEN_CHANNEL_DEFAULT.to_string(), channel_id)]))
+            }
+            TokenChannelsRepr::PerRegion(channels) => TokenChannels(channels),
+        })
+    }
+}
+
+// Discord ids are 64-bit snowflakes, 17-20 decimal digits in practice
+fn is_discord_snowflake(id: &str) -> bool {
+    (17..=20).contains(&id.len()) && id.chars().all(|c| c.is_ascii_digit())
+}
+
+// Trim and lowercase the map, drop blank entries, and reject unknown regions
+// or malformed channel ids
+fn normalize_token_channels(channels: TokenChannels) -> Result<TokenChannels, String> {
+    let mut normalized = std::collections::BTreeMap::new();
+
+    for (region, channel_id) in channels.0 {
+        let region = region.trim().to_lowercase();
+        let channel_id = channel_id.trim().to_string();
+        if channel_id.is_empty() {
+            continue;
+        }
+
+        if region != TOKEN_CHANNEL_DEFAULT && !TOKEN_REGIONS.contains(&region.as_str()) {
+            return Err(format!(
+                "Unknown token channel region '{}'. Use one of: {}, {}",
+                region, TOKEN_REGIONS.join(", "), TOKEN_CHANNEL_DEFAULT
+            ));
+        }
+        if !is_discord_snowflake(&channel_id) {
+            return Err(format!("Token channel for '{}' is not a valid Discord channel ID: {}", region, channel_id));
+        }
+
+        normalized.insert(region, channel_id);
+    }
+
+    Ok(TokenChannels(normalized))
+}
+
+// Reject config the bot can't start with. Blank ids are allowed so the empty
+// first-run config can still be saved.
+fn validate_config(config: &Config) -> Result<(), String> {
+    for (field, id) in [("clientId", &config.client_id), ("guildId", &config.guild_id)] {
+        let id = id.trim();
+        if !id.is_empty() && !is_discord_snowflake(id) {
+            return Err(format!("{} is not a valid Discord ID (17-20 digits): {}", field, id));
+        }
+    }
+
+    for (i, character) in config.characters.iter().enumerate() {
+        let label = if character.name.trim().is_empty() {
+            format!("Character {}", i + 1)
+        } else {
+            format!("Character {} ({})", i + 1, character.name.trim())
+        };
+
+        for (field, value) in [("name", &character.name), ("realm", &character.realm), ("region", &character.region)] {
+            if value.trim().is_empty() {
+                return Err(format!("{} has an empty {}", label, field));
+            }
+        }
+        if !TOKEN_REGIONS.contains(&character.region.trim().to_lowercase().as_str()) {
+            return Err(format!(
+                "{} has unknown region '{}'. Use one of: {}",
+                label, character.region, TOKEN_REGIONS.join(", ")
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 struct BlizzardCredentials {
     #[serde(rename = "clientId")]
@@ -53,6 +150,114 @@ struct Settings {
     open_on_startup: bool,
     #[serde(rename = "autoStartBot", default)]
     auto_start_bot: bool,
+    #[serde(rename = "closeBehavior", default, skip_serializing_if = "Option::is_none")]
+    close_behavior: Option<CloseBehavior>,
+    #[serde(rename = "defaultLogLimit", default, skip_serializing_if = "Option::is_none")]
+    default_log_limit: Option<usize>,
+    #[serde(rename = "defaultSyncHistoryLimit", default, skip_serializing_if = "Option::is_none")]
+    default_sync_history_limit: Option<usize>,
+    #[serde(rename = "botPresence", default, skip_serializing_if = "Option::is_none")]
+    bot_presence: Option<BotPresence>,
+    #[serde(rename = "botWorkingDir", default, skip_serializing_if = "Option::is_none")]
+    bot_working_dir: Option<String>,
+    // Overrides the bundled bot.exe search (or `node main.js` in dev); a .js file is run with Node
+    #[serde(rename = "botExecutablePath", default, skip_serializing_if = "Option::is_none")]
+    bot_executable_path: Option<String>,
+    // Node binary for running main.js or a .js botExecutablePath, instead of `node` on PATH
+    #[serde(rename = "nodePath", default, skip_serializing_if = "Option::is_none")]
+    node_path: Option<String>,
+    #[serde(rename = "logLevel", default, skip_serializing_if = "Option::is_none")]
+    log_level: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    theme: Option<ThemePreference>,
+    // Off by default: a second launch just focuses the running window
+    #[serde(rename = "allowMultipleInstances", default)]
+    allow_multiple_instances: bool,
+    // Dashboard season filter; None (or "all") shows every season
+    #[serde(rename = "selectedSeason", default, skip_serializing_if = "Option::is_none")]
+    selected_season: Option<String>,
+    // Copy of the database for dashboard reads, so they don't contend with the
+    // bot's writes. Anything that writes still uses the live database.
+    #[serde(rename = "readDatabasePath", default, skip_serializing_if = "Option::is_none")]
+    read_database_path: Option<String>,
+    // Restart as soon as an update is installed instead of waiting for confirm_restart
+    #[serde(rename = "autoRestartAfterUpdate", default)]
+    auto_restart_after_update: bool,
+    // Seconds the bot gets to shut down cleanly before it is force-killed
+    #[serde(rename = "shutdownGraceSecs", default, skip_serializing_if = "Option::is_none")]
+    shutdown_grace_secs: Option<u64>,
+    // How many mythic_runs_backup_* files to keep; older ones are deleted
+    #[serde(rename = "databaseBackupRetention", default, skip_serializing_if = "Option::is_none")]
+    database_backup_retention: Option<usize>,
+}
+
+// Levels accepted for Settings.logLevel, passed to the log plugin at startup
+const LOG_LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
+
+// App log written in release builds, where stdout goes nowhere
+const APP_LOG_FILE_NAME: &str = "daebot-app";
+
+fn parse_log_level(level: &str) -> Option<log::LevelFilter> {
+    match level.to_lowercase().as_str() {
+        "error" => Some(log::LevelFilter::Error),
+        "warn" => Some(log::LevelFilter::Warn),
+        "info" => Some(log::LevelFilter::Info),
+        "debug" => Some(log::LevelFilter::Debug),
+        "trace" => Some(log::LevelFilter::Trace),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct BotPresence {
+    text: String,
+    #[serde(rename = "type")]
+    activity_type: String,
+}
+
+// Activity types accepted by discord.js ActivityType for bot presences
+const DISCORD_ACTIVITY_TYPES: [&str; 5] = ["Playing", "Streaming", "Listening", "Watching", "Competing"];
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum CloseBehavior {
+    Ask,
+    Tray,
+    Quit,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum ThemePreference {
+    System,
+    Light,
+    Dark,
+}
+
+// Window background per palette, matching --primary-bg in App.css so the
+// webview never flashes white before the stylesheet loads
+const DARK_BACKGROUND: (u8, u8, u8) = (0x1a, 0x1a, 0x2e);
+const LIGHT_BACKGROUND: (u8, u8, u8) = (0xf3, 0xf4, 0xf6);
+
+#[derive(Serialize)]
+struct ThemeInfo {
+    preference: ThemePreference,
+    // "light" or "dark" after resolving System against the OS
+    effective: String,
+}
+
+impl Settings {
+    // The UI was dark-only before the theme setting existed, so keep that
+    fn effective_theme(&self) -> ThemePreference {
+        self.theme.unwrap_or(ThemePreference::Dark)
+    }
+
+    // Older settings files only have minimizeToTray, so fall back to it
+    fn effective_close_behavior(&self) -> CloseBehavior {
+        match self.close_behavior {
+            Some(behavior) => behavior,
+            None if self.minimize_to_tray => CloseBehavior::Tray,
+            None => CloseBehavior::Quit,
+        }
+    }
 }
 
 fn default_true() -> bool {
@@ -62,16 +267,150 @@ fn default_true() -> bool {
 struct BotState {
     process: Option<Child>,
     status: String,
+    ready: bool,
+    // When get_bot_status last probed the process; None forces a fresh probe
+    last_probe: Option<std::time::Instant>,
+    // Exit code of the last bot process once it has stopped; None while running
+    last_exit: Option<i32>,
+    // When the current process was spawned, for get_bot_uptime
+    started_at: Option<std::time::Instant>,
+}
+
+// Exit code for a finished bot; Unix signals are reported shell-style as 128 + signal
+fn exit_code(status: std::process::ExitStatus) -> i32 {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return 128 + signal;
+        }
+    }
+    status.code().unwrap_or(-1)
 }
 
+// How long a get_bot_status probe result is reused
+const BOT_STATUS_PROBE_TTL: std::time::Duration = std::time::Duration::from_millis(500);
+
 struct AppState {
     bot: Mutex<BotState>,
+    output: Mutex<VecDeque<BotOutputLine>>,
+    app_data_dir: OnceLock<Result<PathBuf, String>>,
+    window_state_generation: AtomicU64,
+    initialization_complete: AtomicBool,
+    // Problems found during startup setup, shown by the frontend once it finishes
+    initialization_error: Mutex<Option<String>>,
+    // An update is downloading, and one is downloaded awaiting install and restart
+    update_in_progress: AtomicBool,
+    pending_update: Mutex<Option<PendingUpdate>>,
+}
+
+// A downloaded update held until confirm_restart installs it; installing
+// starts the installer, which on Windows exits the app right away
+struct PendingUpdate {
+    update: tauri_plugin_updater::Update,
+    bytes: Vec<u8>,
+    channel: &'static str,
+}
+
+// AppData directory as resolved once during setup
+fn app_data_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let state = app.try_state::<AppState>()
+        .ok_or("App state is not initialized")?;
+
+    match state.app_data_dir.get() {
+        Some(Ok(dir)) => Ok(dir.clone()),
+        Some(Err(message)) => Err(message.clone()),
+        None => Err("App data directory has not been resolved yet".to_string()),
+    }
+}
+
+// Where the startup error goes when the AppData folder itself is unavailable
+fn fallback_startup_error_path() -> PathBuf {
+    std::env::temp_dir().join("daebot-startup-error.txt")
+}
+
+// Number of recent bot output lines kept for get_bot_output
+const BOT_OUTPUT_BUFFER_SIZE: usize = 500;
+
+// Printed by the bot's ready handler once initialization is complete
+const BOT_READY_MARKER: &str = "[DAEBOT_READY]";
+
+// How long a freshly started bot gets to print BOT_READY_MARKER
+const BOT_START_TIMEOUT_SECS: u64 = 90;
+
+#[derive(Clone, Serialize)]
+struct BotOutputLine {
+    stream: String,
+    line: String,
+    timestamp: String,
+}
+
+// Helper function to forward one of the bot's output pipes to the ring buffer and frontend.
+// The thread ends on EOF, i.e. once the process has exited and been reaped.
+fn spawn_output_reader<R: Read + Send + 'static>(app: tauri::AppHandle, pipe: R, stream: &'static str) {
+    std::thread::spawn(move || {
+        let mut reader = BufReader::new(pipe);
+        let mut buffer = Vec::new();
+
+        loop {
+            buffer.clear();
+            match reader.read_until(b'\n', &mut buffer) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {}
+            }
+
+            // Decode lossily so a bad byte never stops us draining the pipe
+            let line = String::from_utf8_lossy(&buffer)
+                .trim_end_matches(['\r', '\n'])
+                .to_string();
+            let line = redact_secrets(&line);
+            log::debug!("[bot {}] {}", stream, line);
+
+            let entry = BotOutputLine {
+                stream: stream.to_string(),
+                line,
+                timestamp: chrono::Local::now().to_rfc3339(),
+            };
+
+            if let Some(state) = app.try_state::<AppState>() {
+                if entry.line.contains(BOT_READY_MARKER) {
+                    state.bot.lock().unwrap().ready = true;
+                }
+
+                let mut output = state.output.lock().unwrap();
+                if output.len() >= BOT_OUTPUT_BUFFER_SIZE {
+                    output.pop_front();
+                }
+                output.push_back(entry.clone());
+            }
+
+            let _ = app.emit("bot-output", entry);
+        }
+    });
+}
+
+#[derive(Clone, Serialize)]
+struct BotStatusEvent {
+    status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pid: Option<u32>,
+}
+
+// Helper function to notify the frontend of bot status transitions
+fn emit_bot_status(app: &tauri::AppHandle, status: &str, pid: Option<u32>) {
+    let payload = BotStatusEvent {
+        status: status.to_string(),
+        pid,
+    };
+
+    if let Err(e) = app.emit("bot-status-changed", payload) {
+        log::warn!("Failed to emit bot-status-changed event: {}", e);
+    }
 }
 
 #[tauri::command]
 fn get_settings(app: tauri::AppHandle) -> Result<Settings, String> {
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    let app_dir = app_data_dir(&app)?;
 
     let settings_path = app_dir.join("settings.json");
 
@@ -89,18 +428,68 @@ fn get_settings(app: tauri::AppHandle) -> Result<Settings, String> {
             start_minimized: false,
             open_on_startup: false,
             auto_start_bot: false,
+            close_behavior: None,
+            default_log_limit: None,
+            default_sync_history_limit: None,
+            bot_presence: None,
+            bot_working_dir: None,
+            bot_executable_path: None,
+            node_path: None,
+            log_level: None,
+            theme: None,
+            allow_multiple_instances: false,
+            selected_season: None,
+            read_database_path: None,
+            auto_restart_after_update: false,
+            shutdown_grace_secs: None,
+            database_backup_retention: None,
         })
     }
 }
 
 #[tauri::command]
 fn save_settings(app: tauri::AppHandle, settings: Settings) -> Result<(), String> {
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    let app_dir = app_data_dir(&app)?;
 
     fs::create_dir_all(&app_dir)
         .map_err(|e| format!("Failed to create app data dir: {}", e))?;
 
+    if let Some(level) = &settings.log_level {
+        if parse_log_level(level).is_none() {
+            return Err(format!("Log level must be one of: {}", LOG_LEVELS.join(", ")));
+        }
+    }
+
+    let mut settings = settings;
+    if settings.selected_season.as_deref().is_some_and(|season| season.eq_ignore_ascii_case("all")) {
+        settings.selected_season = None;
+    }
+    if settings.shutdown_grace_secs.is_some_and(|secs| secs > MAX_SHUTDOWN_GRACE_SECS) {
+        return Err(format!("Shutdown grace period can be at most {} seconds", MAX_SHUTDOWN_GRACE_SECS));
+    }
+    if settings.database_backup_retention == Some(0) {
+        return Err("Keep at least one database backup".to_string());
+    }
+
+    if settings.read_database_path.as_deref().is_some_and(|path| path.trim().is_empty()) {
+        settings.read_database_path = None;
+    }
+    if let Some(path) = &settings.read_database_path {
+        validate_read_database(Path::new(path))?;
+    }
+
+    // Only check a newly chosen season, so saves from elsewhere (presence, first
+    // run) don't fail just because the database changed under a saved filter
+    let season_changed = get_settings(app.clone())
+        .map(|current| current.selected_season != settings.selected_season)
+        .unwrap_or(true);
+    if let (Some(season), true) = (&settings.selected_season, season_changed) {
+        // A filter for a season that isn't in the database just shows an empty dashboard
+        if !get_available_seasons(app.clone())?.contains(season) {
+            return Err(format!("Season '{}' has no runs in the database", season));
+        }
+    }
+
     // Handle Windows startup registry
     #[cfg(target_os = "windows")]
     {
@@ -115,8 +504,220 @@ fn save_settings(app: tauri::AppHandle, settings: Settings) -> Result<(), String
     let content = serde_json::to_string_pretty(&settings)
         .map_err(|e| format!("Failed to serialize settings: {}", e))?;
 
-    fs::write(&settings_path, content)
-        .map_err(|e| format!("Failed to write settings: {}", e))
+    write_file_atomic(&settings_path, content)
+        .map_err(|e| format!("Failed to write settings: {}", e))?;
+
+    if let Some(window) = app.get_webview_window("main") {
+        apply_theme(&window, settings.effective_theme());
+    }
+
+    Ok(())
+}
+
+fn resolve_theme(window: &tauri::WebviewWindow, preference: ThemePreference) -> &'static str {
+    match preference {
+        ThemePreference::Light => "light",
+        ThemePreference::Dark => "dark",
+        ThemePreference::System => match window.theme() {
+            Ok(tauri::Theme::Light) => "light",
+            _ => "dark",
+        },
+    }
+}
+
+// Sets the native theme and background before the page has painted anything
+fn apply_theme(window: &tauri::WebviewWindow, preference: ThemePreference) {
+    let native = match preference {
+        ThemePreference::System => None,
+        ThemePreference::Light => Some(tauri::Theme::Light),
+        ThemePreference::Dark => Some(tauri::Theme::Dark),
+    };
+    if let Err(e) = window.set_theme(native) {
+        log::warn!("Failed to set window theme: {}", e);
+    }
+
+    let (r, g, b) = match resolve_theme(window, preference) {
+        "light" => LIGHT_BACKGROUND,
+        _ => DARK_BACKGROUND,
+    };
+    if let Err(e) = window.set_background_color(Some(tauri::window::Color(r, g, b, 255))) {
+        log::warn!("Failed to set window background: {}", e);
+    }
+}
+
+#[tauri::command]
+fn get_theme(app: tauri::AppHandle) -> Result<ThemeInfo, String> {
+    let preference = get_settings(app.clone())?.effective_theme();
+    let effective = match app.get_webview_window("main") {
+        Some(window) => resolve_theme(&window, preference),
+        None if preference == ThemePreference::Light => "light",
+        None => "dark",
+    };
+
+    Ok(ThemeInfo {
+        preference,
+        effective: effective.to_string(),
+    })
+}
+
+#[tauri::command]
+fn is_first_run(app: tauri::AppHandle) -> Result<bool, String> {
+    Ok(get_settings(app)?.first_run)
+}
+
+// Called at the end of onboarding so the setup wizard isn't shown again
+#[tauri::command]
+fn complete_first_run(app: tauri::AppHandle) -> Result<(), String> {
+    let mut settings = get_settings(app.clone())?;
+    settings.first_run = false;
+    save_settings(app, settings)
+}
+
+#[tauri::command]
+fn set_bot_presence(app: tauri::AppHandle, text: String, activity_type: String) -> Result<(), String> {
+    log::debug!("set_bot_presence called: {} {}", activity_type, text);
+
+    let text = text.trim().to_string();
+    if text.is_empty() {
+        return Err("Presence text cannot be empty".to_string());
+    }
+    if text.chars().count() > 128 {
+        return Err("Presence text must be 128 characters or fewer".to_string());
+    }
+
+    let activity_type = DISCORD_ACTIVITY_TYPES.iter()
+        .find(|t| t.eq_ignore_ascii_case(&activity_type))
+        .ok_or_else(|| format!(
+            "Invalid activity type '{}'. Must be one of: {}",
+            activity_type,
+            DISCORD_ACTIVITY_TYPES.join(", ")
+        ))?;
+
+    let presence = BotPresence {
+        text,
+        activity_type: activity_type.to_string(),
+    };
+
+    // Persist the choice in settings so it survives restarts
+    let mut settings = get_settings(app.clone())?;
+    settings.bot_presence = Some(presence.clone());
+    save_settings(app.clone(), settings)?;
+
+    // The bot watches presence.json and applies changes while running
+    let app_dir = app_data_dir(&app)?;
+    let presence_path = app_dir.join("presence.json");
+
+    let content = serde_json::to_string_pretty(&presence)
+        .map_err(|e| format!("Failed to serialize presence: {}", e))?;
+
+    // The bot watches this file, so never let it see a half-written one
+    write_file_atomic(&presence_path, content)
+        .map_err(|e| format!("Failed to write presence file: {}", e))
+}
+
+// The bot skips scheduled syncs while this file exists in the app data folder
+const SYNC_PAUSED_FILE: &str = "sync-paused";
+
+#[tauri::command]
+fn pause_sync(app: tauri::AppHandle) -> Result<(), String> {
+    let app_dir = app_data_dir(&app)?;
+    fs::create_dir_all(&app_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+
+    // Contents are informational; the bot only checks that the file exists
+    fs::write(app_dir.join(SYNC_PAUSED_FILE), chrono::Local::now().to_rfc3339())
+        .map_err(|e| format!("Failed to write sync-paused flag: {}", e))?;
+    log::info!("Sync paused");
+    Ok(())
+}
+
+#[tauri::command]
+fn resume_sync(app: tauri::AppHandle) -> Result<(), String> {
+    let flag_path = app_data_dir(&app)?.join(SYNC_PAUSED_FILE);
+    if flag_path.exists() {
+        fs::remove_file(&flag_path)
+            .map_err(|e| format!("Failed to remove sync-paused flag: {}", e))?;
+    }
+    log::info!("Sync resumed");
+    Ok(())
+}
+
+#[tauri::command]
+fn get_sync_paused(app: tauri::AppHandle) -> Result<bool, String> {
+    Ok(app_data_dir(&app)?.join(SYNC_PAUSED_FILE).exists())
+}
+
+// Files the bot watches/writes for reload_bot_config
+const RELOAD_CONFIG_FILE: &str = "reload-config.json";
+const RELOAD_CONFIG_ACK_FILE: &str = "reload-config-ack.json";
+// The bot polls every 2 seconds, so allow a few polls before giving up
+const RELOAD_CONFIG_ACK_TIMEOUT_SECS: u64 = 10;
+
+#[derive(Clone, Serialize, Deserialize)]
+struct ConfigReloadResult {
+    acknowledged: bool,
+    #[serde(rename = "restartRequired")]
+    restart_required: bool,
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct ConfigReloadAck {
+    nonce: u64,
+    #[serde(rename = "restartRequired", default)]
+    restart_required: bool,
+}
+
+// Asks the running bot to re-read config.json (call after save_config) and
+// waits for it to acknowledge. Older bots never answer, so suggest a restart.
+#[tauri::command]
+async fn reload_bot_config(app: tauri::AppHandle) -> Result<ConfigReloadResult, String> {
+    let running = {
+        let state = app.state::<AppState>();
+        let bot = state.bot.lock().unwrap();
+        bot.process.is_some()
+    };
+    if !running {
+        return Err("The bot is not running; changes will apply when it starts".to_string());
+    }
+
+    let app_dir = app_data_dir(&app)?;
+    let ack_path = app_dir.join(RELOAD_CONFIG_ACK_FILE);
+    let _ = fs::remove_file(&ack_path);
+
+    let nonce = chrono::Utc::now().timestamp_millis() as u64;
+    fs::write(app_dir.join(RELOAD_CONFIG_FILE), serde_json::json!({ "nonce": nonce }).to_string())
+        .map_err(|e| format!("Failed to write reload request: {}", e))?;
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(RELOAD_CONFIG_ACK_TIMEOUT_SECS);
+    while std::time::Instant::now() < deadline {
+        let ack = fs::read_to_string(&ack_path).ok()
+            .and_then(|content| serde_json::from_str::<ConfigReloadAck>(&content).ok())
+            .filter(|ack| ack.nonce == nonce);
+
+        if let Some(ack) = ack {
+            log::info!("Bot acknowledged config reload (restart required: {})", ack.restart_required);
+            let message = if ack.restart_required {
+                "Config reloaded, but the bot token changed. Restart the bot to log in with the new token."
+            } else {
+                "Config reloaded by the running bot."
+            };
+            return Ok(ConfigReloadResult {
+                acknowledged: true,
+                restart_required: ack.restart_required,
+                message: message.to_string(),
+            });
+        }
+
+        tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+    }
+
+    log::warn!("Bot did not acknowledge config reload within {} seconds", RELOAD_CONFIG_ACK_TIMEOUT_SECS);
+    Ok(ConfigReloadResult {
+        acknowledged: false,
+        restart_required: true,
+        message: "The bot didn't confirm the reload. Restart the bot to apply the new config.".to_string(),
+    })
 }
 
 #[cfg(target_os = "windows")]
@@ -141,7 +742,7 @@ fn set_windows_startup(_app: &tauri::AppHandle, start_minimized: bool) -> Result
         .set_value("DaeBot", &command)
         .map_err(|e| format!("Failed to set registry value: {}", e))?;
 
-    println!("Added DaeBot to Windows startup");
+    log::info!("Added DaeBot to Windows startup");
     Ok(())
 }
 
@@ -156,97 +757,574 @@ fn remove_windows_startup() -> Result<(), String> {
         .map_err(|e| format!("Failed to open Run registry key: {}", e))?;
 
     match run_key.delete_value("DaeBot") {
-        Ok(_) => println!("Removed DaeBot from Windows startup"),
+        Ok(_) => log::info!("Removed DaeBot from Windows startup"),
         Err(_) => {} // Ignore error if value doesn't exist
     }
 
     Ok(())
 }
 
+// Write `content` to `path` only if the file doesn't exist yet. create_new keeps
+// concurrent first-run writers from clobbering each other; Ok(false) means
+// another writer got there first.
+fn write_new_file(path: &Path, content: &str) -> std::io::Result<bool> {
+    match fs::OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(mut file) => {
+            file.write_all(content.as_bytes())?;
+            Ok(true)
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+// Write via a temp file in the same directory and rename it over `path`, so a
+// crash mid-write leaves the old file intact instead of a truncated one
+fn write_file_atomic(path: &Path, content: impl AsRef<[u8]>) -> std::io::Result<()> {
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+
+    let result = fs::File::create(&tmp_path).and_then(|mut file| {
+        file.write_all(content.as_ref())?;
+        file.sync_all()
+    });
+    match result.and_then(|_| fs::rename(&tmp_path, path)) {
+        Ok(()) => Ok(()),
+        Err(e) => {
+            let _ = fs::remove_file(&tmp_path);
+            Err(e)
+        }
+    }
+}
+
+// Marks a config.json token encrypted with DPAPI (hex after the prefix). DPAPI ties
+// it to this Windows user, so it can't be read on another machine.
+const ENCRYPTED_TOKEN_PREFIX: &str = "dpapi:";
+
+// Env var start_bot passes the decrypted token in, since the bot can't decrypt it
+const BOT_TOKEN_ENV: &str = "DAEBOT_TOKEN";
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+fn dpapi_transform(data: &[u8], protect: bool) -> Result<Vec<u8>, String> {
+    use windows_sys::Win32::Foundation::LocalFree;
+    use windows_sys::Win32::Security::Cryptography::{
+        CryptProtectData, CryptUnprotectData, CRYPTPROTECT_UI_FORBIDDEN, CRYPT_INTEGER_BLOB,
+    };
+
+    let input = CRYPT_INTEGER_BLOB {
+        cbData: data.len() as u32,
+        pbData: data.as_ptr() as *mut u8,
+    };
+    let mut output = CRYPT_INTEGER_BLOB {
+        cbData: 0,
+        pbData: std::ptr::null_mut(),
+    };
+
+    let ok = unsafe {
+        if protect {
+            CryptProtectData(&input, std::ptr::null(), std::ptr::null(), std::ptr::null(), std::ptr::null(), CRYPTPROTECT_UI_FORBIDDEN, &mut output)
+        } else {
+            CryptUnprotectData(&input, std::ptr::null_mut(), std::ptr::null(), std::ptr::null(), std::ptr::null(), CRYPTPROTECT_UI_FORBIDDEN, &mut output)
+        }
+    };
+    if ok == 0 {
+        return Err(format!("DPAPI call failed: {}", std::io::Error::last_os_error()));
+    }
+
+    let result = unsafe { std::slice::from_raw_parts(output.pbData, output.cbData as usize).to_vec() };
+    unsafe {
+        LocalFree(output.pbData as _);
+    }
+    Ok(result)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn dpapi_transform(_data: &[u8], _protect: bool) -> Result<Vec<u8>, String> {
+    Err("Token encryption is only available on Windows".to_string())
+}
+
+// Stored form of a token; falls back to plaintext where encryption isn't available
+fn encrypt_token(token: &str) -> String {
+    match dpapi_transform(token.as_bytes(), true) {
+        Ok(encrypted) => format!("{}{}", ENCRYPTED_TOKEN_PREFIX, to_hex(&encrypted)),
+        Err(e) => {
+            log::warn!("Storing bot token unencrypted: {}", e);
+            token.to_string()
+        }
+    }
+}
+
+// Plaintext token from its stored form; older plaintext tokens pass through and
+// get encrypted the next time the config is saved
+fn decrypt_token(stored: &str) -> Option<String> {
+    let Some(hex) = stored.strip_prefix(ENCRYPTED_TOKEN_PREFIX) else {
+        return Some(stored.to_string());
+    };
+
+    let decrypted = from_hex(hex)
+        .ok_or_else(|| "not valid hex".to_string())
+        .and_then(|bytes| dpapi_transform(&bytes, false))
+        .and_then(|bytes| String::from_utf8(bytes).map_err(|e| e.to_string()));
+    match decrypted {
+        Ok(token) => Some(token),
+        Err(e) => {
+            // e.g. a config.json copied from another machine or user
+            log::warn!("Could not decrypt the stored bot token, it needs to be entered again: {}", e);
+            None
+        }
+    }
+}
+
+// Parse config.json, returning it with the token decrypted plus the token as stored
+fn parse_stored_config(content: &str) -> Result<(Config, Option<String>), String> {
+    let mut config: Config = serde_json::from_str(content)
+        .map_err(|e| format!("Failed to parse config: {}", e))?;
+
+    let stored_token = config.token.take();
+    config.token = stored_token.as_deref().and_then(decrypt_token);
+    Ok((config, stored_token))
+}
+
 #[tauri::command]
 fn get_config(app: tauri::AppHandle) -> Result<Config, String> {
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    let app_dir = app_data_dir(&app)?;
 
     fs::create_dir_all(&app_dir)
         .map_err(|e| format!("Failed to create app data dir: {}", e))?;
 
     let config_path = app_dir.join("config.json");
-    println!("Loading config from: {:?}", config_path);
+    log::debug!("Loading config from: {:?}", config_path);
 
     if !config_path.exists() {
         // Create blank config on first run
-        println!("Config not found, creating blank config");
+        log::info!("Config not found, creating blank config");
         let blank_config = Config {
             token: None,
             client_id: String::new(),
             guild_id: String::new(),
-            token_channel: String::new(),
+            token_channel: TokenChannels::default(),
             characters: Vec::new(),
         };
 
         let content = serde_json::to_string_pretty(&blank_config)
             .map_err(|e| format!("Failed to serialize blank config: {}", e))?;
 
-        fs::write(&config_path, content)
+        let created = write_new_file(&config_path, &content)
             .map_err(|e| format!("Failed to write blank config: {}", e))?;
 
-        return Ok(blank_config);
+        // Someone else created it in the meantime; fall through and read theirs
+        if created {
+            return Ok(blank_config);
+        }
     }
 
     let content = fs::read_to_string(&config_path)
         .map_err(|e| format!("Failed to read config: {}", e))?;
-    serde_json::from_str(&content)
-        .map_err(|e| format!("Failed to parse config: {}", e))
+    let (config, _) = parse_stored_config(&content)?;
+
+    if let Some(token) = &config.token {
+        remember_secret(token);
+    }
+    Ok(config)
 }
 
 #[tauri::command]
 fn save_config(app: tauri::AppHandle, config: Config) -> Result<(), String> {
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    let app_dir = app_data_dir(&app)?;
 
     fs::create_dir_all(&app_dir)
         .map_err(|e| format!("Failed to create app data dir: {}", e))?;
 
     let config_path = app_dir.join("config.json");
-    println!("Saving config to: {:?}", config_path);
+    log::debug!("Saving config to: {:?}", config_path);
+
+    validate_config(&config)?;
 
     // Read existing config to preserve token if not provided
     let mut final_config = config;
 
-    if final_config.token.is_none() && config_path.exists() {
-        println!("Token not provided, reading existing config to preserve it");
+    let existing = if config_path.exists() {
         let existing_content = fs::read_to_string(&config_path)
             .map_err(|e| format!("Failed to read existing config: {}", e))?;
+        parse_stored_config(&existing_content).ok()
+    } else {
+        None
+    };
 
-        if let Ok(existing_config) = serde_json::from_str::<Config>(&existing_content) {
-            final_config.token = existing_config.token;
-            println!("Preserved existing token");
+    if final_config.token.is_none() {
+        if let Some((existing_config, _)) = &existing {
+            log::info!("Token not provided, preserving the existing one");
+            final_config.token = existing_config.token.clone();
         }
     }
 
+    if let Some(token) = &final_config.token {
+        remember_secret(token);
+    }
+
+    final_config.token_channel = normalize_token_channels(final_config.token_channel)?;
+
+    // Keep the existing ciphertext for an unchanged token; DPAPI output differs on
+    // every call, and the bot compares the stored value to spot token changes
+    final_config.token = match (final_config.token.take(), existing) {
+        (Some(token), Some((existing_config, Some(stored))))
+            if stored.starts_with(ENCRYPTED_TOKEN_PREFIX) && existing_config.token.as_deref() == Some(token.as_str()) =>
+        {
+            Some(stored)
+        }
+        (Some(token), _) => Some(encrypt_token(&token)),
+        (None, _) => None,
+    };
+
     let content = serde_json::to_string_pretty(&final_config)
         .map_err(|e| format!("Failed to serialize config: {}", e))?;
 
-    fs::write(&config_path, content)
+    write_file_atomic(&config_path, content)
         .map_err(|e| format!("Failed to write config: {}", e))
 }
 
 #[tauri::command]
-fn start_bot(state: tauri::State<AppState>, app: tauri::AppHandle) -> Result<String, String> {
-    println!("start_bot command called");
-    let mut bot = state.bot.lock().unwrap();
+fn get_token_channels(app: tauri::AppHandle) -> Result<std::collections::BTreeMap<String, String>, String> {
+    Ok(get_config(app)?.token_channel.0)
+}
 
-    if bot.process.is_some() {
-        println!("Bot process already exists, returning error");
-        return Err("Bot is already running".to_string());
+// Replace the region -> channel map; save_config validates it
+#[tauri::command]
+fn set_token_channels(app: tauri::AppHandle, channels: std::collections::BTreeMap<String, String>) -> Result<(), String> {
+    let mut config = get_config(app.clone())?;
+    config.token_channel = TokenChannels(channels);
+    save_config(app, config)
+}
+
+// Serializes add_character/remove_character so their read-modify-write of
+// config.json can't interleave
+static ROSTER_LOCK: Mutex<()> = Mutex::new(());
+
+fn same_character(a: &Character, b: &Character) -> bool {
+    a.name.trim().eq_ignore_ascii_case(b.name.trim())
+        && a.realm.trim().eq_ignore_ascii_case(b.realm.trim())
+        && a.region.trim().eq_ignore_ascii_case(b.region.trim())
+}
+
+// Append one character to the roster, returning the updated list
+#[tauri::command]
+fn add_character(app: tauri::AppHandle, character: Character) -> Result<Vec<Character>, String> {
+    let _guard = ROSTER_LOCK.lock().unwrap();
+    let mut config = get_config(app.clone())?;
+
+    let character = Character {
+        name: character.name.trim().to_string(),
+        realm: character.realm.trim().to_string(),
+        region: character.region.trim().to_lowercase(),
+    };
+    if config.characters.iter().any(|existing| same_character(existing, &character)) {
+        return Err(format!(
+            "{} - {} ({}) is already in the roster",
+            character.name, character.realm, character.region
+        ));
+    }
+
+    config.characters.push(character);
+    let characters = config.characters.clone();
+    save_config(app, config)?;
+    Ok(characters)
+}
+
+// Remove the matching character (case-insensitive), returning the updated list
+#[tauri::command]
+fn remove_character(app: tauri::AppHandle, name: String, realm: String, region: String) -> Result<Vec<Character>, String> {
+    let _guard = ROSTER_LOCK.lock().unwrap();
+    let mut config = get_config(app.clone())?;
+
+    let target = Character { name, realm, region };
+    let before = config.characters.len();
+    config.characters.retain(|existing| !same_character(existing, &target));
+    if config.characters.len() == before {
+        return Err(format!(
+            "{} - {} ({}) is not in the roster",
+            target.name, target.realm, target.region
+        ));
+    }
+
+    let characters = config.characters.clone();
+    save_config(app, config)?;
+    Ok(characters)
+}
+
+const BLIZZARD_OAUTH_URL: &str = "https://oauth.battle.net/token";
+const BLIZZARD_REQUEST_TIMEOUT_SECS: u64 = 10;
+
+#[derive(Serialize)]
+struct CharacterVerification {
+    exists: bool,
+    // Name and realm as Blizzard spells them, plus class/level, when found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    realm: Option<String>,
+    #[serde(rename = "characterClass", skip_serializing_if = "Option::is_none")]
+    character_class: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    level: Option<u32>,
+}
+
+// Realm names become slugs the same way the bot's Blizzard client does it
+fn realm_slug(realm: &str) -> String {
+    realm.trim().to_lowercase().replace('\'', "").split_whitespace().collect::<Vec<_>>().join("-")
+}
+
+// Looks the character up on the Blizzard profile API so typos are caught before
+// the bot silently syncs nothing for them
+#[tauri::command]
+async fn verify_character(app: tauri::AppHandle, character: Character) -> Result<CharacterVerification, String> {
+    let region = character.region.trim().to_lowercase();
+    if !TOKEN_REGIONS.contains(&region.as_str()) {
+        return Err(format!("Region must be one of: {}", TOKEN_REGIONS.join(", ")));
+    }
+    if character.name.trim().is_empty() || character.realm.trim().is_empty() {
+        return Err("Character name and realm are required".to_string());
+    }
+
+    let credentials = get_blizzard_credentials(app)?;
+    if credentials.client_id.trim().is_empty() || credentials.client_secret.trim().is_empty() {
+        return Err("Blizzard API credentials are not set. Add your client ID and secret in Settings first.".to_string());
+    }
+
+    let client = reqwest::Client::new();
+    let timeout = std::time::Duration::from_secs(BLIZZARD_REQUEST_TIMEOUT_SECS);
+
+    let response = client
+        .post(BLIZZARD_OAUTH_URL)
+        .basic_auth(credentials.client_id.trim(), Some(credentials.client_secret.trim()))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body("grant_type=client_credentials")
+        .timeout(timeout)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Blizzard: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!(
+            "Blizzard rejected the API credentials ({}). Check the client ID and secret in Settings.",
+            response.status()
+        ));
+    }
+    let oauth: serde_json::Value = response.json().await
+        .map_err(|e| format!("Failed to parse Blizzard token response: {}", e))?;
+    let access_token = oauth["access_token"].as_str()
+        .ok_or("Blizzard token response had no access token")?;
+
+    let host = if region == "cn" {
+        "https://gateway.battlenet.com.cn".to_string()
+    } else {
+        format!("https://{}.api.blizzard.com", region)
+    };
+    let mut url = Url::parse(&host)
+        .map_err(|e| format!("Invalid Blizzard API URL: {}", e))?;
+    url.path_segments_mut()
+        .map_err(|_| "Invalid Blizzard API URL".to_string())?
+        .extend(["profile", "wow", "character"])
+        .push(&realm_slug(&character.realm))
+        .push(&character.name.trim().to_lowercase());
+    url.query_pairs_mut()
+        .append_pair("namespace", &format!("profile-{}", region))
+        .append_pair("locale", "en_US");
+
+    let response = client
+        .get(url)
+        .bearer_auth(access_token)
+        .timeout(timeout)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Blizzard: {}", e))?;
+
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(CharacterVerification {
+            exists: false,
+            name: None,
+            realm: None,
+            character_class: None,
+            level: None,
+        });
+    }
+    if !response.status().is_success() {
+        return Err(format!("Blizzard API error looking up character: {}", response.status()));
+    }
+
+    let profile: serde_json::Value = response.json().await
+        .map_err(|e| format!("Failed to parse character profile: {}", e))?;
+    Ok(CharacterVerification {
+        exists: true,
+        name: profile["name"].as_str().map(str::to_string),
+        realm: profile["realm"]["name"].as_str().map(str::to_string),
+        character_class: profile["character_class"]["name"].as_str().map(str::to_string),
+        level: profile["level"].as_u64().map(|level| level as u32),
+    })
+}
+
+#[derive(Deserialize)]
+struct RaiderIoGuild {
+    #[serde(default)]
+    members: Vec<RaiderIoGuildMember>,
+}
+
+#[derive(Deserialize)]
+struct RaiderIoGuildMember {
+    character: RaiderIoCharacter,
+}
+
+#[derive(Deserialize)]
+struct RaiderIoCharacter {
+    name: String,
+    realm: String,
+    region: String,
+}
+
+// Fetch a guild's members from Raider.IO so the UI can pick which to add.
+// Characters already in config.json are left out.
+#[tauri::command]
+async fn import_guild_roster(
+    app: tauri::AppHandle,
+    realm: String,
+    region: String,
+    guild_name: String,
+) -> Result<Vec<Character>, String> {
+    let region = region.trim().to_lowercase();
+    if !TOKEN_REGIONS.contains(&region.as_str()) {
+        return Err(format!("Region must be one of: {}", TOKEN_REGIONS.join(", ")));
+    }
+    if realm.trim().is_empty() || guild_name.trim().is_empty() {
+        return Err("Realm and guild name are required".to_string());
+    }
+
+    log::debug!("import_guild_roster called for {} on {}-{}", guild_name, realm, region);
+
+    let response = reqwest::Client::new()
+        .get("https://raider.io/api/v1/guilds/profile")
+        .query(&[
+            ("region", region.as_str()),
+            ("realm", realm.trim()),
+            ("name", guild_name.trim()),
+            ("fields", "members"),
+        ])
+        .header("User-Agent", "DaeBot")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Raider.IO: {}", e))?;
+
+    let status = response.status();
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err("Raider.IO rate limit reached. Please wait a minute and try again.".to_string());
+    }
+    // Raider.IO answers an unknown guild with 400/404
+    if status == reqwest::StatusCode::BAD_REQUEST || status == reqwest::StatusCode::NOT_FOUND {
+        return Err(format!("Guild '{}' was not found on {}-{}", guild_name.trim(), realm.trim(), region));
+    }
+    if !status.is_success() {
+        return Err(format!("Raider.IO returned an error: {}", status));
+    }
+
+    let guild: RaiderIoGuild = response.json().await
+        .map_err(|e| format!("Failed to parse guild roster: {}", e))?;
+
+    // Raider.IO gives display realm names ("Area 52"); the roster stores slugs
+    let mut known = get_config(app)
+        .map(|config| config.characters)
+        .unwrap_or_default();
+
+    let mut characters = Vec::new();
+    for member in guild.members {
+        let character = Character {
+            name: member.character.name,
+            realm: realm_slug(&member.character.realm),
+            region: member.character.region.to_lowercase(),
+        };
+
+        if !known.iter().any(|existing| same_character(existing, &character)) {
+            known.push(character.clone());
+            characters.push(character);
+        }
+    }
+
+    log::info!("Found {} new character(s) in guild roster", characters.len());
+    Ok(characters)
+}
+
+// Windows error codes for files blocked or removed by antivirus
+const ERROR_ACCESS_DENIED: i32 = 5;
+const ERROR_VIRUS_INFECTED: i32 = 225;
+const ERROR_VIRUS_DELETED: i32 = 226;
+
+// Turn a bot.exe spawn failure into something actionable. The search already
+// found the file, so a failure here usually means antivirus got to it.
+fn describe_bot_spawn_error(bot_exe: &PathBuf, err: &std::io::Error) -> String {
+    let quarantine_hint = "This is usually caused by antivirus software (such as Windows Defender) \
+        quarantining or blocking bot.exe. Add an exclusion for the DaeBot install folder \
+        and reinstall or restore the file.";
+
+    let blocked = err.kind() == std::io::ErrorKind::PermissionDenied
+        || matches!(err.raw_os_error(), Some(ERROR_ACCESS_DENIED | ERROR_VIRUS_INFECTED | ERROR_VIRUS_DELETED));
+
+    if blocked {
+        return format!("bot.exe at {:?} exists but could not be executed ({}). {}", bot_exe, err, quarantine_hint);
+    }
+
+    // Found a moment ago but gone or unreadable now
+    if !bot_exe.exists() {
+        return format!("bot.exe at {:?} was removed before it could start ({}). {}", bot_exe, err, quarantine_hint);
+    }
+    if let Err(open_err) = fs::File::open(bot_exe) {
+        return format!("bot.exe at {:?} exists but cannot be read ({}). {}", bot_exe, open_err, quarantine_hint);
+    }
+
+    format!("Failed to start bot.exe from {:?}: {}", bot_exe, err)
+}
+
+// Build the command that launches the bot: node main.js in development,
+// the bundled bot.exe in production. Also returns the script/executable path.
+fn build_bot_command(app: &tauri::AppHandle) -> Result<(Command, PathBuf), String> {
+    let settings = get_settings(app.clone()).ok();
+    let configured_path = |value: Option<&String>| {
+        value.map(|v| v.trim()).filter(|v| !v.is_empty()).map(PathBuf::from)
+    };
+
+    let configured_exe = configured_path(settings.as_ref().and_then(|s| s.bot_executable_path.as_ref()));
+    if let Some(exe) = &configured_exe {
+        if !exe.is_file() {
+            return Err(format!("Configured bot executable does not exist: {:?}", exe));
+        }
     }
 
-    println!("No existing bot process, starting new one");
+    let node = match configured_path(settings.as_ref().and_then(|s| s.node_path.as_ref())) {
+        Some(node) if !node.is_file() => {
+            return Err(format!("Configured Node path does not exist: {:?}", node));
+        }
+        Some(node) => node,
+        None => PathBuf::from("node"),
+    };
 
     // Use CARGO_MANIFEST_DIR environment variable to get project root
     // In dev mode, this points to src-tauri, so we go up one level
-    let (project_root, bot_exe_path) = if cfg!(debug_assertions) {
+    let (project_root, bot_exe_path) = if let Some(exe) = configured_exe.clone() {
+        let work_dir = exe.parent()
+            .ok_or("Failed to get bot executable parent directory")?
+            .to_path_buf();
+        (work_dir, exe)
+    } else if cfg!(debug_assertions) {
         // Development mode - go up from src-tauri to project root
         let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
             .parent()
@@ -258,7 +1336,7 @@ fn start_bot(state: tauri::State<AppState>, app: tauri::AppHandle) -> Result<Str
         // Production mode - try multiple possible locations for bot.exe
         let resource_dir = app.path().resource_dir()
             .map_err(|e| format!("Failed to get resource directory: {}", e))?;
-        println!("Resource directory: {:?}", resource_dir);
+        log::debug!("Resource directory: {:?}", resource_dir);
 
         let mut checked_paths = Vec::new();
         let mut found = false;
@@ -354,7 +1432,7 @@ fn start_bot(state: tauri::State<AppState>, app: tauri::AppHandle) -> Result<Str
             return Err(error_msg);
         }
 
-        println!("Found bot.exe at: {:?}", bot_exe);
+        log::debug!("Found bot.exe at: {:?}", bot_exe);
 
         // Use the directory containing bot.exe as the working directory
         let work_dir = bot_exe.parent()
@@ -364,203 +1442,929 @@ fn start_bot(state: tauri::State<AppState>, app: tauri::AppHandle) -> Result<Str
         (work_dir, bot_exe)
     };
 
-    println!("Working directory: {:?}", project_root);
-    println!("Bot executable: {:?}", bot_exe_path);
+    // A user-configured working directory overrides the guess above
+    let project_root = match settings.and_then(|s| s.bot_working_dir) {
+        Some(dir) if !dir.trim().is_empty() => {
+            let dir = PathBuf::from(dir.trim());
+            if !dir.is_dir() {
+                return Err(format!("Configured bot working directory does not exist: {:?}", dir));
+            }
+            dir
+        }
+        _ => project_root,
+    };
+
+    log::debug!("Working directory: {:?}", project_root);
+    log::debug!("Bot executable: {:?}", bot_exe_path);
 
     // In production, use the bundled bot.exe
     // In development, use node main.js for easier debugging
-    let child = if cfg!(debug_assertions) {
-        // Development mode - use node
-        Command::new("node")
-            .arg("main.js")
-            .current_dir(&project_root)
-            .spawn()
-            .map_err(|e| format!("Failed to start bot from {:?}: {}", project_root, e))?
+    let runs_with_node = match &configured_exe {
+        Some(exe) => exe.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("js")),
+        None => cfg!(debug_assertions),
+    };
+    let mut command = if runs_with_node {
+        let mut command = Command::new(&node);
+        command.arg(&bot_exe_path);
+        command
     } else {
-        // Production mode - use bot.exe without console window
-        #[cfg(target_os = "windows")]
-        {
-            use std::os::windows::process::CommandExt;
-            const CREATE_NO_WINDOW: u32 = 0x08000000;
+        Command::new(&bot_exe_path)
+    };
+
+    // Production mode - run the bot without a console window
+    #[cfg(target_os = "windows")]
+    if !cfg!(debug_assertions) {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+    command.current_dir(&project_root);
+
+    // config.json may only hold the encrypted token
+    if let Some(token) = get_config(app.clone()).ok().and_then(|config| config.token) {
+        command.env(BOT_TOKEN_ENV, token);
+    }
+
+    Ok((command, bot_exe_path))
+}
+
+fn bot_spawn_error(bot_exe_path: &PathBuf, err: &std::io::Error) -> String {
+    if cfg!(debug_assertions) {
+        format!("Failed to start bot from {:?}: {}", bot_exe_path, err)
+    } else {
+        describe_bot_spawn_error(bot_exe_path, err)
+    }
+}
+
+// Start the bot without the GUI. Output goes to bot-output.log because no
+// window will be around to drain the pipes once this process exits.
+fn headless_start_bot(app: &tauri::AppHandle, wait_for_ready: bool) -> Result<String, String> {
+    ensure_no_running_bot(app)?;
+    let app_dir = app_data_dir(app)?;
+    fs::create_dir_all(&app_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
 
-            Command::new(&bot_exe_path)
-                .current_dir(&project_root)
-                .creation_flags(CREATE_NO_WINDOW)
-                .spawn()
-                .map_err(|e| format!("Failed to start bot.exe from {:?}: {}", bot_exe_path, e))?
+    let output_path = app_dir.join("bot-output.log");
+    let stdout = fs::File::create(&output_path)
+        .map_err(|e| format!("Failed to create {:?}: {}", output_path, e))?;
+    let stderr = stdout.try_clone()
+        .map_err(|e| format!("Failed to open {:?} for stderr: {}", output_path, e))?;
+
+    let (mut command, bot_exe_path) = build_bot_command(app)?;
+    let mut child = command
+        .stdout(stdout)
+        .stderr(stderr)
+        .spawn()
+        .map_err(|e| bot_spawn_error(&bot_exe_path, &e))?;
+    let pid = child.id();
+    record_bot_pid(app, pid);
+
+    if !wait_for_ready {
+        return Ok(format!("Bot started (PID {}), output in {:?}", pid, output_path));
+    }
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(BOT_START_TIMEOUT_SECS);
+    while std::time::Instant::now() < deadline {
+        if let Ok(Some(status)) = child.try_wait() {
+            return Err(format!("Bot exited during startup ({}), see {:?}", status, output_path));
         }
 
-        #[cfg(not(target_os = "windows"))]
-        {
-            Command::new(&bot_exe_path)
-                .current_dir(&project_root)
-                .spawn()
-                .map_err(|e| format!("Failed to start bot.exe from {:?}: {}", bot_exe_path, e))?
+        let ready = fs::read(&output_path)
+            .map(|bytes| String::from_utf8_lossy(&bytes).contains(BOT_READY_MARKER))
+            .unwrap_or(false);
+        if ready {
+            return Ok(format!("Bot started and ready (PID {})", pid));
         }
-    };
 
-    bot.process = Some(child);
-    bot.status = "running".to_string();
+        std::thread::sleep(std::time::Duration::from_millis(500));
+    }
 
-    Ok("Bot started successfully".to_string())
+    Err(format!("Bot (PID {}) did not report ready within {}s, see {:?}", pid, BOT_START_TIMEOUT_SECS, output_path))
+}
+
+// Run headless_start_bot and turn the outcome into a process exit code
+fn run_headless_start(app: &tauri::AppHandle, wait_for_ready: bool) -> i32 {
+    // Release builds use the GUI subsystem; borrow the launching console so
+    // scripts can see the result
+    #[cfg(target_os = "windows")]
+    unsafe {
+        use windows_sys::Win32::System::Console::{AttachConsole, ATTACH_PARENT_PROCESS};
+        AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+
+    match headless_start_bot(app, wait_for_ready) {
+        Ok(message) => {
+            println!("{}", message);
+            0
+        }
+        Err(e) => {
+            eprintln!("Failed to start bot: {}", e);
+            1
+        }
+    }
 }
 
 #[tauri::command]
-fn stop_bot(state: tauri::State<AppState>, app: tauri::AppHandle) -> Result<String, String> {
-    println!("stop_bot called");
+fn start_bot(state: tauri::State<AppState>, app: tauri::AppHandle) -> Result<String, String> {
+    log::debug!("start_bot command called");
+    let mut bot = state.bot.lock().unwrap();
 
-    // First, extract the process and set status to "stopping"
-    let process_opt = {
+    if bot.process.is_some() {
+        log::debug!("Bot process already exists, returning error");
+        return Err("Bot is already running".to_string());
+    }
+    // stop_bot and restart_bot take the process out while it shuts down
+    if bot.status == "stopping" {
+        return Err("Bot is still stopping; try again once it has exited".to_string());
+    }
+
+    log::debug!("No existing bot process, starting new one");
+
+    spawn_bot(&state, &app, &mut bot)?;
+
+    Ok("Bot started successfully".to_string())
+}
+
+// Shared spawn path for start_bot and restart_bot; the caller holds the bot lock
+fn spawn_bot(state: &AppState, app: &tauri::AppHandle, bot: &mut BotState) -> Result<u32, String> {
+    ensure_no_running_bot(app)?;
+    let (mut command, bot_exe_path) = build_bot_command(app)?;
+    clear_shutdown_request(app);
+
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| bot_spawn_error(&bot_exe_path, &e))?;
+
+    // Start a fresh output history for this run and capture the bot's output
+    state.output.lock().unwrap().clear();
+    if let Some(stdout) = child.stdout.take() {
+        spawn_output_reader(app.clone(), stdout, "stdout");
+    }
+    if let Some(stderr) = child.stderr.take() {
+        spawn_output_reader(app.clone(), stderr, "stderr");
+    }
+
+    let pid = child.id();
+    record_bot_pid(app, pid);
+    bot.process = Some(child);
+    bot.status = "running".to_string();
+    bot.ready = false;
+    bot.last_probe = None;
+    bot.last_exit = None;
+    bot.started_at = Some(std::time::Instant::now());
+    emit_bot_status(app, "running", Some(pid));
+    spawn_start_watchdog(app.clone(), pid);
+
+    Ok(pid)
+}
+
+// How long restart_bot waits, after the forced kill, for the old process to exit
+const RESTART_EXIT_TIMEOUT_SECS: u64 = 10;
+
+// Stops the bot, waits for it to actually exit, then starts it again
+#[tauri::command]
+async fn restart_bot(app: tauri::AppHandle) -> Result<String, String> {
+    // Hold "stopping" while we wait; start_bot refuses to spawn in that state
+    let (old_process, old_started_at) = {
+        let state = app.state::<AppState>();
         let mut bot = state.bot.lock().unwrap();
-        if bot.process.is_some() {
+        let process = bot.process.take();
+        let started_at = bot.started_at.take();
+        if let Some(ref process) = process {
             bot.status = "stopping".to_string();
-            bot.process.take()
-        } else {
-            None
+            bot.last_probe = None;
+            emit_bot_status(&app, "stopping", Some(process.id()));
         }
+        (process, started_at)
     };
 
-    if let Some(mut process) = process_opt {
+    if let Some(mut process) = old_process {
         let pid = process.id();
-        println!("Killing bot process with PID: {}", pid);
-
-        // Spawn background task to kill the process using Tauri's async runtime
-        tauri::async_runtime::spawn(async move {
-            // On Windows, use taskkill for forceful termination without showing window
-            #[cfg(target_os = "windows")]
-            {
-                use std::os::windows::process::CommandExt;
-                const CREATE_NO_WINDOW: u32 = 0x08000000;
-
-                let kill_result = Command::new("taskkill")
-                    .args(["/F", "/T", "/PID", &pid.to_string()])
-                    .creation_flags(CREATE_NO_WINDOW)
-                    .output();
-
-                match kill_result {
-                    Ok(output) => {
-                        println!("taskkill output: {:?}", String::from_utf8_lossy(&output.stdout));
-                        if !output.status.success() {
-                            println!("taskkill stderr: {:?}", String::from_utf8_lossy(&output.stderr));
-                        }
-                    },
-                    Err(e) => {
-                        println!("taskkill command failed: {}", e);
-                        // Fallback to regular kill
-                        let _ = process.kill();
-                    }
-                }
-            }
+        log::info!("Restarting bot, stopping PID: {}", pid);
 
-            // On non-Windows systems, use regular kill
-            #[cfg(not(target_os = "windows"))]
-            {
-                let _ = process.kill();
-            }
+        // Wait on a blocking thread so the grace period doesn't freeze the UI
+        let wait_app = app.clone();
+        let (mut process, exited) = tauri::async_runtime::spawn_blocking(move || {
+            request_graceful_stop(&wait_app, process.id());
 
-            // Set final status to "stopped" using app state
-            if let Some(state) = app.try_state::<AppState>() {
-                let mut bot = state.bot.lock().unwrap();
-                bot.status = "stopped".to_string();
-                println!("Bot stopped successfully");
+            let mut exited = wait_for_exit(&mut process, shutdown_grace(&wait_app));
+            if exited.is_none() {
+                send_kill(&mut process);
+                exited = wait_for_exit(&mut process, std::time::Duration::from_secs(RESTART_EXIT_TIMEOUT_SECS));
             }
-        });
-
-        // Return immediately - the UI won't freeze
-        Ok("Bot is stopping".to_string())
-    } else {
-        println!("Bot is not running");
-        Err("Bot is not running".to_string())
+            clear_shutdown_request(&wait_app);
+            (process, exited)
+        })
+        .await
+        .map_err(|e| format!("Failed to wait for the bot to exit: {}", e))?;
+
+        if exited.is_none() {
+            let state = app.state::<AppState>();
+            let mut bot = state.bot.lock().unwrap();
+            if bot.process.is_none() {
+                // Keep tracking the survivor rather than starting a second bot
+                bot.process = Some(process);
+                bot.started_at = old_started_at;
+                bot.status = "running".to_string();
+                bot.last_probe = None;
+                emit_bot_status(&app, "running", Some(pid));
+            } else {
+                // Never overwrite a tracked bot; this one can't be kept as well
+                log::warn!("Bot (PID {}) survived a restart while another bot is tracked", pid);
+                send_kill(&mut process);
+            }
+            return Err(format!(
+                "Bot (PID {}) did not exit within {}s; not starting another one",
+                pid, RESTART_EXIT_TIMEOUT_SECS
+            ));
+        }
     }
-}
 
-#[tauri::command]
-fn get_bot_status(state: tauri::State<AppState>) -> String {
+    let state = app.state::<AppState>();
     let mut bot = state.bot.lock().unwrap();
+    if bot.process.is_some() {
+        return Err("Bot was started while restarting".to_string());
+    }
 
-    // Check if the process is actually still running
-    if let Some(ref mut process) = bot.process {
-        match process.try_wait() {
-            Ok(Some(_)) => {
-                // Process has exited
-                bot.process = None;
-                bot.status = "stopped".to_string();
-            }
-            Ok(None) => {
-                // Process is still running
-                bot.status = "running".to_string();
-            }
-            Err(_) => {
-                // Error checking process status
-                bot.process = None;
-                bot.status = "stopped".to_string();
-            }
+    match spawn_bot(&state, &app, &mut bot) {
+        Ok(pid) => Ok(format!("Bot restarted (PID {})", pid)),
+        Err(e) => {
+            bot.status = "stopped".to_string();
+            emit_bot_status(&app, "stopped", None);
+            Err(e)
         }
-    } else {
-        bot.status = "stopped".to_string();
     }
+}
 
-    bot.status.clone()
+#[derive(Clone, Serialize)]
+struct BotStartTimeoutEvent {
+    pid: u32,
+    #[serde(rename = "timeoutSecs")]
+    timeout_secs: u64,
 }
 
-#[tauri::command]
-fn quit_app(app: tauri::AppHandle, state: tauri::State<AppState>) {
-    println!("Quit command received, stopping bot and exiting application");
+// Flag a bot that is still alive but never reported ready, so the UI can
+// offer to kill it instead of showing "running" forever
+fn spawn_start_watchdog(app: tauri::AppHandle, pid: u32) {
+    std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_secs(BOT_START_TIMEOUT_SECS));
 
-    // Stop the bot if it's running
-    let mut bot = state.bot.lock().unwrap();
-    if let Some(process) = bot.process.take() {
-        let pid = process.id();
-        println!("Stopping bot process with PID: {}", pid);
+        let Some(state) = app.try_state::<AppState>() else {
+            return;
+        };
+        let mut bot = state.bot.lock().unwrap();
 
-        #[cfg(target_os = "windows")]
-        {
-            let _ = Command::new("taskkill")
-                .args(["/F", "/T", "/PID", &pid.to_string()])
-                .output();
-        }
+        // Only care about the process this watchdog was started for
+        let ready = bot.ready;
+        let hung = match bot.process.as_mut() {
+            Some(process) if process.id() == pid => {
+                !ready && matches!(process.try_wait(), Ok(None))
+            }
+            _ => false,
+        };
 
-        #[cfg(not(target_os = "windows"))]
-        {
-            let _ = process.kill();
+        if hung {
+            log::warn!("Bot (PID {}) did not report ready within {}s", pid, BOT_START_TIMEOUT_SECS);
+            let _ = app.emit("bot-start-timeout", BotStartTimeoutEvent {
+                pid,
+                timeout_secs: BOT_START_TIMEOUT_SECS,
+            });
         }
-
-        bot.status = "stopped".to_string();
-    }
-    drop(bot); // Release the lock before exiting
-
-    app.exit(0);
+    });
 }
 
-#[tauri::command]
-async fn deploy_discord_commands(app: tauri::AppHandle) -> Result<String, String> {
-    println!("deploy_discord_commands command called");
+// Force-terminate a bot process, including any children it spawned, returning its exit code
+fn force_kill_process(process: &mut Child) -> Option<i32> {
+    send_kill(process);
+    process.wait().ok().map(exit_code)
+}
 
-    // Get the resource directory where dist-backend is bundled
-    let resource_dir = app.path().resource_dir()
-        .map_err(|e| format!("Failed to get resource dir: {}", e))?;
+// The bot runs its SIGINT cleanup (closing the databases and Discord) when this file
+// appears; Windows has no SIGTERM to send to a windowless process
+const SHUTDOWN_REQUEST_FILE: &str = "shutdown-requested";
 
-    println!("Resource directory: {:?}", resource_dir);
+const DEFAULT_SHUTDOWN_GRACE_SECS: u64 = 5;
+const MAX_SHUTDOWN_GRACE_SECS: u64 = 60;
 
-    // Check multiple possible locations for commands.json
-    // 1. Direct path (dev builds)
-    // 2. _up_ subdirectory (production builds with updates)
-    let possible_paths = vec![
-        resource_dir.join("dist-backend").join("commands.json"),
-        resource_dir.join("_up_").join("dist-backend").join("commands.json"),
-    ];
+fn shutdown_grace(app: &tauri::AppHandle) -> std::time::Duration {
+    let secs = get_settings(app.clone())
+        .ok()
+        .and_then(|settings| settings.shutdown_grace_secs)
+        .unwrap_or(DEFAULT_SHUTDOWN_GRACE_SECS);
+    std::time::Duration::from_secs(secs)
+}
 
-    let mut commands_file = None;
-    for path in &possible_paths {
-        println!("Checking path: {:?}", path);
-        if path.exists() {
-            commands_file = Some(path.clone());
-            println!("Found commands.json at: {:?}", path);
-            break;
+// Ask the bot to exit on its own: the shutdown request file, plus SIGTERM on
+// Unix or a taskkill without /F on Windows
+fn request_graceful_stop(app: &tauri::AppHandle, pid: u32) {
+    if let Ok(app_dir) = app_data_dir(app) {
+        if let Err(e) = fs::write(app_dir.join(SHUTDOWN_REQUEST_FILE), pid.to_string()) {
+            log::warn!("Failed to write shutdown request: {}", e);
         }
     }
 
-    let commands_file = commands_file.ok_or_else(|| {
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+        let _ = Command::new("taskkill")
+            .args(["/T", "/PID", &pid.to_string()])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output();
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = Command::new("kill")
+            .args(["-TERM", &pid.to_string()])
+            .output();
+    }
+}
+
+fn clear_shutdown_request(app: &tauri::AppHandle) {
+    if let Ok(app_dir) = app_data_dir(app) {
+        let _ = fs::remove_file(app_dir.join(SHUTDOWN_REQUEST_FILE));
+    }
+}
+
+// PID of the last bot started by the GUI or --start-bot, so neither starts a
+// second bot on the same token while one is still alive
+const BOT_PID_FILE: &str = "bot.pid";
+
+// Creation time of a running process, recorded next to the PID so a reused PID
+// isn't mistaken for the bot
+fn process_start_time(pid: u32) -> Option<String> {
+    #[cfg(target_os = "windows")]
+    unsafe {
+        use windows_sys::Win32::Foundation::{CloseHandle, FILETIME, STILL_ACTIVE};
+        use windows_sys::Win32::System::Threading::{GetExitCodeProcess, GetProcessTimes, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION};
+
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+        if handle.is_null() {
+            return None;
+        }
+        let empty = || FILETIME { dwLowDateTime: 0, dwHighDateTime: 0 };
+        let (mut created, mut exited, mut kernel, mut user) = (empty(), empty(), empty(), empty());
+        let mut code = 0u32;
+        let alive = GetExitCodeProcess(handle, &mut code) != 0 && code == STILL_ACTIVE as u32;
+        let timed = GetProcessTimes(handle, &mut created, &mut exited, &mut kernel, &mut user) != 0;
+        CloseHandle(handle);
+        if !alive || !timed {
+            return None;
+        }
+        Some((((created.dwHighDateTime as u64) << 32) | created.dwLowDateTime as u64).to_string())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        // C locale so the date format doesn't depend on the user's settings
+        Command::new("ps")
+            .args(["-o", "lstart=", "-p", &pid.to_string()])
+            .env("LC_ALL", "C")
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+            .filter(|start| !start.is_empty())
+    }
+}
+
+// Seconds since a process started, from the same start time bot.pid records
+fn process_uptime_secs(pid: u32) -> Option<u64> {
+    let start = process_start_time(pid)?;
+
+    // FILETIME: 100ns ticks since 1601-01-01
+    #[cfg(target_os = "windows")]
+    let started_at = (start.parse::<u64>().ok()? / 10_000_000).checked_sub(11_644_473_600)? as i64;
+
+    // lstart, e.g. "Thu Oct 15 10:04:31 2026", in local time
+    #[cfg(not(target_os = "windows"))]
+    let started_at = {
+        use chrono::TimeZone;
+        let naive = chrono::NaiveDateTime::parse_from_str(&start, "%a %b %e %H:%M:%S %Y").ok()?;
+        chrono::Local.from_local_datetime(&naive).earliest()?.timestamp()
+    };
+
+    Some(chrono::Utc::now().timestamp().saturating_sub(started_at).max(0) as u64)
+}
+
+// Whether `pid` is still the process that was started at `start`
+fn is_bot_process(pid: u32, start: &str) -> bool {
+    process_start_time(pid).is_some_and(|current| current == start)
+}
+
+// The recorded bot PID if that same process is still running; a stale file is removed
+fn running_bot_pid(app: &tauri::AppHandle) -> Option<u32> {
+    let path = app_data_dir(app).ok()?.join(BOT_PID_FILE);
+    let content = fs::read_to_string(&path).ok()?;
+    let recorded = content.trim().split_once(' ')
+        .and_then(|(pid, start)| pid.parse::<u32>().ok().map(|pid| (pid, start.to_string())));
+    match recorded {
+        Some((pid, start)) if is_bot_process(pid, &start) => Some(pid),
+        _ => {
+            let _ = fs::remove_file(&path);
+            None
+        }
+    }
+}
+
+fn record_bot_pid(app: &tauri::AppHandle, pid: u32) {
+    let Some(start) = process_start_time(pid) else {
+        log::warn!("Failed to read start time of bot process {}, not recording it", pid);
+        return;
+    };
+    if let Ok(app_dir) = app_data_dir(app) {
+        if let Err(e) = fs::write(app_dir.join(BOT_PID_FILE), format!("{} {}", pid, start)) {
+            log::warn!("Failed to write bot PID file: {}", e);
+        }
+    }
+}
+
+fn clear_bot_pid(app: &tauri::AppHandle) {
+    if let Ok(app_dir) = app_data_dir(app) {
+        let _ = fs::remove_file(app_dir.join(BOT_PID_FILE));
+    }
+}
+
+fn ensure_no_running_bot(app: &tauri::AppHandle) -> Result<(), String> {
+    match running_bot_pid(app) {
+        Some(pid) => Err(format!(
+            "Another bot is already running (PID {}), possibly started by another DaeBot instance or --start-bot. Stop it first.",
+            pid
+        )),
+        None => Ok(()),
+    }
+}
+
+// Poll try_wait until the process exits or `timeout` passes
+fn wait_for_exit(process: &mut Child, timeout: std::time::Duration) -> Option<std::process::ExitStatus> {
+    let deadline = std::time::Instant::now() + timeout;
+    loop {
+        match process.try_wait() {
+            Ok(Some(status)) => return Some(status),
+            Ok(None) if std::time::Instant::now() < deadline => {
+                std::thread::sleep(std::time::Duration::from_millis(100));
+            }
+            _ => return None,
+        }
+    }
+}
+
+// Graceful stop, escalating to a forced kill after the grace period; returns the exit code
+fn stop_process(app: &tauri::AppHandle, process: &mut Child) -> Option<i32> {
+    let grace = shutdown_grace(app);
+    request_graceful_stop(app, process.id());
+
+    let exit = match wait_for_exit(process, grace) {
+        Some(status) => Some(exit_code(status)),
+        None => {
+            log::warn!("Bot (PID {}) did not exit within {}s, forcing it", process.id(), grace.as_secs());
+            force_kill_process(process)
+        }
+    };
+
+    clear_shutdown_request(app);
+    clear_bot_pid(app);
+    exit
+}
+
+// Kill the bot and its children without waiting for it to exit
+fn send_kill(process: &mut Child) {
+    #[cfg(target_os = "windows")]
+    {
+        if !kill_pid(process.id()) {
+            let _ = process.kill();
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = process.kill();
+    }
+}
+
+// Force-kill a process (and on Windows its children) that this instance has no Child for
+fn kill_pid(pid: u32) -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+        Command::new("taskkill")
+            .args(["/F", "/T", "/PID", &pid.to_string()])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        Command::new("kill")
+            .args(["-KILL", &pid.to_string()])
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+    }
+}
+
+// Stop a bot from bot.pid that this instance didn't spawn (a --start-bot bot, or one
+// left behind by an app restart): graceful stop, then kill once the grace period passes
+fn stop_recorded_bot(app: &tauri::AppHandle, pid: u32) {
+    let Some(start) = process_start_time(pid) else {
+        clear_bot_pid(app);
+        return;
+    };
+    let grace = shutdown_grace(app);
+    request_graceful_stop(app, pid);
+
+    let deadline = std::time::Instant::now() + grace;
+    while is_bot_process(pid, &start) && std::time::Instant::now() < deadline {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    if is_bot_process(pid, &start) {
+        log::warn!("Bot (PID {}) did not exit within {}s, forcing it", pid, grace.as_secs());
+        if !kill_pid(pid) {
+            log::error!("Failed to kill bot process {}", pid);
+        }
+    }
+
+    clear_shutdown_request(app);
+    clear_bot_pid(app);
+}
+
+// Run stop_recorded_bot off the main thread and report the bot as stopped afterwards
+fn spawn_stop_recorded_bot(app: tauri::AppHandle, pid: u32) {
+    if let Some(state) = app.try_state::<AppState>() {
+        let mut bot = state.bot.lock().unwrap();
+        bot.status = "stopping".to_string();
+        bot.ready = false;
+        bot.last_probe = None;
+    }
+    emit_bot_status(&app, "stopping", Some(pid));
+
+    tauri::async_runtime::spawn_blocking(move || {
+        stop_recorded_bot(&app, pid);
+
+        if let Some(state) = app.try_state::<AppState>() {
+            let mut bot = state.bot.lock().unwrap();
+            bot.status = "stopped".to_string();
+            bot.last_exit = None;
+        }
+        log::info!("Recorded bot process {} stopped", pid);
+        emit_bot_status(&app, "stopped", None);
+    });
+}
+
+#[tauri::command]
+fn kill_hung_bot(state: tauri::State<AppState>, app: tauri::AppHandle) -> Result<String, String> {
+    let mut bot = state.bot.lock().unwrap();
+
+    let Some(mut process) = bot.process.take() else {
+        drop(bot);
+        let pid = running_bot_pid(&app).ok_or("Bot is not running")?;
+        log::info!("Stopping bot process {} from {}", pid, BOT_PID_FILE);
+        spawn_stop_recorded_bot(app, pid);
+        return Ok(format!("Stopping bot process {}", pid));
+    };
+    bot.started_at = None;
+    let pid = process.id();
+    log::info!("Force-killing hung bot process with PID: {}", pid);
+
+    bot.last_exit = force_kill_process(&mut process);
+    clear_bot_pid(&app);
+
+    bot.status = "stopped".to_string();
+    bot.ready = false;
+    bot.last_probe = None;
+    emit_bot_status(&app, "stopped", None);
+
+    Ok(format!("Killed bot process {}", pid))
+}
+
+#[tauri::command]
+fn stop_bot(state: tauri::State<AppState>, app: tauri::AppHandle) -> Result<String, String> {
+    log::debug!("stop_bot called");
+
+    // First, extract the process and set status to "stopping"
+    let process_opt = {
+        let mut bot = state.bot.lock().unwrap();
+        if bot.process.is_some() {
+            bot.status = "stopping".to_string();
+            bot.last_probe = None;
+            bot.started_at = None;
+            bot.process.take()
+        } else {
+            None
+        }
+    };
+
+    if let Some(mut process) = process_opt {
+        let pid = process.id();
+        log::info!("Stopping bot process with PID: {}", pid);
+        emit_bot_status(&app, "stopping", Some(pid));
+
+        // Stop on a blocking thread so the grace period doesn't freeze the UI
+        tauri::async_runtime::spawn_blocking(move || {
+            let exit = stop_process(&app, &mut process);
+
+            // Set final status to "stopped" using app state
+            if let Some(state) = app.try_state::<AppState>() {
+                let mut bot = state.bot.lock().unwrap();
+                bot.status = "stopped".to_string();
+                bot.last_exit = exit;
+                log::info!("Bot stopped successfully");
+            }
+            emit_bot_status(&app, "stopped", None);
+        });
+
+        Ok("Bot is stopping".to_string())
+    } else if let Some(pid) = running_bot_pid(&app) {
+        log::info!("Stopping bot process {} from {}", pid, BOT_PID_FILE);
+        spawn_stop_recorded_bot(app, pid);
+        Ok("Bot is stopping".to_string())
+    } else {
+        log::info!("Bot is not running");
+        Err("Bot is not running".to_string())
+    }
+}
+
+#[tauri::command]
+fn get_bot_status(state: tauri::State<AppState>, app: tauri::AppHandle) -> String {
+    let mut bot = state.bot.lock().unwrap();
+
+    // Reuse a recent probe so tight UI polling doesn't hit try_wait or bot.pid every call
+    if bot.last_probe.is_some_and(|at| at.elapsed() < BOT_STATUS_PROBE_TTL) {
+        return bot.status.clone();
+    }
+    bot.last_probe = Some(std::time::Instant::now());
+
+    // Check if the process is actually still running
+    if let Some(ref mut process) = bot.process {
+        match process.try_wait() {
+            Ok(Some(status)) => {
+                // Process has exited
+                let code = exit_code(status);
+                log::info!("Bot process exited with code {}", code);
+                clear_bot_pid(&app);
+                bot.last_exit = Some(code);
+                bot.process = None;
+                bot.started_at = None;
+                bot.status = "stopped".to_string();
+                emit_bot_status(&app, "stopped", None);
+            }
+            Ok(None) => {
+                // Process is still running
+                bot.status = "running".to_string();
+            }
+            Err(_) => {
+                // Error checking process status
+                bot.process = None;
+                bot.started_at = None;
+                bot.status = "stopped".to_string();
+                emit_bot_status(&app, "stopped", None);
+            }
+        }
+    } else if bot.status != "stopping" {
+        // stop_bot and restart_bot take the process out while they wait for it
+        // to exit; they report "stopped" themselves once it has. A bot started
+        // with --start-bot or by an earlier session is only known from bot.pid.
+        bot.status = if running_bot_pid(&app).is_some() { "running" } else { "stopped" }.to_string();
+    }
+
+    bot.status.clone()
+}
+
+// Seconds the current bot process has been running, or None when stopped
+#[tauri::command]
+fn get_bot_uptime(state: tauri::State<AppState>, app: tauri::AppHandle) -> Option<u64> {
+    let bot = state.bot.lock().unwrap();
+    if bot.process.is_none() {
+        // A --start-bot bot is only known from bot.pid
+        drop(bot);
+        return running_bot_pid(&app).and_then(process_uptime_secs);
+    }
+    bot.started_at.map(|at| at.elapsed().as_secs())
+}
+
+// 0 for a clean shutdown; None if the bot is running or never ran
+#[tauri::command]
+fn get_last_exit_code(state: tauri::State<AppState>) -> Option<i32> {
+    state.bot.lock().unwrap().last_exit
+}
+
+#[tauri::command]
+fn get_bot_output(state: tauri::State<AppState>, limit: Option<usize>) -> Vec<BotOutputLine> {
+    let output = state.output.lock().unwrap();
+    let limit = limit.unwrap_or(BOT_OUTPUT_BUFFER_SIZE);
+    let start = output.len().saturating_sub(limit);
+    output.iter().skip(start).cloned().collect()
+}
+
+#[tauri::command]
+fn quit_app(app: tauri::AppHandle) {
+    log::info!("Quit command received, stopping bot and exiting application");
+    shutdown_bot_and_exit(&app);
+}
+
+// The one exit path for quit_app, the tray menu and window close, so they
+// all stop the bot the same way. Callers run on the event loop, so the grace
+// period is waited out on a blocking thread that exits the app when done.
+fn shutdown_bot_and_exit(app: &tauri::AppHandle) {
+    let process = app.try_state::<AppState>().and_then(|state| {
+        let mut bot = state.bot.lock().unwrap();
+        let process = bot.process.take();
+        bot.started_at = None;
+        if process.is_some() {
+            bot.status = "stopping".to_string();
+            bot.last_probe = None;
+        }
+        process
+    });
+
+    let Some(mut process) = process else {
+        app.exit(0);
+        return;
+    };
+
+    let pid = process.id();
+    log::info!("Stopping bot process with PID: {}", pid);
+    emit_bot_status(app, "stopping", Some(pid));
+
+    let app = app.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let exit = stop_process(&app, &mut process);
+
+        if let Some(state) = app.try_state::<AppState>() {
+            let mut bot = state.bot.lock().unwrap();
+            bot.status = "stopped".to_string();
+            bot.last_exit = exit;
+        }
+        emit_bot_status(&app, "stopped", None);
+        app.exit(0);
+    });
+}
+
+#[tauri::command]
+fn hide_to_tray(app: tauri::AppHandle) -> Result<(), String> {
+    let window = app.get_webview_window("main")
+        .ok_or("Main window not found")?;
+
+    window.hide()
+        .map_err(|e| format!("Failed to hide window: {}", e))
+}
+
+#[tauri::command]
+fn show_window(app: tauri::AppHandle) -> Result<(), String> {
+    let window = app.get_webview_window("main")
+        .ok_or("Main window not found")?;
+
+    // Match the tray click behavior: make visible, restore and bring to front
+    window.show()
+        .map_err(|e| format!("Failed to show window: {}", e))?;
+    window.unminimize()
+        .map_err(|e| format!("Failed to unminimize window: {}", e))?;
+    window.set_focus()
+        .map_err(|e| format!("Failed to focus window: {}", e))
+}
+
+#[tauri::command]
+fn minimize_window(app: tauri::AppHandle) -> Result<(), String> {
+    let window = app.get_webview_window("main")
+        .ok_or("Main window not found")?;
+
+    window.minimize()
+        .map_err(|e| format!("Failed to minimize window: {}", e))
+}
+
+// Last normal (not minimized/maximized) window geometry, in physical pixels
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct WindowState {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+}
+
+// Moves and resizes arrive in bursts; only write once they settle
+const WINDOW_STATE_SAVE_DELAY_MS: u64 = 500;
+
+fn window_state_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    Ok(app_data_dir(app)?.join("window-state.json"))
+}
+
+fn schedule_window_state_save(window: &tauri::Window) {
+    let Some(state) = window.try_state::<AppState>() else {
+        return;
+    };
+    let generation = state.window_state_generation.fetch_add(1, Ordering::SeqCst) + 1;
+    let window = window.clone();
+
+    std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(WINDOW_STATE_SAVE_DELAY_MS));
+
+        // A newer move/resize is pending; let that one write
+        let Some(state) = window.try_state::<AppState>() else {
+            return;
+        };
+        if state.window_state_generation.load(Ordering::SeqCst) != generation {
+            return;
+        }
+
+        // Keep the last normal geometry rather than the minimized/maximized one
+        if window.is_minimized().unwrap_or(false) || window.is_maximized().unwrap_or(false) {
+            return;
+        }
+
+        let (Ok(position), Ok(size)) = (window.outer_position(), window.inner_size()) else {
+            return;
+        };
+        let window_state = WindowState {
+            x: position.x,
+            y: position.y,
+            width: size.width,
+            height: size.height,
+        };
+
+        let result = window_state_path(window.app_handle()).and_then(|path| {
+            let content = serde_json::to_string_pretty(&window_state)
+                .map_err(|e| format!("Failed to serialize window state: {}", e))?;
+            fs::write(&path, content)
+                .map_err(|e| format!("Failed to write window state: {}", e))
+        });
+        if let Err(e) = result {
+            log::warn!("{}", e);
+        }
+    });
+}
+
+fn restore_window_state(window: &tauri::WebviewWindow) {
+    let Ok(path) = window_state_path(window.app_handle()) else {
+        return;
+    };
+    let Some(saved) = fs::read_to_string(&path).ok()
+        .and_then(|content| serde_json::from_str::<WindowState>(&content).ok()) else {
+        return;
+    };
+
+    // Use the monitor the window was last on; if that monitor is gone, fall
+    // back to the primary one so the window never opens off-screen
+    let monitors = window.available_monitors().unwrap_or_default();
+    let monitor = monitors.into_iter()
+        .find(|m| {
+            let (pos, size) = (m.position(), m.size());
+            saved.x >= pos.x && saved.x < pos.x + size.width as i32
+                && saved.y >= pos.y && saved.y < pos.y + size.height as i32
+        })
+        .or_else(|| window.primary_monitor().ok().flatten());
+    let Some(monitor) = monitor else {
+        return;
+    };
+
+    let (pos, size) = (monitor.position(), monitor.size());
+    let width = saved.width.min(size.width);
+    let height = saved.height.min(size.height);
+    let x = saved.x.clamp(pos.x, pos.x + (size.width - width) as i32);
+    let y = saved.y.clamp(pos.y, pos.y + (size.height - height) as i32);
+
+    let _ = window.set_size(tauri::PhysicalSize::new(width, height));
+    let _ = window.set_position(tauri::PhysicalPosition::new(x, y));
+    log::info!("Restored window geometry: {}x{} at ({}, {})", width, height, x, y);
+}
+
+// Starts from the commands.json generated at build time, then merges any
+// definitions dropped into the AppData commands folder as *.json (one command
+// object or an array of them) over it by name. The deploy overwrites every
+// registered command, so an AppData file must never replace the bundled set.
+fn load_command_definitions(app: &tauri::AppHandle) -> Result<Vec<serde_json::Value>, String> {
+    // Get the resource directory where dist-backend is bundled
+    let resource_dir = app.path().resource_dir()
+        .map_err(|e| format!("Failed to get resource dir: {}", e))?;
+
+    log::debug!("Resource directory: {:?}", resource_dir);
+
+    // Check multiple possible locations for commands.json
+    // 1. Direct path (dev builds)
+    // 2. _up_ subdirectory (production builds with updates)
+    let possible_paths = vec![
+        resource_dir.join("dist-backend").join("commands.json"),
+        resource_dir.join("_up_").join("dist-backend").join("commands.json"),
+    ];
+
+    let mut commands_file = None;
+    for path in &possible_paths {
+        log::debug!("Checking path: {:?}", path);
+        if path.exists() {
+            commands_file = Some(path.clone());
+            log::debug!("Found commands.json at: {:?}", path);
+            break;
+        }
+    }
+
+    let commands_file = commands_file.ok_or_else(|| {
         format!(
             "commands.json not found. Checked:\n  - {:?}\n  - {:?}",
             possible_paths[0],
@@ -572,116 +2376,3646 @@ async fn deploy_discord_commands(app: tauri::AppHandle) -> Result<String, String
     let commands_content = fs::read_to_string(&commands_file)
         .map_err(|e| format!("Failed to read commands.json: {}", e))?;
 
-    let commands: Vec<serde_json::Value> = serde_json::from_str(&commands_content)
-        .map_err(|e| format!("Failed to parse commands.json: {}", e))?;
+    let mut commands: Vec<serde_json::Value> = serde_json::from_str(&commands_content)
+        .map_err(|e| format!("Failed to parse commands.json: {}", e))?;
+
+    log::info!("Loaded {} commands from {:?}", commands.len(), commands_file);
+
+    let commands_dir = app_data_dir(app)?.join("commands");
+    let mut definition_files: Vec<PathBuf> = fs::read_dir(&commands_dir)
+        .map(|entries| entries.flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension() == Some(OsStr::new("json")))
+            .collect())
+        .unwrap_or_default();
+    definition_files.sort();
+
+    for path in &definition_files {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+        let value: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse {:?}: {}", path, e))?;
+        let definitions = match value {
+            serde_json::Value::Array(items) => items,
+            other => vec![other],
+        };
+        for definition in definitions {
+            let Some(name) = definition.get("name").and_then(|v| v.as_str()).map(str::to_string) else {
+                return Err(format!("Command definition in {:?} has no name", path));
+            };
+            match commands.iter_mut().find(|c| c.get("name").and_then(|v| v.as_str()) == Some(name.as_str())) {
+                Some(existing) => {
+                    log::debug!("{:?} overrides bundled command {}", path, name);
+                    *existing = definition;
+                }
+                None => commands.push(definition),
+            }
+        }
+    }
+    if !definition_files.is_empty() {
+        log::info!("Merged command definitions from {} file(s) in {:?}", definition_files.len(), commands_dir);
+    }
+
+    Ok(commands)
+}
+
+// Upper bound on a whole deploy, retries included, so a stalled connection
+// can't leave the Settings button spinning forever
+const DEPLOY_TIMEOUT_SECS: u64 = 60;
+
+#[tauri::command]
+async fn deploy_discord_commands(app: tauri::AppHandle, timeout_secs: Option<u64>, global: Option<bool>) -> Result<String, String> {
+    log::debug!("deploy_discord_commands command called (timeout: {:?}, global: {:?})", timeout_secs, global);
+
+    let commands = load_command_definitions(&app)?;
+
+    // Load config
+    let config = load_config(&app)?;
+    let token = config_token(&config)?;
+
+    // Global commands show up in every server the bot is in; guild commands
+    // only in the configured one
+    let global = global.unwrap_or(false);
+    let url = if global {
+        format!("https://discord.com/api/v9/applications/{}/commands", config_client_id(&config)?)
+    } else {
+        let (client_id, guild_id) = config_application_ids(&config)?;
+        format!("https://discord.com/api/v9/applications/{}/guilds/{}/commands", client_id, guild_id)
+    };
+
+    // Deploy commands via Discord REST API
+    let client = reqwest::Client::new();
+
+    log::debug!("Deploying to Discord API: {}", url);
+
+    let request = client
+        .put(&url)
+        .header("Authorization", format!("Bot {}", token))
+        .header("Content-Type", "application/json")
+        .json(&commands);
+
+    let timeout_secs = timeout_secs.unwrap_or(DEPLOY_TIMEOUT_SECS);
+    let deploy = async {
+        let response = discord_send(request, DISCORD_MAX_RETRIES)
+            .await
+            .map_err(|e| format!("Failed to send deployment request: {}", e))?;
+
+        let status = response.status();
+        log::debug!("Discord API response status: {}", status);
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(discord_api_error(status, &error_text));
+        }
+
+        response.json::<Vec<serde_json::Value>>().await
+            .map_err(|e| format!("Failed to parse response: {}", e))
+    };
+
+    // Dropping the future on timeout aborts the in-flight request
+    let result = tokio::time::timeout(std::time::Duration::from_secs(timeout_secs), deploy)
+        .await
+        .map_err(|_| {
+            log::warn!("Command deployment timed out after {} seconds", timeout_secs);
+            format!(
+                "Deploy timed out after {} seconds. Discord may be slow or unreachable; try again later.",
+                timeout_secs
+            )
+        })??;
+
+    // Build success message
+    let scope = if global { "globally" } else { "to the server" };
+    let mut message = format!("Successfully deployed {} command(s) {}!\n\n", result.len(), scope);
+    message.push_str("Registered commands:\n");
+
+    for cmd in &result {
+        if let Some(name) = cmd.get("name").and_then(|v| v.as_str()) {
+            message.push_str(&format!("  - /{}\n", name));
+        }
+    }
+
+    log::info!("Deployment successful!");
+    Ok(message)
+}
+
+#[tauri::command]
+async fn insert_manual_run(app: tauri::AppHandle, run_data: serde_json::Value) -> Result<String, String> {
+    log::debug!("insert_manual_run command called");
+    log::debug!("Run data: {:?}", run_data);
+
+    // Extract fields from run_data
+    let character_name = run_data.get("characterName")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing characterName")?;
+    let realm = run_data.get("realm")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing realm")?;
+    let region = run_data.get("region")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing region")?;
+    let dungeon = run_data.get("dungeon")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing dungeon")?;
+    let keystone_level = run_data.get("keystoneLevel")
+        .and_then(|v| v.as_i64())
+        .ok_or("Missing keystoneLevel")? as i64;
+    let completion_time = run_data.get("completionTime")
+        .and_then(|v| v.as_i64())
+        .unwrap_or(0) as i64;
+    let upgraded_level = run_data.get("upgradedLevel")
+        .and_then(|v| v.as_i64())
+        .unwrap_or(0) as i64;
+    let spec = run_data.get("spec")
+        .and_then(|v| v.as_str())
+        .unwrap_or("Unknown");
+    let role = run_data.get("role")
+        .and_then(|v| v.as_str())
+        .unwrap_or("DPS");
+    let season = run_data.get("season")
+        .and_then(|v| v.as_str())
+        .unwrap_or("manual-insert");
+
+    // Normalize realm to lowercase to match database storage
+    let normalized_realm = realm.to_lowercase();
+
+    // Get database path
+    let app_dir = app_data_dir(&app)?;
+    let data_dir = app_dir.join("data");
+    fs::create_dir_all(&data_dir)
+        .map_err(|e| format!("Failed to create data directory: {}", e))?;
+    let db_path = data_dir.join("mythic_runs.db");
+
+    if !db_path.exists() {
+        return Err("Database not found. Please start the bot first to initialize the database.".to_string());
+    }
+
+    // Open database connection
+    let conn = Connection::open(&db_path)
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+
+    // Enable WAL mode
+    conn.pragma_update(None, "journal_mode", "WAL")
+        .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
+
+    // Step 1: Upsert character
+    log::debug!("Upserting character: {}-{} ({})", character_name, normalized_realm, region);
+
+    // Check if character exists
+    let character_id: Option<i64> = conn.query_row(
+        "SELECT id FROM characters WHERE name = ?1 AND realm = ?2 AND region = ?3",
+        [character_name, normalized_realm.as_str(), region],
+        |row| row.get(0)
+    ).ok();
+
+    let character_id = if let Some(id) = character_id {
+        // Update existing character
+        conn.execute(
+            "UPDATE characters SET active_spec_name = ?1, active_spec_role = ?2, updated_at = ?3 WHERE id = ?4",
+            (spec, role, chrono::Utc::now().timestamp_millis(), id),
+        ).map_err(|e| format!("Failed to update character: {}", e))?;
+        log::info!("Updated existing character with ID: {}", id);
+        id
+    } else {
+        // Insert new character
+        conn.execute(
+            "INSERT INTO characters (name, realm, region, class, active_spec_name, active_spec_role, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            (
+                character_name,
+                normalized_realm.as_str(),
+                region,
+                "Unknown", // class
+                spec,
+                role,
+                chrono::Utc::now().timestamp_millis(),
+                chrono::Utc::now().timestamp_millis(),
+            ),
+        ).map_err(|e| format!("Failed to insert character: {}", e))?;
+
+        let id = conn.last_insert_rowid();
+        log::info!("Created new character with ID: {}", id);
+        id
+    };
+
+    // Step 2: Insert the run
+    log::debug!("Inserting run for character ID: {}", character_id);
+    let completed_timestamp = chrono::Utc::now().timestamp_millis();
+    let keystone_run_id = completed_timestamp; // Use timestamp as unique ID
+    let is_completed_within_time = if upgraded_level > 0 { 1 } else { 0 };
+
+    // Check for duplicate
+    let duplicate_check: Option<i64> = conn.query_row(
+        "SELECT id FROM mythic_runs WHERE character_id = ?1 AND dungeon = ?2 AND mythic_level = ?3 AND completed_timestamp = ?4",
+        (character_id, dungeon, keystone_level, completed_timestamp),
+        |row| row.get(0)
+    ).ok();
+
+    if duplicate_check.is_some() {
+        return Ok(format!(
+            "⚠️  Run already exists (duplicate detected)\n\
+             Character: {}-{}\n\
+             Dungeon: {} +{}\n\
+             Spec: {} ({})",
+            character_name, realm, dungeon, keystone_level, spec, role
+        ));
+    }
+
+    conn.execute(
+        "INSERT INTO mythic_runs (
+            character_id, dungeon, mythic_level, completed_timestamp,
+            duration, keystone_run_id, is_completed_within_time, score,
+            num_keystone_upgrades, spec_name, spec_role, affixes, season, created_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+        (
+            character_id,
+            dungeon,
+            keystone_level,
+            completed_timestamp,
+            completion_time,
+            keystone_run_id,
+            is_completed_within_time,
+            0, // score - manual runs don't have scores
+            upgraded_level,
+            spec,
+            role,
+            rusqlite::types::Null, // affixes - manual runs don't track affixes
+            season,
+            chrono::Utc::now().timestamp_millis(), // created_at
+        ),
+    ).map_err(|e| format!("Failed to insert run: {}", e))?;
+
+    let run_id = conn.last_insert_rowid();
+    log::info!("Successfully inserted run with ID: {}", run_id);
+
+    Ok(format!(
+        "✅ Successfully inserted manual run!\n\
+         Run ID: {}\n\
+         Character: {}-{}\n\
+         Dungeon: {} +{}\n\
+         Spec: {} ({})\n\
+         Season: {}",
+        run_id, character_name, realm, dungeon, keystone_level, spec, role, season
+    ))
+}
+
+#[tauri::command]
+async fn delete_discord_commands(app: tauri::AppHandle) -> Result<String, String> {
+    log::debug!("delete_discord_commands command called");
+
+    // Load config
+    let config = load_config(&app)?;
+    let (client_id, guild_id) = config_application_ids(&config)?;
+    let token = config_token(&config)?;
+
+    // Get all registered commands
+    let client = reqwest::Client::new();
+    let list_url = format!("https://discord.com/api/v9/applications/{}/guilds/{}/commands", client_id, guild_id);
+
+    let request = client
+        .get(&list_url)
+        .header("Authorization", format!("Bot {}", token));
+    let response = discord_send(request, DISCORD_MAX_RETRIES)
+        .await
+        .map_err(|e| format!("Failed to fetch commands: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(discord_api_error(status, &error_text));
+    }
+
+    let commands: Vec<serde_json::Value> = response.json().await
+        .map_err(|e| format!("Failed to parse commands list: {}", e))?;
+
+    if commands.is_empty() {
+        return Ok("No commands to delete".to_string());
+    }
+
+    log::info!("Found {} commands to delete", commands.len());
+
+    // Delete each command; discord_send waits out 429s, so anything still
+    // failing after its retries is counted as skipped
+    let total = commands.len();
+    let mut deleted_count = 0;
+    for cmd in commands {
+        if let Some(cmd_id) = cmd.get("id").and_then(|v| v.as_str()) {
+            let delete_url = format!("https://discord.com/api/v9/applications/{}/guilds/{}/commands/{}",
+                client_id, guild_id, cmd_id);
+
+            let request = client
+                .delete(&delete_url)
+                .header("Authorization", format!("Bot {}", token));
+
+            match discord_send(request, DISCORD_MAX_RETRIES).await {
+                Ok(resp) if resp.status().is_success() => {
+                    deleted_count += 1;
+                    if let Some(name) = cmd.get("name").and_then(|v| v.as_str()) {
+                        log::info!("Deleted command: /{}", name);
+                    }
+                }
+                Ok(resp) => {
+                    log::warn!("Failed to delete command {}: {}", cmd_id, resp.status());
+                }
+                Err(e) => {
+                    log::warn!("Error deleting command {}: {}", cmd_id, e);
+                }
+            }
+        }
+    }
+
+    let skipped = total - deleted_count;
+    if skipped > 0 {
+        log::warn!("Deleted {} of {} command(s), {} skipped", deleted_count, total, skipped);
+        return Ok(format!(
+            "Deleted {} command(s), {} skipped (rate limited or failed). Run delete again to remove the rest.",
+            deleted_count, skipped
+        ));
+    }
+
+    Ok(format!("Successfully deleted {} command(s)", deleted_count))
+}
+
+// Discord permission bits used for channel access checks
+const DISCORD_PERMISSION_ADMINISTRATOR: u64 = 1 << 3;
+const DISCORD_PERMISSION_VIEW_CHANNEL: u64 = 1 << 10;
+const DISCORD_PERMISSION_SEND_MESSAGES: u64 = 1 << 11;
+
+#[derive(Clone, Serialize, Deserialize)]
+struct ChannelAccessResult {
+    ok: bool,
+    status: String,
+    reason: String,
+}
+
+// Retry limits for Discord REST calls, so a persistent outage still fails in
+// bounded time (worst case roughly 0.5 + 1 + 2s of backoff, or Retry-After waits)
+const DISCORD_MAX_RETRIES: u32 = 3;
+const DISCORD_RETRY_BASE_MS: u64 = 500;
+const DISCORD_MAX_RETRY_AFTER_SECS: f64 = 30.0;
+
+// Delay Discord asked for on a 429, capped so one bad header can't hang a command
+fn discord_retry_after(response: &reqwest::Response) -> Option<std::time::Duration> {
+    response.headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<f64>().ok())
+        .filter(|secs| secs.is_finite() && *secs >= 0.0)
+        .map(|secs| std::time::Duration::from_secs_f64(secs.min(DISCORD_MAX_RETRY_AFTER_SECS)))
+}
+
+// Send a Discord API request, retrying network errors and 5xx responses with
+// exponential backoff and waiting out Retry-After on 429s. After max_retries
+// the last response (or error) is returned to the caller as-is.
+async fn discord_send(request: reqwest::RequestBuilder, max_retries: u32) -> Result<reqwest::Response, reqwest::Error> {
+    let mut attempt = 0;
+    loop {
+        let backoff = std::time::Duration::from_millis(DISCORD_RETRY_BASE_MS << attempt);
+
+        // Bodies here are JSON or empty, so the builder can always be cloned
+        let result = match request.try_clone() {
+            Some(this_attempt) => this_attempt.send().await,
+            None => return request.send().await,
+        };
+
+        let delay = match &result {
+            Ok(response) if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                discord_retry_after(response).unwrap_or(backoff)
+            }
+            Ok(response) if response.status().is_server_error() => backoff,
+            Ok(_) => return result,
+            Err(e) if e.is_builder() => return result,
+            Err(_) => backoff,
+        };
+
+        if attempt >= max_retries {
+            return result;
+        }
+
+        match &result {
+            Ok(response) => log::warn!("Discord API returned {}, retrying in {:?}", response.status(), delay),
+            Err(e) => log::warn!("Discord API request failed ({}), retrying in {:?}", e, delay),
+        }
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+// Discord error bodies are echoed back to the UI, so keep credentials out of them
+fn discord_api_error(status: reqwest::StatusCode, body: &str) -> String {
+    redact_secrets(&format!("Discord API error ({}): {}", status, body))
+}
+
+// Helper function to GET a Discord API endpoint with the bot token
+async fn discord_get(client: &reqwest::Client, url: &str, token: &str) -> Result<serde_json::Value, String> {
+    let request = client
+        .get(url)
+        .header("Authorization", format!("Bot {}", token));
+    let response = discord_send(request, DISCORD_MAX_RETRIES)
+        .await
+        .map_err(|e| format!("Failed to send request to Discord: {}", e))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(discord_api_error(status, &error_text));
+    }
+
+    response.json().await
+        .map_err(|e| format!("Failed to parse Discord response: {}", e))
+}
+
+// Discord sends permission bitfields as strings
+fn parse_discord_permissions(value: Option<&serde_json::Value>) -> u64 {
+    value
+        .and_then(|v| v.as_str())
+        .and_then(|s| s.parse::<u64>().ok())
+        .unwrap_or(0)
+}
+
+// Resolve the bot's effective permissions in a channel from guild roles and channel overwrites
+fn compute_channel_permissions(
+    guild_id: &str,
+    bot_id: &str,
+    member_roles: &[String],
+    guild_roles: &[serde_json::Value],
+    overwrites: &[serde_json::Value],
+) -> u64 {
+    let role_permissions = |role_id: &str| {
+        guild_roles.iter()
+            .find(|role| role.get("id").and_then(|v| v.as_str()) == Some(role_id))
+            .map(|role| parse_discord_permissions(role.get("permissions")))
+            .unwrap_or(0)
+    };
+
+    // The @everyone role shares its id with the guild
+    let mut permissions = role_permissions(guild_id);
+    for role_id in member_roles {
+        permissions |= role_permissions(role_id);
+    }
+
+    if permissions & DISCORD_PERMISSION_ADMINISTRATOR != 0 {
+        return u64::MAX;
+    }
+
+    let find_overwrite = |id: &str| {
+        overwrites.iter()
+            .find(|o| o.get("id").and_then(|v| v.as_str()) == Some(id))
+            .map(|o| (parse_discord_permissions(o.get("allow")), parse_discord_permissions(o.get("deny"))))
+    };
+
+    // Apply overwrites in Discord's order: @everyone, then roles, then the member itself
+    if let Some((allow, deny)) = find_overwrite(guild_id) {
+        permissions = (permissions & !deny) | allow;
+    }
+
+    let mut roles_allow = 0;
+    let mut roles_deny = 0;
+    for role_id in member_roles {
+        if let Some((allow, deny)) = find_overwrite(role_id) {
+            roles_allow |= allow;
+            roles_deny |= deny;
+        }
+    }
+    permissions = (permissions & !roles_deny) | roles_allow;
+
+    if let Some((allow, deny)) = find_overwrite(bot_id) {
+        permissions = (permissions & !deny) | allow;
+    }
+
+    permissions
+}
+
+#[tauri::command]
+async fn check_channel_access(app: tauri::AppHandle, channel_id: String) -> Result<ChannelAccessResult, String> {
+    log::debug!("check_channel_access command called for channel: {}", channel_id);
+
+    // The id goes into the URL path, so anything else could reach another endpoint
+    let channel_id = channel_id.trim().to_string();
+    if !is_discord_snowflake(&channel_id) {
+        return Err(format!("'{}' is not a valid Discord channel ID", channel_id));
+    }
+
+    // Load config
+    let config = load_config(&app)?;
+    let token = config_token(&config)?;
+
+    let client = reqwest::Client::new();
+    let channel_url = format!("https://discord.com/api/v9/channels/{}", channel_id);
+
+    let request = client
+        .get(&channel_url)
+        .header("Authorization", format!("Bot {}", token));
+    let response = discord_send(request, DISCORD_MAX_RETRIES)
+        .await
+        .map_err(|e| format!("Failed to fetch channel: {}", e))?;
+
+    let status = response.status();
+    log::debug!("Discord API response status: {}", status);
+
+    if status == reqwest::StatusCode::NOT_FOUND {
+        return Ok(ChannelAccessResult {
+            ok: false,
+            status: "not_found".to_string(),
+            reason: format!("Channel {} was not found. Check the channel ID and that the bot is in that server.", channel_id),
+        });
+    }
+
+    if status == reqwest::StatusCode::FORBIDDEN {
+        return Ok(ChannelAccessResult {
+            ok: false,
+            status: "no_access".to_string(),
+            reason: "The bot cannot see this channel (missing View Channel permission).".to_string(),
+        });
+    }
+
+    if !status.is_success() {
+        let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(discord_api_error(status, &error_text));
+    }
+
+    let channel: serde_json::Value = response.json().await
+        .map_err(|e| format!("Failed to parse channel: {}", e))?;
+
+    let guild_id = match channel.get("guild_id").and_then(|v| v.as_str()) {
+        Some(id) => id.to_string(),
+        None => {
+            return Ok(ChannelAccessResult {
+                ok: false,
+                status: "no_access".to_string(),
+                reason: "This channel is not part of a server.".to_string(),
+            });
+        }
+    };
+
+    // Work out the bot's effective permissions in the channel
+    let bot_user = discord_get(&client, "https://discord.com/api/v9/users/@me", token).await?;
+    let bot_id = bot_user.get("id")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing bot user id in Discord response")?
+        .to_string();
+
+    let member = discord_get(
+        &client,
+        &format!("https://discord.com/api/v9/guilds/{}/members/{}", guild_id, bot_id),
+        token,
+    ).await?;
+    let member_roles: Vec<String> = member.get("roles")
+        .and_then(|v| v.as_array())
+        .map(|roles| roles.iter().filter_map(|r| r.as_str().map(String::from)).collect())
+        .unwrap_or_default();
+
+    let roles = discord_get(
+        &client,
+        &format!("https://discord.com/api/v9/guilds/{}/roles", guild_id),
+        token,
+    ).await?;
+    let guild_roles = roles.as_array().cloned().unwrap_or_default();
+
+    let overwrites = channel.get("permission_overwrites")
+        .and_then(|v| v.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    let permissions = compute_channel_permissions(&guild_id, &bot_id, &member_roles, &guild_roles, &overwrites);
+
+    if permissions & DISCORD_PERMISSION_VIEW_CHANNEL == 0 {
+        return Ok(ChannelAccessResult {
+            ok: false,
+            status: "no_access".to_string(),
+            reason: "The bot cannot see this channel (missing View Channel permission).".to_string(),
+        });
+    }
+
+    if permissions & DISCORD_PERMISSION_SEND_MESSAGES == 0 {
+        return Ok(ChannelAccessResult {
+            ok: false,
+            status: "no_access".to_string(),
+            reason: "The bot can see this channel but cannot post in it (missing Send Messages permission).".to_string(),
+        });
+    }
+
+    let channel_name = channel.get("name").and_then(|v| v.as_str()).unwrap_or("unknown");
+    log::info!("Bot has access to channel #{}", channel_name);
+
+    Ok(ChannelAccessResult {
+        ok: true,
+        status: "ok".to_string(),
+        reason: format!("The bot can see and post in #{}.", channel_name),
+    })
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct BotGuild {
+    id: String,
+    name: String,
+}
+
+#[tauri::command]
+async fn list_bot_guilds(app: tauri::AppHandle) -> Result<Vec<BotGuild>, String> {
+    log::debug!("list_bot_guilds command called");
+
+    // Load config
+    let config = load_config(&app)?;
+    let token = config_token(&config)?;
+
+    let client = reqwest::Client::new();
+    let guilds = discord_get(&client, "https://discord.com/api/v9/users/@me/guilds", token).await?;
+
+    let guilds: Vec<BotGuild> = guilds.as_array()
+        .ok_or("Unexpected guild list response from Discord")?
+        .iter()
+        .filter_map(|guild| {
+            Some(BotGuild {
+                id: guild.get("id")?.as_str()?.to_string(),
+                name: guild.get("name")?.as_str()?.to_string(),
+            })
+        })
+        .collect();
+
+    log::info!("Bot is in {} guild(s)", guilds.len());
+    Ok(guilds)
+}
+
+#[derive(Serialize)]
+struct DiscordBotUser {
+    id: String,
+    username: String,
+}
+
+// Checks the stored token against Discord so setup can flag a bad token before
+// the bot fails to log in
+#[tauri::command]
+async fn validate_discord_token(app: tauri::AppHandle) -> Result<DiscordBotUser, String> {
+    let config = load_config(&app)?;
+    let token = config_token(&config)?;
+
+    let client = reqwest::Client::new();
+    let request = client
+        .get("https://discord.com/api/v10/users/@me")
+        .header("Authorization", format!("Bot {}", token));
+    let response = discord_send(request, DISCORD_MAX_RETRIES)
+        .await
+        .map_err(|e| format!("Failed to reach Discord: {}", e))?;
+
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err("Discord rejected the bot token. Copy a fresh token from the Developer Portal (Bot > Reset Token).".to_string());
+    }
+    if !status.is_success() {
+        let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(discord_api_error(status, &error_text));
+    }
+
+    let user: serde_json::Value = response.json().await
+        .map_err(|e| format!("Failed to parse Discord response: {}", e))?;
+    Ok(DiscordBotUser {
+        id: user["id"].as_str().unwrap_or_default().to_string(),
+        username: user["username"].as_str().unwrap_or_default().to_string(),
+    })
+}
+
+// Helper function to load config; goes through get_config so the Discord
+// commands and the settings UI agree on what a valid config is
+fn load_config(app: &tauri::AppHandle) -> Result<Config, String> {
+    get_config(app.clone())
+}
+
+// The bot token is optional in Config, but every Discord call needs it
+fn config_token(config: &Config) -> Result<&str, String> {
+    config.token.as_deref()
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .ok_or_else(|| "Bot token not set. Add it in Settings before using Discord commands.".to_string())
+}
+
+fn config_client_id(config: &Config) -> Result<&str, String> {
+    let client_id = config.client_id.trim();
+    if client_id.is_empty() {
+        return Err("Client ID not set. Add it in Settings before using Discord commands.".to_string());
+    }
+    Ok(client_id)
+}
+
+fn config_application_ids(config: &Config) -> Result<(&str, &str), String> {
+    let client_id = config_client_id(config)?;
+
+    let guild_id = config.guild_id.trim();
+    if guild_id.is_empty() {
+        return Err("Guild ID not set. Add it in Settings before using Discord commands.".to_string());
+    }
+
+    Ok((client_id, guild_id))
+}
+
+// Extension check that works on the raw OS file name, so entries whose
+// names aren't valid UTF-8 aren't silently skipped
+fn is_js_file(path: &Path) -> bool {
+    path.extension() == Some(OsStr::new("js"))
+}
+
+#[derive(Clone, Serialize)]
+struct InitializationProgress {
+    stage: String,
+    message: String,
+    // Units done/total within the stage (files for the commands copy)
+    current: usize,
+    total: usize,
+}
+
+#[derive(Clone, Serialize)]
+struct InitializationComplete {
+    success: bool,
+    error: Option<String>,
+}
+
+fn emit_initialization_progress(app: &tauri::AppHandle, stage: &str, message: String, current: usize, total: usize) {
+    let _ = app.emit("initialization-progress", InitializationProgress {
+        stage: stage.to_string(),
+        message,
+        current,
+        total,
+    });
+}
+
+fn finish_initialization(app: &tauri::AppHandle, error: Option<String>) {
+    if let Some(state) = app.try_state::<AppState>() {
+        *state.initialization_error.lock().unwrap() = error.clone();
+        state.initialization_complete.store(true, Ordering::SeqCst);
+    }
+    let _ = app.emit("initialization-complete", InitializationComplete {
+        success: error.is_none(),
+        error,
+    });
+}
+
+// Blocks until startup file setup has finished; call from background tasks only
+fn wait_for_initialization(app: &tauri::AppHandle) {
+    if let Some(state) = app.try_state::<AppState>() {
+        while !state.initialization_complete.load(Ordering::SeqCst) {
+            std::thread::sleep(std::time::Duration::from_millis(200));
+        }
+    }
+}
+
+// Lets the frontend catch up if it loaded after initialization-complete was sent
+#[tauri::command]
+fn is_initialization_complete(state: tauri::State<AppState>) -> bool {
+    state.initialization_complete.load(Ordering::SeqCst)
+}
+
+// The error initialization-complete carried, for a frontend that missed the event
+#[tauri::command]
+fn get_initialization_error(state: tauri::State<AppState>) -> Option<String> {
+    state.initialization_error.lock().unwrap().clone()
+}
+
+// Creates the AppData layout and copies bundled commands on first run. Runs on
+// a background thread during setup, emitting initialization-progress events.
+fn initialize_app_data(app: &tauri::AppHandle, app_dir: &Path) {
+    emit_initialization_progress(app, "folders", "Creating DaeBot folders...".to_string(), 0, 1);
+    if let Err(e) = fs::create_dir_all(app_dir) {
+        let message = format!("Failed to create app data dir: {}", e);
+        log::warn!("{}", message);
+        finish_initialization(app, Some(message));
+        return;
+    }
+    log::debug!("AppData directory initialized: {:?}", app_dir);
+
+    // Create blank config.json if it doesn't exist
+    emit_initialization_progress(app, "config", "Preparing configuration files...".to_string(), 0, 2);
+    let config_path = app_dir.join("config.json");
+    let blank_config = Config {
+        token: None,
+        client_id: String::new(),
+        guild_id: String::new(),
+        token_channel: TokenChannels::default(),
+        characters: Vec::new(),
+    };
+    if let Ok(content) = serde_json::to_string_pretty(&blank_config) {
+        match write_new_file(&config_path, &content) {
+            Ok(true) => log::info!("Created blank config.json at {:?}", config_path),
+            Ok(false) => {}
+            Err(e) => log::warn!("Failed to create blank config: {}", e),
+        }
+    }
+
+    // Create blank .env if it doesn't exist
+    emit_initialization_progress(app, "config", "Preparing configuration files...".to_string(), 1, 2);
+    let env_path = app_dir.join(".env");
+    let blank_env = "BLIZZARD_CLIENT_ID=\nBLIZZARD_CLIENT_SECRET=\n";
+    match write_new_file(&env_path, blank_env) {
+        Ok(true) => log::info!("Created blank .env at {:?}", env_path),
+        Ok(false) => {}
+        Err(e) => log::warn!("Failed to create blank .env: {}", e),
+    }
+
+    // Copy command files from bundled resources to AppData if they don't exist
+    let commands_dir = app_dir.join("commands");
+    if !commands_dir.exists() {
+        log::info!("Commands folder not found in AppData, copying command files from resources...");
+
+        // Get the resource path where bundled files are stored
+        if let Ok(resource_path) = app.path().resource_dir() {
+            log::debug!("Resource directory: {:?}", resource_path);
+
+            // Commands are bundled in _up_/dist/commands subdirectory
+            let source_commands_path = resource_path.join("_up_").join("dist").join("commands");
+            log::debug!("Looking for command files at: {:?}", source_commands_path);
+
+            if source_commands_path.exists() {
+                // Create commands directory
+                if let Err(e) = fs::create_dir_all(&commands_dir) {
+                    log::warn!("Failed to create commands directory: {}", e);
+                } else {
+                    // Copy all .js files from bundled commands to AppData commands directory
+                    let files: Vec<_> = fs::read_dir(&source_commands_path)
+                        .map(|entries| entries.flatten().filter(|entry| is_js_file(&entry.path())).collect())
+                        .unwrap_or_default();
+                    let total = files.len();
+
+                    let mut copied_count = 0;
+                    for (i, entry) in files.iter().enumerate() {
+                        let file_name = entry.file_name();
+                        emit_initialization_progress(
+                            app,
+                            "commands",
+                            format!("Copying command files ({}/{})...", i + 1, total),
+                            i,
+                            total,
+                        );
+
+                        let source_file = source_commands_path.join(&file_name);
+                        let dest_file = commands_dir.join(&file_name);
+
+                        match fs::copy(&source_file, &dest_file) {
+                            Ok(_) => {
+                                log::debug!("Copied: {:?}", file_name);
+                                copied_count += 1;
+                            }
+                            Err(e) => log::warn!("Failed to copy {:?}: {}", file_name, e),
+                        }
+                    }
+
+                    if copied_count > 0 {
+                        log::info!("Successfully copied {} command file(s) to AppData: {:?}", copied_count, commands_dir);
+                    } else {
+                        log::warn!("No .js command files found in bundled resources");
+                    }
+                }
+            } else {
+                log::warn!("Commands not found at: {:?}", source_commands_path);
+            }
+        } else {
+            log::warn!("Could not get resource directory");
+        }
+    } else {
+        log::debug!("Commands folder already exists in AppData: {:?}", commands_dir);
+    }
+
+    // Problems worth telling the user about, reported with initialization-complete
+    let mut problems = Vec::new();
+
+    // Catch half-copied bundles now rather than on the first deploy
+    emit_initialization_progress(app, "verify", "Checking bundled files...".to_string(), 0, 1);
+    match verify_resources(app.clone()) {
+        Ok(report) if !report.ok => {
+            let damaged: Vec<String> = report.checks.iter()
+                .filter(|check| !check.ok)
+                .map(|check| format!("  - {} {}", check.name, check.problem.as_deref().unwrap_or("has a problem")))
+                .collect();
+            let message = format!(
+                "Some DaeBot files are missing or damaged, possibly from an interrupted update. \
+                 Reinstalling DaeBot should fix this.\n{}",
+                damaged.join("\n")
+            );
+            log::error!("{}", message);
+            problems.push(message);
+        }
+        Ok(_) => {}
+        Err(e) => log::warn!("Failed to verify bundled resources: {}", e),
+    }
+
+    // Later commands assume the current schema, so repair it before the UI loads
+    emit_initialization_progress(app, "database", "Checking the database...".to_string(), 0, 1);
+    if let Err(e) = migrate_database(app.clone()) {
+        log::warn!("Failed to migrate database: {}", e);
+    }
+
+    // A replica that has gone missing would make the dashboard look empty
+    if let Some(path) = get_settings(app.clone()).ok().and_then(|settings| settings.read_database_path) {
+        if let Err(e) = validate_read_database(Path::new(&path)) {
+            let message = format!("{}. Dashboard stats will be unavailable until you fix or clear the read database path in Settings.", e);
+            log::error!("{}", message);
+            problems.push(message);
+        }
+    }
+
+    finish_initialization(app, (!problems.is_empty()).then(|| problems.join("\n\n")));
+
+    // Index creation can take a while on a large database and nothing waits on it
+    if let Err(e) = ensure_indexes(app.clone()) {
+        log::warn!("Failed to ensure database indexes: {}", e);
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct ResourceCheck {
+    name: String,
+    path: Option<String>,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    problem: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct ResourceReport {
+    ok: bool,
+    checks: Vec<ResourceCheck>,
+}
+
+// Where the bundled dist-backend lives; updates may stage it under _up_
+fn find_dist_backend(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let resource_dir = app.path().resource_dir()
+        .map_err(|e| format!("Failed to get resource dir: {}", e))?;
+
+    let possible_paths = [
+        resource_dir.join("dist-backend"),
+        resource_dir.join("_up_").join("dist-backend"),
+    ];
+
+    possible_paths.iter()
+        .find(|path| path.exists())
+        .cloned()
+        .ok_or_else(|| format!(
+            "dist-backend not found. Checked:\n  - {:?}\n  - {:?}",
+            possible_paths[0],
+            possible_paths[1]
+        ))
+}
+
+// A resource counts as present only if it exists and isn't a zero-byte leftover
+fn check_resource_file(name: &str, path: &Path) -> ResourceCheck {
+    let problem = match fs::metadata(path) {
+        Ok(metadata) if !metadata.is_file() => Some("is not a file".to_string()),
+        Ok(metadata) if metadata.len() == 0 => Some("is empty".to_string()),
+        Ok(_) => None,
+        Err(_) => Some("is missing".to_string()),
+    };
+
+    ResourceCheck {
+        name: name.to_string(),
+        path: Some(path.display().to_string()),
+        ok: problem.is_none(),
+        problem,
+    }
+}
+
+// Confirm the bundled files update staging can leave half-copied are all in place
+#[tauri::command]
+fn verify_resources(app: tauri::AppHandle) -> Result<ResourceReport, String> {
+    let mut checks = Vec::new();
+
+    match find_dist_backend(&app) {
+        Ok(dist_backend) => {
+            checks.push(check_resource_file("deploy-commands.js", &dist_backend.join("deploy-commands.js")));
+            checks.push(check_resource_file("commands.json", &dist_backend.join("commands.json")));
+
+            let commands_dir = dist_backend.join("commands");
+            let command_files: Vec<fs::DirEntry> = fs::read_dir(&commands_dir)
+                .map(|entries| entries.flatten().filter(|entry| is_js_file(&entry.path())).collect())
+                .unwrap_or_default();
+            let empty_files: Vec<String> = command_files.iter()
+                .filter(|entry| entry.metadata().map(|m| m.len() == 0).unwrap_or(true))
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .collect();
+
+            let problem = if !commands_dir.is_dir() {
+                Some("is missing".to_string())
+            } else if command_files.is_empty() {
+                Some("contains no command files".to_string())
+            } else if !empty_files.is_empty() {
+                Some(format!("has empty files: {}", empty_files.join(", ")))
+            } else {
+                None
+            };
+            checks.push(ResourceCheck {
+                name: "commands folder".to_string(),
+                path: Some(commands_dir.display().to_string()),
+                ok: problem.is_none(),
+                problem,
+            });
+        }
+        Err(e) => checks.push(ResourceCheck {
+            name: "dist-backend".to_string(),
+            path: None,
+            ok: false,
+            problem: Some(e),
+        }),
+    }
+
+    // Dev builds run main.js from the source tree, so only check bot.exe in release
+    if !cfg!(debug_assertions) {
+        match build_bot_command(&app) {
+            Ok((_, bot_exe)) => checks.push(check_resource_file("bot.exe", &bot_exe)),
+            Err(e) => checks.push(ResourceCheck {
+                name: "bot.exe".to_string(),
+                path: None,
+                ok: false,
+                problem: Some(e),
+            }),
+        }
+    }
+
+    Ok(ResourceReport {
+        ok: checks.iter().all(|check| check.ok),
+        checks,
+    })
+}
+
+#[tauri::command]
+fn copy_commands_folder(app: tauri::AppHandle, clean: bool) -> Result<String, String> {
+    log::debug!("copy_commands_folder command called (clean: {})", clean);
+
+    // Get AppData directory
+    let app_dir = app_data_dir(&app)?;
+    let commands_dir = app_dir.join("commands");
+
+    // Get resource directory
+    let resource_path = app.path().resource_dir()
+        .map_err(|e| format!("Failed to get resource dir: {}", e))?;
+
+    log::debug!("Resource directory: {:?}", resource_path);
+
+    // Check multiple possible locations for commands
+    // 1. Direct path (dev builds): dist-backend/commands
+    // 2. _up_ subdirectory (production builds): _up_/dist-backend/commands
+    let possible_paths = vec![
+        resource_path.join("dist-backend").join("commands"),
+        resource_path.join("_up_").join("dist-backend").join("commands"),
+    ];
+
+    let mut source_commands_path = None;
+    for path in &possible_paths {
+        log::debug!("Checking for commands at: {:?}", path);
+        if path.exists() {
+            source_commands_path = Some(path.clone());
+            log::debug!("Found commands directory at: {:?}", path);
+            break;
+        }
+    }
+
+    let source_commands_path = source_commands_path.ok_or_else(|| {
+        format!(
+            "Commands not found. Checked:\n  - {:?}\n  - {:?}",
+            possible_paths[0],
+            possible_paths[1]
+        )
+    })?;
+
+    // Create commands directory if it doesn't exist
+    if !commands_dir.exists() {
+        fs::create_dir_all(&commands_dir)
+            .map_err(|e| format!("Failed to create commands directory: {}", e))?;
+    }
+
+    // With clean set, drop .js files the bundle no longer ships so the
+    // deployed command set matches it exactly
+    let mut removed_files = Vec::new();
+    if clean {
+        let existing = fs::read_dir(&commands_dir)
+            .map_err(|e| format!("Failed to read AppData commands directory: {}", e))?;
+
+        for entry in existing.flatten() {
+            let file_name = entry.file_name();
+
+            if is_js_file(&entry.path()) && !source_commands_path.join(&file_name).exists() {
+                log::info!("Removing stale command file {:?}", entry.path());
+                fs::remove_file(entry.path())
+                    .map_err(|e| format!("Failed to remove stale {:?}: {}", file_name, e))?;
+                removed_files.push(file_name.to_string_lossy().to_string());
+            }
+        }
+    }
+
+    // Find all .js files in the bundled commands directory
+    let entries = fs::read_dir(&source_commands_path)
+        .map_err(|e| format!("Failed to read commands directory: {}", e))?;
+
+    let mut copied_files = Vec::new();
+
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+
+        if is_js_file(&entry.path()) {
+            let source_file = source_commands_path.join(&file_name);
+            let dest_file = commands_dir.join(&file_name);
+
+            log::debug!("Copying {:?} to {:?}", source_file, dest_file);
+            fs::copy(&source_file, &dest_file)
+                .map_err(|e| format!("Failed to copy {:?}: {}", file_name, e))?;
+
+            copied_files.push(file_name.to_string_lossy().to_string());
+        }
+    }
+
+    if copied_files.is_empty() {
+        return Err("No command files found to copy".to_string());
+    }
+
+    let mut summary = format!(
+        "Successfully copied {} command file(s) to:\n{:?}\n\nFiles:\n{}",
+        copied_files.len(),
+        commands_dir,
+        copied_files.join("\n")
+    );
+
+    if !removed_files.is_empty() {
+        summary.push_str(&format!(
+            "\n\nRemoved {} stale file(s):\n{}",
+            removed_files.len(),
+            removed_files.join("\n")
+        ));
+    }
+
+    Ok(summary)
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct UpdateInfo {
+    version: String,
+    #[serde(rename = "currentVersion")]
+    current_version: String,
+    available: bool,
+    #[serde(rename = "isPrerelease")]
+    is_prerelease: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    changelog: Option<String>,
+    #[serde(rename = "changelogSections", skip_serializing_if = "Option::is_none")]
+    changelog_sections: Option<StructuredChangelog>,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+struct StructuredChangelog {
+    features: Vec<String>,
+    fixes: Vec<String>,
+    breaking: Vec<String>,
+    other: Vec<String>,
+}
+
+// Split a release body into categories using its markdown headings
+fn parse_changelog(markdown: &str) -> StructuredChangelog {
+    let mut changelog = StructuredChangelog::default();
+    let mut current = &mut changelog.other;
+
+    for line in markdown.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with('#') {
+            let heading = line.trim_start_matches('#').trim().to_lowercase();
+            current = if heading.contains("breaking") {
+                &mut changelog.breaking
+            } else if heading.contains("fix") || heading.contains("bug") {
+                &mut changelog.fixes
+            } else if heading.contains("feature") || heading.contains("new") || heading.contains("added") {
+                &mut changelog.features
+            } else {
+                &mut changelog.other
+            };
+            continue;
+        }
+
+        // Strip list markers so the UI can render its own bullets
+        let item = line
+            .strip_prefix("- ")
+            .or_else(|| line.strip_prefix("* "))
+            .unwrap_or(line)
+            .trim();
+        if !item.is_empty() {
+            current.push(item.to_string());
+        }
+    }
+
+    changelog
+}
+
+// Helper struct for GitHub API response
+#[derive(Deserialize)]
+struct GitHubRelease {
+    body: Option<String>,
+    #[serde(default)]
+    tag_name: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    prerelease: bool,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    published_at: Option<String>,
+}
+
+// Probed by check_connectivity; the updater depends on it anyway
+const CONNECTIVITY_CHECK_URL: &str = "https://api.github.com";
+const CONNECTIVITY_TIMEOUT_SECS: u64 = 5;
+
+#[derive(Clone, Serialize, Deserialize)]
+struct ConnectivityStatus {
+    online: bool,
+    #[serde(rename = "latencyMs")]
+    latency_ms: Option<u64>,
+    error: Option<String>,
+}
+
+// Quick online/offline check so the UI can disable network features up front
+#[tauri::command]
+async fn check_connectivity() -> Result<ConnectivityStatus, String> {
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(CONNECTIVITY_TIMEOUT_SECS))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let started = std::time::Instant::now();
+    // Any HTTP response means we got through, even a rate-limit error
+    match client.head(CONNECTIVITY_CHECK_URL).header("User-Agent", "DaeBot").send().await {
+        Ok(_) => Ok(ConnectivityStatus {
+            online: true,
+            latency_ms: Some(started.elapsed().as_millis() as u64),
+            error: None,
+        }),
+        Err(e) => {
+            log::debug!("Connectivity check failed: {}", e);
+            Ok(ConnectivityStatus {
+                online: false,
+                latency_ms: None,
+                error: Some(if e.is_timeout() {
+                    format!("No response within {} seconds", CONNECTIVITY_TIMEOUT_SECS)
+                } else {
+                    e.to_string()
+                }),
+            })
+        }
+    }
+}
+
+// Fetch changelog from GitHub releases
+async fn fetch_changelog(version: &str) -> Option<String> {
+    let url = format!("https://api.github.com/repos/Drizzyt77/DaeBotJS/releases/tags/v{}", version);
+
+    match reqwest::Client::new()
+        .get(&url)
+        .header("User-Agent", "DaeBot")
+        .send()
+        .await
+    {
+        Ok(response) => {
+            match response.json::<GitHubRelease>().await {
+                Ok(release) => release.body,
+                Err(e) => {
+                    log::warn!("Failed to parse GitHub release: {}", e);
+                    None
+                }
+            }
+        }
+        Err(e) => {
+            log::warn!("Failed to fetch changelog from GitHub: {}", e);
+            None
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct ReleaseNotes {
+    version: String,
+    name: Option<String>,
+    #[serde(rename = "publishedAt")]
+    published_at: Option<String>,
+    #[serde(rename = "isPrerelease")]
+    is_prerelease: bool,
+    changelog: Option<String>,
+    #[serde(rename = "changelogSections", skip_serializing_if = "Option::is_none")]
+    changelog_sections: Option<StructuredChangelog>,
+}
+
+// Notes for the newest release on the user's channel, whether or not it is
+// newer than what's installed (for an "About / What's New" screen)
+#[tauri::command]
+async fn get_latest_release_notes(app: tauri::AppHandle) -> Result<ReleaseNotes, String> {
+    let beta_channel = get_bot_settings(app.clone())
+        .map(|s| s.beta_channel)
+        .unwrap_or(false);
+
+    let client = reqwest::Client::new();
+    let request = |url: &str| client.get(url).header("User-Agent", "DaeBot").send();
+
+    // releases/latest skips pre-releases, so the beta channel takes the newest
+    // non-draft entry from the full list instead
+    let release = if beta_channel {
+        let response = request("https://api.github.com/repos/Drizzyt77/DaeBotJS/releases?per_page=10").await
+            .map_err(|e| format!("Failed to fetch releases from GitHub: {}", e))?;
+        if !response.status().is_success() {
+            return Err(format!("GitHub API error ({})", response.status()));
+        }
+        let releases: Vec<GitHubRelease> = response.json().await
+            .map_err(|e| format!("Failed to parse GitHub releases: {}", e))?;
+        releases.into_iter()
+            .find(|release| !release.draft)
+            .ok_or("No releases found")?
+    } else {
+        let response = request("https://api.github.com/repos/Drizzyt77/DaeBotJS/releases/latest").await
+            .map_err(|e| format!("Failed to fetch latest release from GitHub: {}", e))?;
+        if !response.status().is_success() {
+            return Err(format!("GitHub API error ({})", response.status()));
+        }
+        response.json().await
+            .map_err(|e| format!("Failed to parse GitHub release: {}", e))?
+    };
+
+    Ok(ReleaseNotes {
+        version: release.tag_name.trim_start_matches('v').to_string(),
+        name: release.name,
+        published_at: release.published_at,
+        is_prerelease: release.prerelease,
+        changelog_sections: release.body.as_deref().map(parse_changelog),
+        changelog: release.body,
+    })
+}
+
+// Release manifest the updater checks for the given channel
+fn update_endpoint(beta_channel: bool) -> &'static str {
+    if beta_channel {
+        "https://github.com/Drizzyt77/DaeBotJS/releases/latest/download/latest-beta.json"
+    } else {
+        "https://github.com/Drizzyt77/DaeBotJS/releases/latest/download/latest.json"
+    }
+}
+
+#[derive(Serialize)]
+struct UpdaterConfigInfo {
+    #[serde(rename = "configuredEndpoints")]
+    configured_endpoints: Vec<String>,
+    #[serde(rename = "activeEndpoint")]
+    active_endpoint: String,
+    #[serde(rename = "pubkeyPresent")]
+    pubkey_present: bool,
+    #[serde(rename = "buildOk")]
+    build_ok: bool,
+    #[serde(rename = "buildError")]
+    build_error: Option<String>,
+}
+
+// Diagnostic for self-hosted builds: what the updater is configured with and
+// whether it can actually be constructed
+#[tauri::command]
+fn get_updater_config(app: tauri::AppHandle) -> Result<UpdaterConfigInfo, String> {
+    let updater_config = app.config().plugins.0.get("updater").cloned()
+        .unwrap_or(serde_json::Value::Null);
+
+    let configured_endpoints = updater_config.get("endpoints")
+        .and_then(|v| v.as_array())
+        .map(|endpoints| endpoints.iter()
+            .filter_map(|e| e.as_str().map(String::from))
+            .collect())
+        .unwrap_or_default();
+
+    let pubkey_present = updater_config.get("pubkey")
+        .and_then(|v| v.as_str())
+        .map(|key| !key.trim().is_empty())
+        .unwrap_or(false);
+
+    let beta_channel = get_bot_settings(app.clone())
+        .map(|s| s.beta_channel)
+        .unwrap_or(false);
+    let active_endpoint = update_endpoint(beta_channel).to_string();
+
+    // Mirror what check_for_updates does so any failure shows up here too
+    let build_result = Url::parse(&active_endpoint)
+        .map_err(|e| format!("Invalid update URL: {}", e))
+        .and_then(|url| app.updater_builder()
+            .endpoints(vec![url])
+            .map_err(|e| format!("Failed to set update endpoints: {}", e)))
+        .and_then(|builder| builder.build()
+            .map_err(|e| format!("Failed to build updater: {}", e)));
+
+    let build_error = build_result.err();
+
+    Ok(UpdaterConfigInfo {
+        configured_endpoints,
+        active_endpoint,
+        pubkey_present,
+        build_ok: build_error.is_none(),
+        build_error,
+    })
+}
+
+#[tauri::command]
+async fn check_for_updates(app: tauri::AppHandle) -> Result<UpdateInfo, String> {
+    log::info!("Checking for updates...");
+
+    // Get bot settings to check beta channel preference
+    let settings = match get_bot_settings(app.clone()) {
+        Ok(s) => s,
+        Err(e) => {
+            log::warn!("Failed to get bot settings: {}, defaulting to stable channel", e);
+            // If we can't get settings, default to stable channel (beta_channel = false)
+            BotSettings {
+                season_id: 0,
+                season_name: String::new(),
+                default_region: String::new(),
+                default_realm: String::new(),
+                active_dungeons: Vec::new(),
+                beta_channel: false,
+                token_region: default_token_region(),
+                report_schedule: None,
+                token_alert_threshold: None,
+                token_alert_direction: default_token_alert_direction(),
+                updated_at: None,
+            }
+        }
+    };
+
+    let current_version = app.package_info().version.to_string();
+    log::debug!("Current version: {}", current_version);
+    log::debug!("Beta channel enabled: {}", settings.beta_channel);
+
+    // Use different update endpoint based on beta channel setting
+    let update_endpoint = update_endpoint(settings.beta_channel);
+    log::debug!("Using update endpoint: {}", update_endpoint);
+
+    // Parse the endpoint URL
+    let update_url = match Url::parse(update_endpoint) {
+        Ok(url) => url,
+        Err(e) => {
+            return Err(format!("Invalid update URL: {}", e));
+        }
+    };
+
+    // Try to check for updates using the updater API
+    let updater_builder = app.updater_builder()
+        .endpoints(vec![update_url])
+        .map_err(|e| format!("Failed to set update endpoints: {}", e))?;
+
+    match updater_builder.build() {
+        Ok(updater) => {
+            match updater.check().await {
+                Ok(update_result) => {
+                    if let Some(update) = update_result {
+                        let new_version = update.version.clone();
+                        let is_prerelease = new_version.contains("beta") || new_version.contains("alpha") || new_version.contains("rc");
+
+                        log::info!("Update available: {}", new_version);
+                        log::debug!("Is pre-release: {}", is_prerelease);
+
+                        // If user is on stable channel, don't show pre-release updates
+                        if !settings.beta_channel && is_prerelease {
+                            log::info!("Skipping pre-release update (user is on stable channel)");
+                            return Ok(UpdateInfo {
+                                version: current_version.clone(),
+                                current_version,
+                                available: false,
+                                is_prerelease: false,
+                                changelog: None,
+                                changelog_sections: None,
+                            });
+                        }
+
+                        // Fetch changelog from GitHub
+                        let changelog = fetch_changelog(&new_version).await;
+                        let changelog_sections = changelog.as_deref().map(parse_changelog);
+
+                        Ok(UpdateInfo {
+                            version: new_version,
+                            current_version,
+                            available: true,
+                            is_prerelease,
+                            changelog,
+                            changelog_sections,
+                        })
+                    } else {
+                        log::info!("No updates available");
+                        Ok(UpdateInfo {
+                            version: current_version.clone(),
+                            current_version,
+                            available: false,
+                            is_prerelease: false,
+                            changelog: None,
+                            changelog_sections: None,
+                        })
+                    }
+                }
+                Err(e) => {
+                    log::error!("Error checking for updates: {}", e);
+                    // Return no update available on error
+                    Ok(UpdateInfo {
+                        version: current_version.clone(),
+                        current_version,
+                        available: false,
+                        is_prerelease: false,
+                        changelog: None,
+                        changelog_sections: None,
+                    })
+                }
+            }
+        }
+        Err(e) => {
+            log::error!("Error building updater: {}", e);
+            Ok(UpdateInfo {
+                version: current_version.clone(),
+                current_version,
+                available: false,
+                is_prerelease: false,
+                changelog: None,
+                changelog_sections: None,
+            })
+        }
+    }
+}
+
+#[tauri::command]
+fn get_app_version(app: tauri::AppHandle) -> String {
+    app.package_info().version.to_string()
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct AppPaths {
+    #[serde(rename = "appDataDir")]
+    app_data_dir: String,
+    #[serde(rename = "configPath")]
+    config_path: String,
+    #[serde(rename = "databasePath")]
+    database_path: String,
+    #[serde(rename = "logsDir")]
+    logs_dir: String,
+    #[serde(rename = "commandsDir")]
+    commands_dir: String,
+    #[serde(rename = "updaterLogPath")]
+    updater_log_path: String,
+    #[serde(rename = "appLogPath")]
+    app_log_path: String,
+}
+
+#[tauri::command]
+fn get_paths(app: tauri::AppHandle) -> Result<AppPaths, String> {
+    let app_dir = app_data_dir(&app)?;
+
+    Ok(AppPaths {
+        app_data_dir: app_dir.display().to_string(),
+        config_path: app_dir.join("config.json").display().to_string(),
+        database_path: app_dir.join("data").join("mythic_runs.db").display().to_string(),
+        logs_dir: app_dir.join("logs").display().to_string(),
+        commands_dir: app_dir.join("commands").display().to_string(),
+        updater_log_path: updater_log_path().display().to_string(),
+        app_log_path: app_dir.join(format!("{}.log", APP_LOG_FILE_NAME)).display().to_string(),
+    })
+}
+
+#[tauri::command]
+fn get_blizzard_credentials(app: tauri::AppHandle) -> Result<BlizzardCredentials, String> {
+    let app_dir = app_data_dir(&app)?;
+
+    fs::create_dir_all(&app_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+
+    let env_path = app_dir.join(".env");
+    log::debug!("Loading .env from: {:?}", env_path);
+
+    if !env_path.exists() {
+        // Return empty credentials
+        return Ok(BlizzardCredentials {
+            client_id: String::new(),
+            client_secret: String::new(),
+        });
+    }
+
+    let content = fs::read_to_string(&env_path)
+        .map_err(|e| format!("Failed to read .env: {}", e))?;
+
+    let mut client_id = String::new();
+    let mut client_secret = String::new();
+
+    for line in content.lines() {
+        if let Some((key, value)) = line.split_once('=') {
+            let key = key.trim();
+            let value = value.trim();
+            match key {
+                "BLIZZARD_CLIENT_ID" => client_id = value.to_string(),
+                "BLIZZARD_CLIENT_SECRET" => client_secret = value.to_string(),
+                _ => {}
+            }
+        }
+    }
+
+    remember_secret(&client_secret);
+
+    Ok(BlizzardCredentials {
+        client_id,
+        client_secret,
+    })
+}
+
+#[tauri::command]
+fn save_blizzard_credentials(app: tauri::AppHandle, credentials: BlizzardCredentials) -> Result<(), String> {
+    let app_dir = app_data_dir(&app)?;
+
+    fs::create_dir_all(&app_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+
+    let env_path = app_dir.join(".env");
+    log::debug!("Saving .env to: {:?}", env_path);
+
+    remember_secret(&credentials.client_secret);
+
+    let content = format!(
+        "BLIZZARD_CLIENT_ID={}\nBLIZZARD_CLIENT_SECRET={}\n",
+        credentials.client_id,
+        credentials.client_secret
+    );
+
+    write_file_atomic(&env_path, content)
+        .map_err(|e| format!("Failed to write .env: {}", e))
+}
+
+// Helper function to snapshot a file so it can be restored on rollback
+fn snapshot_file(path: &PathBuf) -> Option<Vec<u8>> {
+    fs::read(path).ok()
+}
+
+// Helper function to put a file back the way snapshot_file found it
+fn restore_file(path: &PathBuf, snapshot: &Option<Vec<u8>>) {
+    let result = match snapshot {
+        Some(content) => write_file_atomic(path, content),
+        None if path.exists() => fs::remove_file(path),
+        None => Ok(()),
+    };
+
+    if let Err(e) = result {
+        log::warn!("Failed to restore {:?}: {}", path, e);
+    }
+}
+
+#[tauri::command]
+fn apply_initial_setup(
+    app: tauri::AppHandle,
+    config: Config,
+    blizzard_credentials: Option<BlizzardCredentials>,
+    bot_settings: Option<BotSettings>,
+) -> Result<(), String> {
+    log::debug!("apply_initial_setup called");
+
+    // Validate everything before touching any files
+    if config.client_id.trim().is_empty() {
+        return Err("Discord client ID is required".to_string());
+    }
+    if config.guild_id.trim().is_empty() {
+        return Err("Discord guild ID is required".to_string());
+    }
+    if let Some(ref credentials) = blizzard_credentials {
+        if credentials.client_id.trim().is_empty() != credentials.client_secret.trim().is_empty() {
+            return Err("Blizzard client ID and client secret must be provided together".to_string());
+        }
+    }
+    if let Some(ref settings) = bot_settings {
+        validate_bot_settings(settings)?;
+    }
+
+    let app_dir = app_data_dir(&app)?;
+
+    fs::create_dir_all(&app_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+
+    // Remember the current files so a failure leaves the user where they started
+    let config_path = app_dir.join("config.json");
+    let env_path = app_dir.join(".env");
+    let config_snapshot = snapshot_file(&config_path);
+    let env_snapshot = snapshot_file(&env_path);
+
+    let result = (|| {
+        save_config(app.clone(), config)?;
+
+        if let Some(credentials) = blizzard_credentials {
+            save_blizzard_credentials(app.clone(), credentials)?;
+        }
+
+        // Last step since the database update can't be rolled back with the files
+        if let Some(settings) = bot_settings {
+            update_bot_settings(app.clone(), settings)?;
+        }
+
+        Ok::<(), String>(())
+    })();
+
+    if let Err(e) = result {
+        log::error!("Initial setup failed, rolling back: {}", e);
+        restore_file(&config_path, &config_snapshot);
+        restore_file(&env_path, &env_snapshot);
+        return Err(format!("Setup failed and no changes were saved: {}", e));
+    }
+
+    log::info!("Initial setup applied successfully");
+    Ok(())
+}
+
+#[tauri::command]
+fn import_database(app: tauri::AppHandle, file_path: PathBuf) -> Result<String, String> {
+    // Keep the path as an OS path; it may not be valid UTF-8
+    let source_path = file_path;
+    log::debug!("[import_database] Called with file_path: {:?}", source_path);
+    log::debug!("[import_database] file_path is_empty: {}", source_path.as_os_str().is_empty());
+    log::debug!("[import_database] PathBuf exists: {}", source_path.exists());
+
+    // Verify source file exists
+    if !source_path.exists() {
+        let error_msg = format!("Source database file does not exist: '{}'", source_path.display());
+        log::error!("[import_database] {}", error_msg);
+        return Err(error_msg);
+    }
+
+    // Verify it's a valid SQLite database by trying to open it
+    let counts = match Connection::open(&source_path) {
+        Ok(conn) => {
+            validate_database_tables(&conn)?;
+            import_row_counts(&conn)?
+        }
+        Err(e) => {
+            return Err(format!("Invalid SQLite database: {}", e));
+        }
+    };
+    log::info!(
+        "Import source has {} runs, {} characters, {} seasons",
+        counts.runs, counts.characters, counts.seasons
+    );
+
+    // Get destination path
+    let app_dir = app_data_dir(&app)?;
+
+    let data_dir = app_dir.join("data");
+    fs::create_dir_all(&data_dir)
+        .map_err(|e| format!("Failed to create data directory: {}", e))?;
+
+    let dest_path = data_dir.join("mythic_runs.db");
+
+    // Backup existing database if it exists
+    let mut pruned = Vec::new();
+    if dest_path.exists() {
+        backup_live_database(&dest_path)?;
+        pruned = prune_database_backups(&app);
+    }
+
+    // Copy the new database
+    fs::copy(&source_path, &dest_path)
+        .map_err(|e| format!("Failed to copy database: {}", e))?;
+
+    log::info!("Database imported successfully to: {:?}", dest_path);
+
+    // Imports can come from much older versions of the bot
+    if let Err(e) = migrate_database(app.clone()) {
+        log::warn!("Failed to migrate imported database: {}", e);
+    }
+
+    let mut result = format!(
+        "Database imported successfully! Old database backed up if it existed.\n\n\
+         Runs: {}\nCharacters: {}\nSeasons: {}",
+        counts.runs, counts.characters, counts.seasons
+    );
+    if !pruned.is_empty() {
+        result.push_str(&format!("\n\nRemoved old backups: {}", pruned.join(", ")));
+    }
+    if counts.runs == 0 {
+        result.push_str("\n\nWarning: the imported database has no mythic runs, so no run history was brought over.");
+    }
+    Ok(result)
+}
+
+// Checks a database to be imported or restored has at least one of the tables
+// the bot uses, so an unrelated SQLite file can't replace the real one
+fn validate_database_tables(conn: &Connection) -> Result<(), String> {
+    let table_check: Result<i64, _> = conn.query_row(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND (name='mythic_runs' OR name='token_prices')",
+        [],
+        |row| row.get(0)
+    );
+
+    match table_check {
+        Ok(count) if count > 0 => {
+            log::info!("Database validation passed, found {} expected tables", count);
+            Ok(())
+        }
+        _ => Err("Database does not contain expected tables (mythic_runs or token_prices)".to_string()),
+    }
+}
+
+// Gzips the live database into a timestamped backup next to it, checkpointing
+// first so runs still in the WAL are included. Returns the backup file name.
+fn backup_live_database(db_path: &Path) -> Result<String, String> {
+    let data_dir = db_path.parent()
+        .ok_or_else(|| "Database path has no parent folder".to_string())?;
+    let backup_name = format!(
+        "{}{}.db.gz",
+        DB_BACKUP_PREFIX,
+        chrono::Local::now().format("%Y%m%d_%H%M%S")
+    );
+    let backup_path = data_dir.join(&backup_name);
+
+    if let Ok(conn) = Connection::open(db_path) {
+        let _ = conn.busy_timeout(std::time::Duration::from_secs(5));
+        if let Err(e) = conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(())) {
+            log::warn!("Failed to checkpoint database before backup: {}", e);
+        }
+    }
+
+    log::info!("Backing up existing database to: {:?}", backup_path);
+    compress_file(&db_path.to_path_buf(), &backup_path)
+        .map_err(|e| format!("Failed to backup existing database: {}", e))?;
+    Ok(backup_name)
+}
+
+struct ImportCounts {
+    runs: i64,
+    characters: i64,
+    seasons: i64,
+}
+
+// What an import would actually bring over; zero for anything the file doesn't have
+fn import_row_counts(conn: &Connection) -> Result<ImportCounts, String> {
+    let columns: Vec<String> = conn
+        .prepare("SELECT name FROM pragma_table_info('mythic_runs')")
+        .and_then(|mut stmt| {
+            stmt.query_map([], |row| row.get(0))?
+                .collect::<Result<Vec<String>, _>>()
+        })
+        .map_err(|e| format!("Failed to read mythic_runs columns: {}", e))?;
+
+    // token_prices-only databases have no mythic_runs table at all
+    if columns.is_empty() {
+        return Ok(ImportCounts { runs: 0, characters: 0, seasons: 0 });
+    }
+
+    let count = |sql: &str| -> Result<i64, String> {
+        conn.query_row(sql, [], |row| row.get(0))
+            .map_err(|e| format!("Failed to count imported data: {}", e))
+    };
+
+    let characters = if columns.iter().any(|c| c == "character_id") {
+        count("SELECT COUNT(DISTINCT character_id) FROM mythic_runs")?
+    } else {
+        0
+    };
+    // Databases from before seasons were tracked have no season column
+    let seasons = if columns.iter().any(|c| c == "season") {
+        count("SELECT COUNT(DISTINCT season) FROM mythic_runs WHERE season IS NOT NULL")?
+    } else {
+        0
+    };
+
+    Ok(ImportCounts {
+        runs: count("SELECT COUNT(*) FROM mythic_runs")?,
+        characters,
+        seasons,
+    })
+}
+
+// Copy size for export_database; one export-progress event per chunk
+const EXPORT_CHUNK_SIZE: usize = 1024 * 1024;
+
+#[derive(Clone, Serialize)]
+struct ExportProgress {
+    #[serde(rename = "bytesCopied")]
+    bytes_copied: u64,
+    #[serde(rename = "totalBytes")]
+    total_bytes: u64,
+}
+
+#[tauri::command]
+async fn export_database(app: tauri::AppHandle, dest_path: PathBuf) -> Result<u64, String> {
+    let app_dir = app_data_dir(&app)?;
+
+    let source_path = app_dir.join("data").join("mythic_runs.db");
+    if !source_path.exists() {
+        return Err("Database not found".to_string());
+    }
+    if dest_path == source_path {
+        return Err("Choose a destination other than the live database".to_string());
+    }
+    match dest_path.parent() {
+        Some(dir) if dir.is_dir() => {}
+        _ => return Err(format!("Destination folder does not exist: {}", dest_path.display())),
+    }
+
+    // The copy is all blocking file IO; keep it off the async runtime
+    tauri::async_runtime::spawn_blocking(move || copy_database_for_export(&app, &source_path, &dest_path))
+        .await
+        .map_err(|e| format!("Export failed: {}", e))?
+}
+
+fn copy_database_for_export(app: &tauri::AppHandle, source_path: &Path, dest_path: &Path) -> Result<u64, String> {
+    // Fold the WAL into the main file first, then hold a read transaction for
+    // the copy so the bot's new writes stay in the WAL instead of being
+    // checkpointed into the file mid-copy
+    let conn = Connection::open(source_path)
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    conn.busy_timeout(std::time::Duration::from_secs(5))
+        .map_err(|e| format!("Failed to set busy timeout: {}", e))?;
+    let busy: i64 = conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| row.get(0))
+        .map_err(|e| format!("Failed to checkpoint database: {}", e))?;
+    // A blocked checkpoint leaves runs in the WAL that a file copy would miss
+    if busy != 0 {
+        return Err("The database is busy (the bot may be syncing); try the export again in a moment".to_string());
+    }
+    conn.execute_batch("BEGIN")
+        .and_then(|_| conn.query_row("SELECT COUNT(*) FROM sqlite_master", [], |_| Ok(())))
+        .map_err(|e| format!("Failed to lock database for export: {}", e))?;
+
+    let mut input = fs::File::open(source_path)
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    let total_bytes = input.metadata()
+        .map_err(|e| format!("Failed to read database size: {}", e))?
+        .len();
+    let mut output = fs::File::create(dest_path)
+        .map_err(|e| format!("Failed to create export file: {}", e))?;
+
+    // Copy in chunks rather than fs::copy so large databases report progress
+    let mut buffer = vec![0u8; EXPORT_CHUNK_SIZE];
+    let mut bytes_copied: u64 = 0;
+    loop {
+        let read = input.read(&mut buffer)
+            .map_err(|e| format!("Failed to read database: {}", e))?;
+        if read == 0 {
+            break;
+        }
+
+        output.write_all(&buffer[..read])
+            .map_err(|e| format!("Failed to write export file: {}", e))?;
+        bytes_copied += read as u64;
+
+        let _ = app.emit("export-progress", ExportProgress {
+            bytes_copied,
+            total_bytes,
+        });
+    }
+
+    output.sync_all()
+        .map_err(|e| format!("Failed to flush export file: {}", e))?;
+    drop(conn);
+
+    log::info!("Exported database ({} bytes) to: {:?}", bytes_copied, dest_path);
+    Ok(bytes_copied)
+}
+
+// Copies the database for sharing, replacing character names, realms and
+// Discord user ids with stable placeholders so runs still line up with their
+// characters, and dropping keystone run ids that would identify them
+#[tauri::command]
+fn export_anonymized_database(app: tauri::AppHandle, dest_path: PathBuf) -> Result<String, String> {
+    let app_dir = app_data_dir(&app)?;
+
+    let source_path = app_dir.join("data").join("mythic_runs.db");
+    if !source_path.exists() {
+        return Err("Database not found".to_string());
+    }
+    if dest_path == source_path {
+        return Err("Choose a destination other than the live database".to_string());
+    }
+
+    // VACUUM INTO refuses to overwrite, and the save dialog already confirmed it
+    if dest_path.exists() {
+        fs::remove_file(&dest_path)
+            .map_err(|e| format!("Failed to replace existing export file: {}", e))?;
+    }
+
+    // VACUUM INTO includes anything still in the WAL, unlike a file copy
+    let source = Connection::open(&source_path)
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    source.busy_timeout(std::time::Duration::from_secs(5))
+        .map_err(|e| format!("Failed to set busy timeout: {}", e))?;
+    source.execute("VACUUM INTO ?1", [dest_path.to_string_lossy()])
+        .map_err(|e| format!("Failed to copy database: {}", e))?;
+    drop(source);
+
+    let scrub = || -> Result<usize, String> {
+        let mut conn = Connection::open(&dest_path)
+            .map_err(|e| format!("Failed to open export copy: {}", e))?;
+        let tx = conn.transaction()
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        // Rename by id first so the realm updates can't hit UNIQUE(name, realm, region)
+        let characters = tx
+            .execute("UPDATE characters SET name = 'character-' || id", [])
+            .map_err(|e| format!("Failed to anonymize characters: {}", e))?;
+
+        let realms: Vec<String> = tx
+            .prepare("SELECT DISTINCT realm FROM characters ORDER BY realm")
+            .and_then(|mut stmt| {
+                stmt.query_map([], |row| row.get(0))?
+                    .collect::<Result<Vec<String>, _>>()
+            })
+            .map_err(|e| format!("Failed to read realms: {}", e))?;
+        for (i, realm) in realms.iter().enumerate() {
+            tx.execute(
+                "UPDATE characters SET realm = ?1 WHERE realm = ?2",
+                rusqlite::params![format!("realm-{}", i + 1), realm],
+            )
+            .map_err(|e| format!("Failed to anonymize realms: {}", e))?;
+        }
+
+        // Sync errors quote character names back from the Blizzard API
+        if table_exists(&tx, "sync_history")? {
+            tx.execute(
+                "UPDATE sync_history SET error_message = '[removed]' WHERE error_message IS NOT NULL",
+                [],
+            )
+            .map_err(|e| format!("Failed to anonymize sync history: {}", e))?;
+        }
+
+        // Raider.IO resolves a keystone run id back to the real characters
+        if table_exists(&tx, "mythic_runs")? {
+            tx.execute("UPDATE mythic_runs SET keystone_run_id = NULL", [])
+                .map_err(|e| format!("Failed to anonymize runs: {}", e))?;
+        }
+
+        // These are real Discord user ids; keep the rows so DM counts still add up
+        if table_exists(&tx, "token_user_preferences")? {
+            tx.execute("UPDATE token_user_preferences SET user_id = 'user-' || rowid", [])
+                .map_err(|e| format!("Failed to anonymize token preferences: {}", e))?;
+        }
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit anonymized export: {}", e))?;
+
+        // Old values linger in free pages until the file is rebuilt
+        conn.execute_batch("VACUUM")
+            .map_err(|e| format!("Failed to compact export copy: {}", e))?;
+
+        Ok(characters)
+    };
+
+    match scrub() {
+        Ok(characters) => {
+            log::info!("Exported anonymized database ({} characters) to: {:?}", characters, dest_path);
+            Ok(format!(
+                "Anonymized database exported to {} ({} characters renamed)",
+                dest_path.display(),
+                characters
+            ))
+        }
+        Err(e) => {
+            // Never leave a half-scrubbed copy behind
+            let _ = fs::remove_file(&dest_path);
+            Err(e)
+        }
+    }
+}
+
+const DB_BACKUP_PREFIX: &str = "mythic_runs_backup_";
+
+// Gzip `src` into `dest`, returning the compressed size
+fn compress_file(src: &PathBuf, dest: &PathBuf) -> std::io::Result<u64> {
+    let mut input = fs::File::open(src)?;
+    let output = fs::File::create(dest)?;
+    let mut encoder = flate2::write::GzEncoder::new(output, flate2::Compression::default());
+    std::io::copy(&mut input, &mut encoder)?;
+    let file = encoder.finish()?;
+    file.sync_all()?;
+    Ok(file.metadata()?.len())
+}
+
+// Inflate a gzip file written by compress_file
+fn decompress_file(src: &PathBuf, dest: &PathBuf) -> std::io::Result<u64> {
+    let input = fs::File::open(src)?;
+    let mut decoder = flate2::read::GzDecoder::new(input);
+    let mut output = fs::File::create(dest)?;
+    let written = std::io::copy(&mut decoder, &mut output)?;
+    output.sync_all()?;
+    Ok(written)
+}
+
+#[derive(Serialize)]
+struct DatabaseBackup {
+    name: String,
+    #[serde(rename = "sizeBytes")]
+    size_bytes: u64,
+    compressed: bool,
+    #[serde(rename = "createdAt")]
+    created_at: Option<String>,
+}
+
+#[tauri::command]
+fn list_database_backups(app: tauri::AppHandle) -> Result<Vec<DatabaseBackup>, String> {
+    let app_dir = app_data_dir(&app)?;
+
+    let data_dir = app_dir.join("data");
+    if !data_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries = fs::read_dir(&data_dir)
+        .map_err(|e| format!("Failed to read data directory: {}", e))?;
+
+    let mut backups = Vec::new();
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let Some(rest) = name.strip_prefix(DB_BACKUP_PREFIX) else {
+            continue;
+        };
+
+        // Both the gzipped form and older uncompressed backups are listed
+        let (stamp, compressed) = if let Some(stamp) = rest.strip_suffix(".db.gz") {
+            (stamp, true)
+        } else if let Some(stamp) = rest.strip_suffix(".db") {
+            (stamp, false)
+        } else {
+            continue;
+        };
+
+        let created_at = chrono::NaiveDateTime::parse_from_str(stamp, "%Y%m%d_%H%M%S")
+            .ok()
+            .map(|dt| dt.format("%Y-%m-%dT%H:%M:%S").to_string());
+        let size_bytes = entry.metadata().map(|m| m.len()).unwrap_or(0);
+
+        backups.push(DatabaseBackup {
+            name,
+            size_bytes,
+            compressed,
+            created_at,
+        });
+    }
+
+    // Newest first; the timestamp format sorts lexically
+    backups.sort_by(|a, b| b.name.cmp(&a.name));
+
+    Ok(backups)
+}
+
+const DEFAULT_DATABASE_BACKUP_RETENTION: usize = 5;
+
+// Deletes all but the newest databaseBackupRetention backups, returning the
+// names removed. Failures are only logged; a stale backup is harmless.
+fn prune_database_backups(app: &tauri::AppHandle) -> Vec<String> {
+    let keep = get_settings(app.clone())
+        .ok()
+        .and_then(|settings| settings.database_backup_retention)
+        .unwrap_or(DEFAULT_DATABASE_BACKUP_RETENTION)
+        .max(1);
+
+    let backups = match list_database_backups(app.clone()) {
+        Ok(backups) => backups,
+        Err(e) => {
+            log::warn!("Failed to list database backups for cleanup: {}", e);
+            return Vec::new();
+        }
+    };
+    let Ok(data_dir) = app_data_dir(app).map(|dir| dir.join("data")) else {
+        return Vec::new();
+    };
+
+    let mut removed = Vec::new();
+    for backup in backups.into_iter().skip(keep) {
+        match fs::remove_file(data_dir.join(&backup.name)) {
+            Ok(()) => {
+                log::info!("Removed old database backup: {}", backup.name);
+                removed.push(backup.name);
+            }
+            Err(e) => log::warn!("Failed to remove old database backup {}: {}", backup.name, e),
+        }
+    }
+    removed
+}
+
+#[tauri::command]
+fn restore_database_backup(app: tauri::AppHandle, backup_name: String) -> Result<String, String> {
+    // Only accept bare backup file names, never paths
+    if !backup_name.starts_with(DB_BACKUP_PREFIX) || backup_name.contains(['/', '\\']) {
+        return Err(format!("Invalid backup name: {}", backup_name));
+    }
+
+    {
+        let state = app.state::<AppState>();
+        let bot = state.bot.lock().unwrap();
+        // A --start-bot bot or one from an earlier session is only in bot.pid
+        if bot.process.is_some() || running_bot_pid(&app).is_some() {
+            return Err("Stop the bot before restoring a database backup".to_string());
+        }
+    }
+
+    let app_dir = app_data_dir(&app)?;
+
+    let data_dir = app_dir.join("data");
+    let backup_path = data_dir.join(&backup_name);
+    if !backup_path.exists() {
+        return Err(format!("Backup not found: {}", backup_name));
+    }
+
+    let dest_path = data_dir.join("mythic_runs.db");
+    let staging_path = data_dir.join("mythic_runs.db.restore");
+
+    // Stage the restored file first so a failed decompress leaves the live DB alone
+    let staged = if backup_name.ends_with(".gz") {
+        decompress_file(&backup_path, &staging_path)
+    } else {
+        fs::copy(&backup_path, &staging_path)
+    };
+    if let Err(e) = staged {
+        let _ = fs::remove_file(&staging_path);
+        return Err(format!("Failed to read backup: {}", e));
+    }
+
+    let validated = Connection::open(&staging_path)
+        .map_err(|e| format!("Backup is not a valid SQLite database: {}", e))
+        .and_then(|conn| validate_database_tables(&conn));
+    if let Err(e) = validated {
+        let _ = fs::remove_file(&staging_path);
+        return Err(e);
+    }
+
+    // The database being replaced becomes a backup of its own
+    let previous = if dest_path.exists() {
+        match backup_live_database(&dest_path) {
+            Ok(name) => Some(name),
+            Err(e) => {
+                let _ = fs::remove_file(&staging_path);
+                return Err(e);
+            }
+        }
+    } else {
+        None
+    };
+
+    // A leftover WAL would be replayed on top of the restored database
+    let _ = fs::remove_file(data_dir.join("mythic_runs.db-wal"));
+    let _ = fs::remove_file(data_dir.join("mythic_runs.db-shm"));
+    fs::rename(&staging_path, &dest_path)
+        .map_err(|e| format!("Failed to restore database: {}", e))?;
+
+    log::info!("Restored database from backup: {:?}", backup_path);
+
+    // Backups may predate the current schema
+    if let Err(e) = migrate_database(app.clone()) {
+        log::warn!("Failed to migrate restored database: {}", e);
+    }
+
+    let mut result = format!("Database restored from {}", backup_name);
+    if let Some(previous) = previous {
+        result.push_str(&format!("\n\nThe previous database was saved as {}", previous));
+    }
+    let pruned = prune_database_backups(&app);
+    if !pruned.is_empty() {
+        result.push_str(&format!("\n\nRemoved old backups: {}", pruned.join(", ")));
+    }
+    Ok(result)
+}
+
+// Top-level AppData files carried by export_backup; logs, the WAL and other
+// runtime files are left out
+const BACKUP_FILES: &[&str] = &["config.json", "settings.json", ".env", "presence.json"];
+const BACKUP_DATABASE: &str = "data/mythic_runs.db";
+const BACKUP_COMMANDS_DIR: &str = "commands";
+const BACKUP_MANIFEST: &str = "backup-manifest.json";
+
+#[derive(Serialize, Deserialize)]
+struct BackupManifest {
+    #[serde(rename = "appVersion")]
+    app_version: String,
+    #[serde(rename = "createdAt")]
+    created_at: String,
+    entries: Vec<String>,
+}
+
+// Archive entry name -> file on disk, for everything under commands/
+fn collect_backup_commands(dir: &Path, prefix: &str, files: &mut Vec<(String, PathBuf)>) -> Result<(), String> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = format!("{}/{}", prefix, entry.file_name().to_string_lossy());
+        if path.is_dir() {
+            collect_backup_commands(&path, &name, files)?;
+        } else if !name.ends_with(".log") {
+            files.push((name, path));
+        }
+    }
+    Ok(())
+}
+
+// Zips the backed-up parts of `app_dir` into `dest_path`, returning the entry count
+fn write_backup_archive(app: &tauri::AppHandle, app_dir: &Path, dest_path: &Path) -> Result<usize, String> {
+    let mut files: Vec<(String, PathBuf)> = BACKUP_FILES
+        .iter()
+        .map(|name| (name.to_string(), app_dir.join(name)))
+        .filter(|(_, path)| path.exists())
+        .collect();
+
+    // Fold the WAL into the main file so the -wal/-shm files can be skipped,
+    // then hold a read transaction until the archive is written so the bot's
+    // new writes stay in the WAL instead of being checkpointed mid-copy
+    let db_path = app_dir.join("data").join("mythic_runs.db");
+    let _db_lock = if db_path.exists() {
+        let conn = Connection::open(&db_path)
+            .map_err(|e| format!("Failed to open database: {}", e))?;
+        conn.busy_timeout(std::time::Duration::from_secs(5))
+            .map_err(|e| format!("Failed to set busy timeout: {}", e))?;
+        let busy: i64 = conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| row.get(0))
+            .map_err(|e| format!("Failed to checkpoint database: {}", e))?;
+        // A blocked checkpoint leaves runs in the WAL that the archive would miss
+        if busy != 0 {
+            return Err("The database is busy (the bot may be syncing); try the backup again in a moment".to_string());
+        }
+        conn.execute_batch("BEGIN")
+            .and_then(|_| conn.query_row("SELECT COUNT(*) FROM sqlite_master", [], |_| Ok(())))
+            .map_err(|e| format!("Failed to lock database for backup: {}", e))?;
+        files.push((BACKUP_DATABASE.to_string(), db_path));
+        Some(conn)
+    } else {
+        None
+    };
+
+    let commands_dir = app_dir.join(BACKUP_COMMANDS_DIR);
+    if commands_dir.is_dir() {
+        collect_backup_commands(&commands_dir, BACKUP_COMMANDS_DIR, &mut files)?;
+    }
+
+    if files.is_empty() {
+        return Err("Nothing to back up yet".to_string());
+    }
+
+    let manifest = BackupManifest {
+        app_version: app.package_info().version.to_string(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+        entries: files.iter().map(|(name, _)| name.clone()).collect(),
+    };
+
+    let output = fs::File::create(dest_path)
+        .map_err(|e| format!("Failed to create backup file: {}", e))?;
+    let mut zip = zip::ZipWriter::new(output);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+
+    zip.start_file(BACKUP_MANIFEST, options)
+        .map_err(|e| format!("Failed to write backup manifest: {}", e))?;
+    let manifest_json = serde_json::to_vec_pretty(&manifest)
+        .map_err(|e| format!("Failed to serialize backup manifest: {}", e))?;
+    zip.write_all(&manifest_json)
+        .map_err(|e| format!("Failed to write backup manifest: {}", e))?;
+
+    for (name, path) in &files {
+        zip.start_file(name.as_str(), options)
+            .map_err(|e| format!("Failed to add {} to backup: {}", name, e))?;
+        let mut input = fs::File::open(path)
+            .map_err(|e| format!("Failed to read {}: {}", name, e))?;
+        std::io::copy(&mut input, &mut zip)
+            .map_err(|e| format!("Failed to add {} to backup: {}", name, e))?;
+    }
+
+    let file = zip.finish()
+        .map_err(|e| format!("Failed to finish backup file: {}", e))?;
+    file.sync_all()
+        .map_err(|e| format!("Failed to flush backup file: {}", e))?;
+
+    Ok(files.len())
+}
+
+// Only names export_backup itself writes may come out of an archive
+fn is_backup_entry(name: &str) -> bool {
+    name == BACKUP_MANIFEST
+        || name == BACKUP_DATABASE
+        || BACKUP_FILES.contains(&name)
+        || name.starts_with(&format!("{}/", BACKUP_COMMANDS_DIR))
+}
+
+// One archive with config, settings, .env, the database and commands, for
+// moving DaeBot to another machine
+#[tauri::command]
+fn export_backup(app: tauri::AppHandle, dest_path: PathBuf) -> Result<String, String> {
+    let app_dir = app_data_dir(&app)?;
+
+    match write_backup_archive(&app, &app_dir, &dest_path) {
+        Ok(count) => {
+            log::info!("Exported backup ({} files) to: {:?}", count, dest_path);
+            Ok(format!("Backup exported to {} ({} files)", dest_path.display(), count))
+        }
+        Err(e) => {
+            let _ = fs::remove_file(&dest_path);
+            Err(e)
+        }
+    }
+}
+
+// Installs an export_backup archive. Everything is extracted and checked in a
+// staging folder first, and the current files are zipped up before any are replaced.
+#[tauri::command]
+fn import_backup(app: tauri::AppHandle, src_path: PathBuf) -> Result<String, String> {
+    {
+        let state = app.state::<AppState>();
+        let bot = state.bot.lock().unwrap();
+        // A --start-bot bot or one from an earlier session is only in bot.pid
+        if bot.process.is_some() || running_bot_pid(&app).is_some() {
+            return Err("Stop the bot before importing a backup".to_string());
+        }
+    }
+
+    let input = fs::File::open(&src_path)
+        .map_err(|e| format!("Failed to open backup file: {}", e))?;
+    let mut archive = zip::ZipArchive::new(input)
+        .map_err(|e| format!("Not a valid backup file: {}", e))?;
+
+    let manifest: BackupManifest = {
+        let entry = archive.by_name(BACKUP_MANIFEST)
+            .map_err(|_| "Not a DaeBot backup (no backup manifest)".to_string())?;
+        serde_json::from_reader(entry)
+            .map_err(|e| format!("Invalid backup manifest: {}", e))?
+    };
+
+    let app_dir = app_data_dir(&app)?;
+    let staging_dir = app_dir.join("backup-import");
+    if staging_dir.exists() {
+        fs::remove_dir_all(&staging_dir)
+            .map_err(|e| format!("Failed to clear old import folder: {}", e))?;
+    }
+
+    // Returns the staged entries and whether the backed up token can't be decrypted here
+    let stage = |archive: &mut zip::ZipArchive<fs::File>| -> Result<(Vec<String>, bool), String> {
+        let mut staged = Vec::new();
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i)
+                .map_err(|e| format!("Failed to read backup: {}", e))?;
+            if entry.is_dir() || entry.name() == BACKUP_MANIFEST {
+                continue;
+            }
+
+            let name = entry.name().to_string();
+            let relative = entry.enclosed_name()
+                .filter(|_| is_backup_entry(&name))
+                .ok_or(format!("Backup contains an unexpected file: {}", name))?;
+
+            let dest = staging_dir.join(relative);
+            if let Some(parent) = dest.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create import folder: {}", e))?;
+            }
+            let mut output = fs::File::create(&dest)
+                .map_err(|e| format!("Failed to extract {}: {}", name, e))?;
+            std::io::copy(&mut entry, &mut output)
+                .map_err(|e| format!("Failed to extract {}: {}", name, e))?;
+            staged.push(name);
+        }
+
+        if let Some(missing) = manifest.entries.iter().find(|name| !staged.contains(name)) {
+            return Err(format!("Backup is incomplete; {} is missing", missing));
+        }
+
+        let config_path = staging_dir.join("config.json");
+        let mut token_lost = false;
+        if config_path.exists() {
+            let config = fs::read_to_string(&config_path)
+                .map_err(|e| format!("Failed to read backed up config.json: {}", e))?;
+            let config = serde_json::from_str::<serde_json::Value>(&config)
+                .map_err(|e| format!("Backed up config.json is not valid JSON: {}", e))?;
+            // DPAPI ties an encrypted token to the Windows user that saved it
+            token_lost = config.get("token")
+                .and_then(|token| token.as_str())
+                .is_some_and(|token| decrypt_token(token).is_none());
+        }
+
+        let db_path = staging_dir.join(BACKUP_DATABASE);
+        if db_path.exists() {
+            let conn = Connection::open(&db_path)
+                .map_err(|e| format!("Backed up database is not a valid SQLite file: {}", e))?;
+            if !table_exists(&conn, "mythic_runs")? {
+                return Err("Backed up database has no mythic_runs table".to_string());
+            }
+        }
+
+        Ok((staged, token_lost))
+    };
+
+    let (staged, token_lost) = match stage(&mut archive) {
+        Ok(staged) => staged,
+        Err(e) => {
+            let _ = fs::remove_dir_all(&staging_dir);
+            return Err(e);
+        }
+    };
+
+    // Keep what's being replaced, in the same format, next to the database backups
+    let data_dir = app_dir.join("data");
+    fs::create_dir_all(&data_dir)
+        .map_err(|e| format!("Failed to create data directory: {}", e))?;
+    let safety_path = data_dir.join(format!(
+        "daebot_backup_before_import_{}.zip",
+        chrono::Local::now().format("%Y%m%d_%H%M%S")
+    ));
+    match write_backup_archive(&app, &app_dir, &safety_path) {
+        Ok(_) => log::info!("Backed up current files to: {:?}", safety_path),
+        // A fresh install has nothing to lose
+        Err(e) if e == "Nothing to back up yet" => {
+            let _ = fs::remove_file(&safety_path);
+        }
+        Err(e) => {
+            let _ = fs::remove_file(&safety_path);
+            let _ = fs::remove_dir_all(&staging_dir);
+            return Err(format!("Failed to back up current files: {}", e));
+        }
+    }
+
+    let install = || -> Result<(), String> {
+        for name in BACKUP_FILES.iter().chain(std::iter::once(&BACKUP_DATABASE)) {
+            let staged_path = staging_dir.join(name);
+            if !staged_path.exists() {
+                continue;
+            }
+            let dest = app_dir.join(name);
+            if *name == BACKUP_DATABASE {
+                // A leftover WAL would be replayed on top of the imported database
+                let _ = fs::remove_file(app_dir.join("data").join("mythic_runs.db-wal"));
+                let _ = fs::remove_file(app_dir.join("data").join("mythic_runs.db-shm"));
+            }
+            fs::rename(&staged_path, &dest)
+                .map_err(|e| format!("Failed to install {}: {}", name, e))?;
+        }
+
+        let staged_commands = staging_dir.join(BACKUP_COMMANDS_DIR);
+        if staged_commands.is_dir() {
+            let commands_dir = app_dir.join(BACKUP_COMMANDS_DIR);
+            if commands_dir.exists() {
+                fs::remove_dir_all(&commands_dir)
+                    .map_err(|e| format!("Failed to replace commands folder: {}", e))?;
+            }
+            fs::rename(&staged_commands, &commands_dir)
+                .map_err(|e| format!("Failed to install commands folder: {}", e))?;
+        }
+        Ok(())
+    };
+
+    let installed = install();
+    let _ = fs::remove_dir_all(&staging_dir);
+    installed.map_err(|e| format!("{} (previous files are in {})", e, safety_path.display()))?;
+
+    // Backups can come from much older versions of the bot
+    if let Err(e) = migrate_database(app.clone()) {
+        log::warn!("Failed to migrate imported database: {}", e);
+    }
+
+    log::info!("Imported backup from {:?} ({} files)", src_path, staged.len());
+    let mut result = format!(
+        "Backup from DaeBot {} ({}) imported: {} files restored. Restart DaeBot to load the imported settings.",
+        manifest.app_version,
+        manifest.created_at,
+        staged.len()
+    );
+    if token_lost {
+        log::warn!("Imported bot token was encrypted for another Windows user or machine");
+        result.push_str(
+            "\n\nThe bot token in this backup was encrypted on another machine or Windows account and can't be read here. Enter it again in Settings."
+        );
+    }
+    Ok(result)
+}
+
+// Credentials seen in config.json / .env, masked verbatim by redact_secrets
+static KNOWN_SECRETS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+const REDACTED: &str = "[REDACTED]";
+
+// Record a credential so it is masked wherever it shows up in logs or errors
+fn remember_secret(secret: &str) {
+    let secret = secret.trim();
+    // Very short values would mask ordinary words
+    if secret.len() < 8 {
+        return;
+    }
+
+    let mut secrets = KNOWN_SECRETS.lock().unwrap();
+    if !secrets.iter().any(|s| s == secret) {
+        secrets.push(secret.to_string());
+    }
+}
+
+// Discord bot tokens are three base64url segments: id.timestamp.hmac
+fn looks_like_discord_token(word: &str) -> bool {
+    let parts: Vec<&str> = word.split('.').collect();
+    let [id, timestamp, hmac] = parts.as_slice() else {
+        return false;
+    };
+
+    let base64url = |part: &str| part.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    id.len() >= 18 && timestamp.len() >= 5 && hmac.len() >= 25
+        && base64url(id) && base64url(timestamp) && base64url(hmac)
+}
+
+// Mask known credentials and anything shaped like a Discord token before text
+// is logged or returned to the UI, so pasted logs don't leak them
+fn redact_secrets(text: &str) -> String {
+    let mut redacted = text.to_string();
+    for secret in KNOWN_SECRETS.lock().unwrap().iter() {
+        redacted = redacted.replace(secret.as_str(), REDACTED);
+    }
+
+    let is_token_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.';
+    let tokens: Vec<String> = redacted.split(|c: char| !is_token_char(c))
+        .filter(|word| looks_like_discord_token(word))
+        .map(str::to_string)
+        .collect();
+
+    for token in tokens {
+        redacted = redacted.replace(&token, REDACTED);
+    }
+    redacted
+}
+
+// Helper function to resolve the updater log location
+fn updater_log_path() -> PathBuf {
+    // AppData/Roaming/com.daebot.app/updater.log
+    if let Some(appdata) = std::env::var_os("APPDATA") {
+        PathBuf::from(appdata).join("com.daebot.app").join("updater.log")
+    } else {
+        PathBuf::from("updater.log")
+    }
+}
+
+// Helper function to log updater messages to a file
+fn log_updater(message: &str) {
+    let message = redact_secrets(message);
+    let log_path = updater_log_path();
+
+    // Ensure directory exists
+    if let Some(parent) = log_path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    if let Ok(mut file) = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log_path)
+    {
+        let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+        let _ = writeln!(file, "[{}] {}", timestamp, message);
+        let _ = file.flush();
+    }
+
+    // Also print to console
+    log::info!("{}", message);
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct UpdateHistoryEntry {
+    #[serde(rename = "fromVersion")]
+    from_version: String,
+    #[serde(rename = "toVersion")]
+    to_version: String,
+    timestamp: String,
+    channel: String,
+}
+
+// Helper function to open the database and make sure update_history exists
+fn open_update_history_db(app: &tauri::AppHandle) -> Result<Connection, String> {
+    let app_dir = app_data_dir(app)?;
+
+    let data_dir = app_dir.join("data");
+    fs::create_dir_all(&data_dir)
+        .map_err(|e| format!("Failed to create data directory: {}", e))?;
+
+    let conn = Connection::open(data_dir.join("mythic_runs.db"))
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+
+    // Enable WAL mode to match the Node.js bot
+    conn.pragma_update(None, "journal_mode", "WAL")
+        .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
+
+    // The app owns this table, the Node.js bot never touches it
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS update_history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            from_version TEXT NOT NULL,
+            to_version TEXT NOT NULL,
+            timestamp INTEGER NOT NULL,
+            channel TEXT NOT NULL
+        )",
+        [],
+    ).map_err(|e| format!("Failed to create update_history table: {}", e))?;
+
+    Ok(conn)
+}
+
+// Helper function to record an update installation; returns the row id so a
+// failed install can take it back out
+fn record_update_history(app: &tauri::AppHandle, from_version: &str, to_version: &str, channel: &str) -> Result<i64, String> {
+    let conn = open_update_history_db(app)?;
+
+    conn.execute(
+        "INSERT INTO update_history (from_version, to_version, timestamp, channel) VALUES (?1, ?2, ?3, ?4)",
+        (from_version, to_version, chrono::Utc::now().timestamp_millis(), channel),
+    ).map_err(|e| format!("Failed to insert update history: {}", e))?;
+
+    log_updater(&format!("[UPDATER] Recorded update {} -> {} ({})", from_version, to_version, channel));
+    Ok(conn.last_insert_rowid())
+}
+
+fn remove_update_history(app: &tauri::AppHandle, id: i64) -> Result<(), String> {
+    let conn = open_update_history_db(app)?;
+    conn.execute("DELETE FROM update_history WHERE id = ?1", [id])
+        .map_err(|e| format!("Failed to remove update history entry: {}", e))?;
+    Ok(())
+}
+
+#[tauri::command]
+fn get_update_history(app: tauri::AppHandle) -> Result<Vec<UpdateHistoryEntry>, String> {
+    let conn = open_update_history_db(&app)?;
+
+    let mut stmt = conn.prepare(
+        "SELECT from_version, to_version, timestamp, channel FROM update_history ORDER BY timestamp DESC"
+    ).map_err(|e| format!("Failed to prepare query: {}", e))?;
+
+    let history_iter = stmt.query_map([], |row| {
+        // Convert INTEGER timestamp (milliseconds) to ISO 8601 string
+        let timestamp_ms: i64 = row.get(2)?;
+        let dt = DateTime::from_timestamp_millis(timestamp_ms).unwrap_or_default();
+
+        Ok(UpdateHistoryEntry {
+            from_version: row.get(0)?,
+            to_version: row.get(1)?,
+            timestamp: dt.to_rfc3339(),
+            channel: row.get(3)?,
+        })
+    }).map_err(|e| format!("Failed to query update history: {}", e))?;
+
+    let mut history = Vec::new();
+    for entry in history_iter {
+        history.push(entry.map_err(|e| format!("Failed to read update history entry: {}", e))?);
+    }
+
+    Ok(history)
+}
+
+#[derive(Clone, Serialize)]
+struct UpdateDownloadProgress {
+    downloaded: u64,
+    total: Option<u64>,
+}
+
+#[derive(Clone, Serialize)]
+struct UpdateDownloaded {
+    version: String,
+    // False when autoRestartAfterUpdate is on and the app is about to install and restart itself
+    #[serde(rename = "restartPending")]
+    restart_pending: bool,
+}
+
+// Background half of install_update: downloads only, so the app keeps running
+// until the user (or autoRestartAfterUpdate) confirms the install
+async fn download_update(app: tauri::AppHandle, update: tauri_plugin_updater::Update, beta_channel: bool) {
+    let downloaded = AtomicU64::new(0);
+    let result = update.download(|chunk_length, content_length| {
+        let so_far = downloaded.fetch_add(chunk_length as u64, Ordering::SeqCst) + chunk_length as u64;
+        let _ = app.emit("update-download-progress", UpdateDownloadProgress {
+            downloaded: so_far,
+            total: content_length,
+        });
+    }, || {
+        log_updater("[UPDATER] Download finished");
+    }).await;
+
+    let state = app.state::<AppState>();
+    state.update_in_progress.store(false, Ordering::SeqCst);
+
+    match result {
+        Ok(bytes) => {
+            let version = update.version.clone();
+            *state.pending_update.lock().unwrap() = Some(PendingUpdate {
+                update,
+                bytes,
+                channel: if beta_channel { "beta" } else { "stable" },
+            });
+
+            let auto_restart = get_settings(app.clone())
+                .map(|settings| settings.auto_restart_after_update)
+                .unwrap_or(false);
+
+            let _ = app.emit("update-downloaded", UpdateDownloaded {
+                version,
+                restart_pending: !auto_restart,
+            });
+
+            if auto_restart {
+                log_updater("[UPDATER] Update downloaded, installing and restarting...");
+                if let Err(e) = install_pending_update(&app) {
+                    log_updater(&e);
+                    let _ = app.emit("update-failed", e);
+                }
+                return;
+            }
+            log_updater("[UPDATER] Update downloaded, waiting for restart confirmation");
+        }
+        Err(e) => {
+            let error_msg = format!("[UPDATER ERROR] Failed to download update: {:?}", e);
+            log_updater(&error_msg);
+            let _ = app.emit("update-failed", error_msg);
+        }
+    }
+}
+
+// Runs the installer for the downloaded update and restarts into it. On
+// Windows the installer exits the app itself, so nothing after install() runs.
+fn install_pending_update(app: &tauri::AppHandle) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let pending = state.pending_update.lock().unwrap().take()
+        .ok_or("No downloaded update is waiting for a restart")?;
+
+    // Record first: on Windows install() never returns
+    let from_version = app.package_info().version.to_string();
+    let history_id = record_update_history(app, &from_version, &pending.update.version, pending.channel)
+        .inspect_err(|e| log_updater(&format!("[UPDATER] Failed to record update history: {}", e)))
+        .ok();
+
+    if let Err(e) = pending.update.install(&pending.bytes) {
+        if let Some(id) = history_id {
+            if let Err(e) = remove_update_history(app, id) {
+                log_updater(&format!("[UPDATER] Failed to remove update history entry: {}", e));
+            }
+        }
+        return Err(format!("[UPDATER ERROR] Failed to install update: {:?}", e));
+    }
+
+    log_updater("[UPDATER] Update installed successfully, restarting...");
+    app.restart();
+}
+
+// Installs the update install_update downloaded and restarts into it
+#[tauri::command]
+fn confirm_restart(app: tauri::AppHandle) -> Result<(), String> {
+    log_updater("[UPDATER] Restart confirmed, installing update...");
+    install_pending_update(&app)
+}
+
+// Checks for an update and starts downloading it in the background, returning
+// right away; progress and the result arrive as events
+#[tauri::command]
+async fn install_update(app: tauri::AppHandle) -> Result<String, String> {
+    log_updater("[UPDATER] Starting update installation...");
+
+    // Get bot settings to check beta channel preference (same as check_for_updates)
+    let settings = match get_bot_settings(app.clone()) {
+        Ok(s) => s,
+        Err(e) => {
+            log_updater(&format!("[UPDATER] Failed to get bot settings: {}, defaulting to stable channel", e));
+            BotSettings {
+                season_id: 0,
+                season_name: String::new(),
+                default_region: String::new(),
+                default_realm: String::new(),
+                active_dungeons: Vec::new(),
+                beta_channel: false,
+                token_region: default_token_region(),
+                report_schedule: None,
+                token_alert_threshold: None,
+                token_alert_direction: default_token_alert_direction(),
+                updated_at: None,
+            }
+        }
+    };
+
+    // Use different update endpoint based on beta channel setting
+    let update_endpoint = update_endpoint(settings.beta_channel);
+    log_updater(&format!("[UPDATER] Using update endpoint: {}", update_endpoint));
+
+    // Parse the endpoint URL
+    let update_url = match Url::parse(update_endpoint) {
+        Ok(url) => url,
+        Err(e) => {
+            return Err(format!("[UPDATER ERROR] Invalid update URL: {}", e));
+        }
+    };
+
+    // Build updater with the correct endpoint
+    let updater_builder = app.updater_builder()
+        .endpoints(vec![update_url])
+        .map_err(|e| format!("[UPDATER ERROR] Failed to set endpoints: {}", e))?;
+
+    match updater_builder.build() {
+        Ok(updater) => {
+            log_updater("[UPDATER] Updater builder created successfully");
+
+            match updater.check().await {
+                Ok(update_result) => {
+                    if let Some(update) = update_result {
+                        log_updater(&format!("[UPDATER] Update found: version {}", update.version));
+                        log_updater(&format!("[UPDATER] Download URL: {}", update.download_url));
+
+                        let state = app.state::<AppState>();
+                        if state.update_in_progress.swap(true, Ordering::SeqCst) {
+                            return Err("[UPDATER] An update is already being installed".to_string());
+                        }
+
+                        // Download in the background; the UI follows along through
+                        // update-download-progress / update-downloaded / update-failed
+                        let version = update.version.clone();
+                        let app_handle = app.clone();
+                        tauri::async_runtime::spawn(async move {
+                            download_update(app_handle, update, settings.beta_channel).await;
+                        });
+
+                        Ok(format!("Downloading update v{}", version))
+                    } else {
+                        let msg = "[UPDATER] No updates available";
+                        log_updater(msg);
+                        Err(msg.to_string())
+                    }
+                }
+                Err(e) => {
+                    let error_msg = format!("[UPDATER ERROR] Error checking for updates: {:?}", e);
+                    log_updater(&error_msg);
+                    Err(error_msg)
+                }
+            }
+        }
+        Err(e) => {
+            let error_msg = format!("[UPDATER ERROR] Error building updater: {:?}", e);
+            log_updater(&error_msg);
+            Err(error_msg)
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct LogEntry {
+    timestamp: String,
+    level: String,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct Stats {
+    #[serde(rename = "totalRuns")]
+    total_runs: i64,
+    #[serde(rename = "totalCharacters")]
+    total_characters: i64,
+    #[serde(rename = "lastSync")]
+    last_sync: Option<String>,
+    #[serde(rename = "databaseSize")]
+    database_size: u64,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct SyncHistoryEntry {
+    timestamp: String,
+    success: bool,
+    #[serde(rename = "syncType")]
+    sync_type: String,
+    #[serde(rename = "runsAdded", skip_serializing_if = "Option::is_none")]
+    runs_added: Option<i64>,
+    #[serde(rename = "charactersProcessed", skip_serializing_if = "Option::is_none")]
+    characters_processed: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CharacterRun {
+    // mythic_runs.id, for looking the run up with get_run_detail
+    id: i64,
+    dungeon: String,
+    #[serde(rename = "keyLevel")]
+    key_level: i64,
+    score: f64,
+    timed: bool,
+    #[serde(rename = "completedTimestamp")]
+    completed_timestamp: i64,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct RunDetail {
+    id: i64,
+    dungeon: String,
+    #[serde(rename = "keyLevel")]
+    key_level: i64,
+    score: f64,
+    timed: bool,
+    #[serde(rename = "keystoneUpgrades")]
+    keystone_upgrades: i64,
+    // Clear time in milliseconds
+    duration: i64,
+    #[serde(rename = "completedTimestamp")]
+    completed_timestamp: i64,
+    // Whatever the Raider.IO API returned, stored by the bot as JSON
+    affixes: serde_json::Value,
+    season: Option<String>,
+    #[serde(rename = "specName")]
+    spec_name: Option<String>,
+    #[serde(rename = "specRole")]
+    spec_role: Option<String>,
+    #[serde(rename = "characterName")]
+    character_name: String,
+    realm: String,
+    region: String,
+    class: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct TimelineBucket {
+    date: String,
+    #[serde(rename = "dayStart")]
+    day_start: i64,
+    #[serde(rename = "runCount")]
+    run_count: i64,
+    #[serde(rename = "bestKey")]
+    best_key: Option<i64>,
+}
+
+const MS_PER_DAY: i64 = 86_400_000;
+
+// Upper bound on how many day buckets get_runs_timeline will build
+const MAX_TIMELINE_DAYS: i64 = 3660;
+
+#[derive(Clone, Serialize, Deserialize)]
+struct ColumnInfo {
+    name: String,
+    #[serde(rename = "type")]
+    column_type: String,
+    #[serde(rename = "notNull")]
+    not_null: bool,
+    #[serde(rename = "primaryKey")]
+    primary_key: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct TableSchema {
+    name: String,
+    columns: Vec<ColumnInfo>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct BotSettings {
+    #[serde(rename = "seasonId")]
+    season_id: i64,
+    #[serde(rename = "seasonName")]
+    season_name: String,
+    #[serde(rename = "defaultRegion")]
+    default_region: String,
+    #[serde(rename = "defaultRealm")]
+    default_realm: String,
+    #[serde(rename = "activeDungeons")]
+    active_dungeons: Vec<String>,
+    #[serde(rename = "betaChannel")]
+    beta_channel: bool,
+    #[serde(rename = "tokenRegion", default = "default_token_region")]
+    token_region: String,
+    #[serde(rename = "reportSchedule", default)]
+    report_schedule: Option<String>,
+    #[serde(rename = "tokenAlertThreshold", default)]
+    token_alert_threshold: Option<i64>,
+    #[serde(rename = "tokenAlertDirection", default = "default_token_alert_direction")]
+    token_alert_direction: String,
+    #[serde(rename = "updatedAt", skip_serializing_if = "Option::is_none")]
+    updated_at: Option<i64>,
+}
+
+// Regions the WoW token price can be pulled for
+const TOKEN_REGIONS: [&str; 5] = ["us", "eu", "kr", "tw", "cn"];
+
+fn default_token_region() -> String {
+    "us".to_string()
+}
+
+// Token alerts fire when the price is at/above or at/below the threshold.
+// The range matches what /token set-threshold accepts in Discord.
+const TOKEN_ALERT_DIRECTIONS: [&str; 2] = ["above", "below"];
+const TOKEN_ALERT_THRESHOLD_MIN: i64 = 50_000;
+const TOKEN_ALERT_THRESHOLD_MAX: i64 = 1_000_000;
+
+fn default_token_alert_direction() -> String {
+    "above".to_string()
+}
+
+const WEEKDAYS: [&str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+
+// Report schedules are one of:
+//   "daily HH:MM"        - every day at a time of day (24h, server local time)
+//   "weekly <day> HH:MM" - one day a week, day as mon..sun
+//   "every <N>h"         - fixed interval of 1-168 hours
+fn validate_report_schedule(schedule: &str) -> Result<(), String> {
+    let malformed = || format!(
+        "Invalid report schedule '{}'. Use 'daily HH:MM', 'weekly <mon-sun> HH:MM' or 'every <N>h'",
+        schedule
+    );
+
+    let valid_time = |time: &str| chrono::NaiveTime::parse_from_str(time, "%H:%M").is_ok();
+
+    let parts: Vec<&str> = schedule.split_whitespace().collect();
+    let valid = match parts.as_slice() {
+        ["daily", time] => valid_time(time),
+        ["weekly", day, time] => WEEKDAYS.contains(day) && valid_time(time),
+        ["every", interval] => interval.strip_suffix('h')
+            .and_then(|hours| hours.parse::<u32>().ok())
+            .is_some_and(|hours| (1..=168).contains(&hours)),
+        _ => false,
+    };
+
+    if valid { Ok(()) } else { Err(malformed()) }
+}
+
+// Next run of a validated report schedule. Interval schedules count from the
+// last sync and are due immediately if there hasn't been one.
+fn next_scheduled_run(
+    schedule: &str,
+    now: DateTime<chrono::Local>,
+    last_sync: Option<DateTime<chrono::Local>>,
+) -> Option<DateTime<chrono::Local>> {
+    use chrono::{Datelike, Duration, NaiveTime, TimeZone};
+
+    // Days from today, skipping DST gaps where the local time doesn't exist
+    let at = |days_ahead: i64, time: NaiveTime| {
+        let date = now.date_naive() + Duration::days(days_ahead);
+        chrono::Local.from_local_datetime(&date.and_time(time)).earliest()
+    };
+
+    let parts: Vec<&str> = schedule.split_whitespace().collect();
+    match parts.as_slice() {
+        ["daily", time] => {
+            let time = NaiveTime::parse_from_str(time, "%H:%M").ok()?;
+            (0..=2).filter_map(|days| at(days, time)).find(|t| *t > now)
+        }
+        ["weekly", day, time] => {
+            let time = NaiveTime::parse_from_str(time, "%H:%M").ok()?;
+            let target = WEEKDAYS.iter().position(|d| d == day)? as i64;
+            let today = now.weekday().num_days_from_monday() as i64;
+            let days_ahead = (target - today).rem_euclid(7);
+            [days_ahead, days_ahead + 7].into_iter()
+                .filter_map(|days| at(days, time))
+                .find(|t| *t > now)
+        }
+        ["every", interval] => {
+            let hours = interval.strip_suffix('h')?.parse::<i64>().ok()?;
+            Some(last_sync.map_or(now, |last| last + Duration::hours(hours)))
+        }
+        _ => None,
+    }
+}
+
+// Helper function to add bot_settings columns newer than the bot's schema
+fn ensure_bot_settings_columns(conn: &Connection) -> Result<(), String> {
+    let has_token_region: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('bot_settings') WHERE name='token_region'",
+        [],
+        |row| row.get(0)
+    ).map_err(|e| format!("Failed to check bot_settings columns: {}", e))?;
+
+    if has_token_region == 0 {
+        log::info!("Adding token_region column to bot_settings...");
+        conn.execute("ALTER TABLE bot_settings ADD COLUMN token_region TEXT NOT NULL DEFAULT 'us'", [])
+            .map_err(|e| format!("Failed to add token_region column: {}", e))?;
+    }
+
+    let has_report_schedule: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('bot_settings') WHERE name='report_schedule'",
+        [],
+        |row| row.get(0)
+    ).map_err(|e| format!("Failed to check bot_settings columns: {}", e))?;
+
+    if has_report_schedule == 0 {
+        log::info!("Adding report_schedule column to bot_settings...");
+        conn.execute("ALTER TABLE bot_settings ADD COLUMN report_schedule TEXT", [])
+            .map_err(|e| format!("Failed to add report_schedule column: {}", e))?;
+    }
+
+    let has_token_alert: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('bot_settings') WHERE name='token_alert_threshold'",
+        [],
+        |row| row.get(0)
+    ).map_err(|e| format!("Failed to check bot_settings columns: {}", e))?;
+
+    if has_token_alert == 0 {
+        log::info!("Adding token alert columns to bot_settings...");
+        conn.execute("ALTER TABLE bot_settings ADD COLUMN token_alert_threshold INTEGER", [])
+            .map_err(|e| format!("Failed to add token_alert_threshold column: {}", e))?;
+        conn.execute("ALTER TABLE bot_settings ADD COLUMN token_alert_direction TEXT NOT NULL DEFAULT 'above'", [])
+            .map_err(|e| format!("Failed to add token_alert_direction column: {}", e))?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+fn get_available_seasons(app: tauri::AppHandle) -> Result<Vec<String>, String> {
+    let db_path = read_database_path(&app)?;
+
+    if !db_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let conn = open_read_database(&app, &db_path)?;
+
+    // Query distinct seasons ordered by most recent
+    let mut stmt = conn.prepare(
+        "SELECT DISTINCT season FROM mythic_runs WHERE season IS NOT NULL ORDER BY season DESC"
+    ).map_err(|e| format!("Failed to prepare query: {}", e))?;
+
+    let seasons_iter = stmt.query_map([], |row| {
+        row.get(0)
+    }).map_err(|e| format!("Failed to query seasons: {}", e))?;
+
+    let mut seasons = Vec::new();
+    for season in seasons_iter {
+        seasons.push(season.map_err(|e| format!("Failed to read season: {}", e))?);
+    }
+
+    Ok(seasons)
+}
+
+// Regions of characters that have at least one stored run
+#[tauri::command]
+fn get_regions(app: tauri::AppHandle) -> Result<Vec<String>, String> {
+    let db_path = read_database_path(&app)?;
+
+    if !db_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let conn = open_read_database(&app, &db_path)?;
+
+    let mut stmt = conn.prepare(
+        "SELECT DISTINCT c.region FROM characters c
+         WHERE EXISTS (SELECT 1 FROM mythic_runs r WHERE r.character_id = c.id)
+         ORDER BY c.region"
+    ).map_err(|e| format!("Failed to prepare query: {}", e))?;
+
+    let regions_iter = stmt.query_map([], |row| {
+        row.get(0)
+    }).map_err(|e| format!("Failed to query regions: {}", e))?;
+
+    let mut regions = Vec::new();
+    for region in regions_iter {
+        regions.push(region.map_err(|e| format!("Failed to read region: {}", e))?);
+    }
+
+    Ok(regions)
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct SeasonBreakdown {
+    season: String,
+    runs: i64,
+    characters: i64,
+}
+
+#[tauri::command]
+fn get_season_breakdown(app: tauri::AppHandle) -> Result<Vec<SeasonBreakdown>, String> {
+    let db_path = read_database_path(&app)?;
+
+    if !db_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let conn = open_read_database(&app, &db_path)?;
+
+    // Same seasons and order as get_available_seasons, counted in one pass
+    let mut stmt = conn.prepare(
+        "SELECT season, COUNT(*), COUNT(DISTINCT character_id)
+         FROM mythic_runs
+         WHERE season IS NOT NULL
+         GROUP BY season
+         ORDER BY season DESC"
+    ).map_err(|e| format!("Failed to prepare query: {}", e))?;
+
+    let breakdown_iter = stmt.query_map([], |row| {
+        Ok(SeasonBreakdown {
+            season: row.get(0)?,
+            runs: row.get(1)?,
+            characters: row.get(2)?,
+        })
+    }).map_err(|e| format!("Failed to query season breakdown: {}", e))?;
+
+    let mut breakdown = Vec::new();
+    for entry in breakdown_iter {
+        breakdown.push(entry.map_err(|e| format!("Failed to read season breakdown: {}", e))?);
+    }
+
+    Ok(breakdown)
+}
+
+#[tauri::command]
+fn get_bot_settings(app: tauri::AppHandle) -> Result<BotSettings, String> {
+    let app_dir = app_data_dir(&app)?;
+    let db_path = app_dir.join("data").join("mythic_runs.db");
+
+    if !db_path.exists() {
+        return Err("Database not found".to_string());
+    }
+
+    let conn = Connection::open(&db_path)
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+
+    // Enable WAL mode
+    conn.pragma_update(None, "journal_mode", "WAL")
+        .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
+
+    ensure_bot_settings_columns(&conn)?;
+
+    // Query bot settings
+    let settings = conn.query_row(
+        "SELECT current_season_id, current_season_name, default_region, default_realm, active_dungeons, beta_channel, updated_at, token_region, report_schedule,
+                token_alert_threshold, token_alert_direction
+         FROM bot_settings WHERE id = 1",
+        [],
+        |row| {
+            let dungeons_json: String = row.get(4)?;
+            let dungeons: Vec<String> = serde_json::from_str(&dungeons_json).unwrap_or_default();
+            let beta_channel_int: i64 = row.get(5)?;
+
+            Ok(BotSettings {
+                season_id: row.get(0)?,
+                season_name: row.get(1)?,
+                default_region: row.get(2)?,
+                default_realm: row.get(3)?,
+                active_dungeons: dungeons,
+                beta_channel: beta_channel_int != 0,
+                token_region: row.get(7)?,
+                report_schedule: row.get(8)?,
+                token_alert_threshold: row.get(9)?,
+                token_alert_direction: row.get(10)?,
+                updated_at: Some(row.get(6)?),
+            })
+        }
+    ).map_err(|e| format!("Failed to query bot settings: {}", e))?;
+
+    Ok(settings)
+}
+
+// Convert a SQLite value into JSON without assuming its declared type
+fn sqlite_value_to_json(value: rusqlite::types::ValueRef) -> serde_json::Value {
+    use rusqlite::types::ValueRef;
+
+    match value {
+        ValueRef::Null => serde_json::Value::Null,
+        ValueRef::Integer(i) => serde_json::Value::from(i),
+        ValueRef::Real(f) => serde_json::Value::from(f),
+        ValueRef::Text(t) => serde_json::Value::from(String::from_utf8_lossy(t).to_string()),
+        ValueRef::Blob(b) => serde_json::Value::from(format!("<blob {} bytes>", b.len())),
+    }
+}
+
+// Schema-tolerant view of the bot_settings row for diagnosing mismatches;
+// returns whatever columns exist instead of failing on a missing one
+#[tauri::command]
+fn get_bot_settings_raw(app: tauri::AppHandle) -> Result<serde_json::Map<String, serde_json::Value>, String> {
+    let app_dir = app_data_dir(&app)?;
+    let db_path = app_dir.join("data").join("mythic_runs.db");
+
+    if !db_path.exists() {
+        return Err("Database not found".to_string());
+    }
+
+    let conn = Connection::open(&db_path)
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+
+    // Enable WAL mode to read from the WAL file
+    conn.pragma_update(None, "journal_mode", "WAL")
+        .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
+
+    let mut stmt = conn.prepare("SELECT * FROM bot_settings WHERE id = 1")
+        .map_err(|e| format!("Failed to prepare query: {}", e))?;
+    let column_names: Vec<String> = stmt.column_names().iter().map(|c| c.to_string()).collect();
+
+    let mut rows = stmt.query([])
+        .map_err(|e| format!("Failed to query bot settings: {}", e))?;
+    let row = rows.next()
+        .map_err(|e| format!("Failed to read bot settings: {}", e))?
+        .ok_or("No bot settings row found")?;
+
+    let mut settings = serde_json::Map::new();
+    for (i, name) in column_names.into_iter().enumerate() {
+        let value = row.get_ref(i)
+            .map_err(|e| format!("Failed to read column {}: {}", name, e))?;
+        settings.insert(name, sqlite_value_to_json(value));
+    }
+
+    Ok(settings)
+}
+
+// Lowercase and collapse whitespace so "Weekly  Tue 09:00" is stored canonically
+fn normalize_report_schedule(schedule: &str) -> String {
+    schedule.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+// Helper function to validate bot settings before they are written
+fn validate_bot_settings(settings: &BotSettings) -> Result<(), String> {
+    // Validate season name format
+    if !settings.season_name.starts_with("season-") {
+        return Err("Season name must start with 'season-' (e.g., season-mid-1)".to_string());
+    }
+
+    // Validate token region
+    if !TOKEN_REGIONS.contains(&settings.token_region.to_lowercase().as_str()) {
+        return Err(format!("Token region must be one of: {}", TOKEN_REGIONS.join(", ")));
+    }
+
+    // Validate report schedule (absent means reports are not scheduled)
+    if let Some(schedule) = &settings.report_schedule {
+        let schedule = normalize_report_schedule(schedule);
+        if !schedule.is_empty() {
+            validate_report_schedule(&schedule)?;
+        }
+    }
+
+    // Validate token alert (no threshold means the Discord-configured one is used)
+    if !TOKEN_ALERT_DIRECTIONS.contains(&settings.token_alert_direction.to_lowercase().as_str()) {
+        return Err(format!("Token alert direction must be one of: {}", TOKEN_ALERT_DIRECTIONS.join(", ")));
+    }
+    if let Some(threshold) = settings.token_alert_threshold {
+        if !(TOKEN_ALERT_THRESHOLD_MIN..=TOKEN_ALERT_THRESHOLD_MAX).contains(&threshold) {
+            return Err(format!(
+                "Token alert threshold must be between {} and {} gold",
+                TOKEN_ALERT_THRESHOLD_MIN, TOKEN_ALERT_THRESHOLD_MAX
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct NextSyncEstimate {
+    schedule: String,
+    #[serde(rename = "nextSync")]
+    next_sync: String,
+    // Zero when the run is already due or overdue
+    #[serde(rename = "secondsRemaining")]
+    seconds_remaining: i64,
+    #[serde(rename = "lastSync")]
+    last_sync: Option<String>,
+}
+
+// Estimate for the dashboard countdown, from BotSettings.reportSchedule and
+// the newest sync_history entry. None when no schedule is configured.
+#[tauri::command]
+fn get_next_sync_estimate(app: tauri::AppHandle) -> Result<Option<NextSyncEstimate>, String> {
+    let schedule = match get_bot_settings(app.clone())?.report_schedule {
+        Some(schedule) if !schedule.trim().is_empty() => schedule,
+        _ => return Ok(None),
+    };
+
+    let db_path = read_database_path(&app)?;
+    let conn = open_read_database(&app, &db_path)?;
+
+    let last_sync_ms: Option<i64> = if table_exists(&conn, "sync_history")? {
+        conn.query_row("SELECT MAX(timestamp) FROM sync_history", [], |row| row.get(0))
+            .map_err(|e| format!("Failed to read last sync time: {}", e))?
+    } else {
+        None
+    };
+    let last_sync = last_sync_ms
+        .and_then(DateTime::from_timestamp_millis)
+        .map(|dt| dt.with_timezone(&chrono::Local));
+
+    let now = chrono::Local::now();
+    let next = next_scheduled_run(&schedule, now, last_sync)
+        .ok_or_else(|| format!("Could not compute the next run for schedule '{}'", schedule))?;
+
+    Ok(Some(NextSyncEstimate {
+        schedule,
+        next_sync: next.to_rfc3339(),
+        seconds_remaining: (next - now).num_seconds().max(0),
+        last_sync: last_sync.map(|dt| dt.to_rfc3339()),
+    }))
+}
+
+#[tauri::command]
+fn update_bot_settings(app: tauri::AppHandle, settings: BotSettings) -> Result<(), String> {
+    let app_dir = app_data_dir(&app)?;
+    let db_path = app_dir.join("data").join("mythic_runs.db");
 
-    println!("Loaded {} commands from commands.json", commands.len());
+    if !db_path.exists() {
+        return Err("Database not found".to_string());
+    }
 
-    // Load config
-    let config = load_config(&app)?;
-    let client_id = config.get("clientId")
-        .and_then(|v| v.as_str())
-        .ok_or("Missing clientId in config")?;
-    let guild_id = config.get("guildId")
-        .and_then(|v| v.as_str())
-        .ok_or("Missing guildId in config")?;
-    let token = config.get("token")
-        .and_then(|v| v.as_str())
-        .ok_or("Missing token in config")?;
+    let conn = Connection::open(&db_path)
+        .map_err(|e| format!("Failed to open database: {}", e))?;
 
-    // Deploy commands via Discord REST API
-    let client = reqwest::Client::new();
-    let url = format!("https://discord.com/api/v9/applications/{}/guilds/{}/commands", client_id, guild_id);
+    // Enable WAL mode
+    conn.pragma_update(None, "journal_mode", "WAL")
+        .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
 
-    println!("Deploying to Discord API: {}", url);
+    validate_bot_settings(&settings)?;
+    let token_region = settings.token_region.to_lowercase();
+    let report_schedule = settings.report_schedule.as_deref()
+        .map(normalize_report_schedule)
+        .filter(|schedule| !schedule.is_empty());
+    let token_alert_direction = settings.token_alert_direction.to_lowercase();
 
-    let response = client
-        .put(&url)
-        .header("Authorization", format!("Bot {}", token))
-        .header("Content-Type", "application/json")
-        .json(&commands)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to send deployment request: {}", e))?;
+    ensure_bot_settings_columns(&conn)?;
 
-    let status = response.status();
-    println!("Discord API response status: {}", status);
+    // Serialize dungeons to JSON
+    let dungeons_json = serde_json::to_string(&settings.active_dungeons)
+        .map_err(|e| format!("Failed to serialize dungeons: {}", e))?;
 
-    if !status.is_success() {
-        let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-        return Err(format!("Discord API error ({}): {}", status, error_text));
-    }
+    // Update bot settings
+    conn.execute(
+        "UPDATE bot_settings
+         SET current_season_id = ?1,
+             current_season_name = ?2,
+             default_region = ?3,
+             default_realm = ?4,
+             active_dungeons = ?5,
+             beta_channel = ?6,
+             updated_at = ?7,
+             token_region = ?8,
+             report_schedule = ?9,
+             token_alert_threshold = ?10,
+             token_alert_direction = ?11
+         WHERE id = 1",
+        (
+            settings.season_id,
+            &settings.season_name,
+            &settings.default_region,
+            &settings.default_realm,
+            &dungeons_json,
+            settings.beta_channel as i64,
+            chrono::Utc::now().timestamp_millis(),
+            &token_region,
+            &report_schedule,
+            settings.token_alert_threshold,
+            &token_alert_direction,
+        ),
+    ).map_err(|e| format!("Failed to update bot settings: {}", e))?;
 
-    let result: Vec<serde_json::Value> = response.json().await
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
+    Ok(())
+}
 
-    // Build success message
-    let mut message = format!("Successfully deployed {} command(s)!\n\n", result.len());
-    message.push_str("Registered commands:\n");
+#[tauri::command]
+fn reset_bot_settings(app: tauri::AppHandle) -> Result<BotSettings, String> {
+    log::debug!("reset_bot_settings called");
 
-    for cmd in &result {
-        if let Some(name) = cmd.get("name").and_then(|v| v.as_str()) {
-            message.push_str(&format!("  - /{}\n", name));
-        }
+    let app_dir = app_data_dir(&app)?;
+    let db_path = app_dir.join("data").join("mythic_runs.db");
+
+    if !db_path.exists() {
+        return Err("Database not found".to_string());
     }
 
-    println!("Deployment successful!");
-    Ok(message)
-}
+    let mut conn = Connection::open(&db_path)
+        .map_err(|e| format!("Failed to open database: {}", e))?;
 
-#[tauri::command]
-async fn insert_manual_run(app: tauri::AppHandle, run_data: serde_json::Value) -> Result<String, String> {
-    println!("insert_manual_run command called");
-    println!("Run data: {:?}", run_data);
+    // Enable WAL mode
+    conn.pragma_update(None, "journal_mode", "WAL")
+        .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
 
-    // Extract fields from run_data
-    let character_name = run_data.get("characterName")
-        .and_then(|v| v.as_str())
-        .ok_or("Missing characterName")?;
-    let realm = run_data.get("realm")
-        .and_then(|v| v.as_str())
-        .ok_or("Missing realm")?;
-    let region = run_data.get("region")
-        .and_then(|v| v.as_str())
-        .ok_or("Missing region")?;
-    let dungeon = run_data.get("dungeon")
-        .and_then(|v| v.as_str())
-        .ok_or("Missing dungeon")?;
-    let keystone_level = run_data.get("keystoneLevel")
-        .and_then(|v| v.as_i64())
-        .ok_or("Missing keystoneLevel")? as i64;
-    let completion_time = run_data.get("completionTime")
-        .and_then(|v| v.as_i64())
-        .unwrap_or(0) as i64;
-    let upgraded_level = run_data.get("upgradedLevel")
-        .and_then(|v| v.as_i64())
-        .unwrap_or(0) as i64;
-    let spec = run_data.get("spec")
-        .and_then(|v| v.as_str())
-        .unwrap_or("Unknown");
-    let role = run_data.get("role")
-        .and_then(|v| v.as_str())
-        .unwrap_or("DPS");
-    let season = run_data.get("season")
-        .and_then(|v| v.as_str())
-        .unwrap_or("manual-insert");
+    ensure_bot_settings_columns(&conn)?;
+
+    let tx = conn.transaction()
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+    // Same defaults the Node.js bot seeds the row with, on the stable channel
+    tx.execute(
+        "INSERT INTO bot_settings (id, current_season_id, current_season_name, default_region, default_realm, active_dungeons, beta_channel, token_region, report_schedule, token_alert_threshold, token_alert_direction, updated_at)
+         VALUES (1, 15, 'season-tww-3', 'us', 'thrall', '[]', 0, 'us', NULL, NULL, 'above', ?1)
+         ON CONFLICT(id) DO UPDATE SET
+             current_season_id = excluded.current_season_id,
+             current_season_name = excluded.current_season_name,
+             default_region = excluded.default_region,
+             default_realm = excluded.default_realm,
+             active_dungeons = excluded.active_dungeons,
+             beta_channel = excluded.beta_channel,
+             token_region = excluded.token_region,
+             report_schedule = excluded.report_schedule,
+             token_alert_threshold = excluded.token_alert_threshold,
+             token_alert_direction = excluded.token_alert_direction,
+             updated_at = excluded.updated_at",
+        [chrono::Utc::now().timestamp_millis()],
+    ).map_err(|e| format!("Failed to reset bot settings: {}", e))?;
+
+    tx.commit()
+        .map_err(|e| format!("Failed to commit bot settings reset: {}", e))?;
+
+    log::info!("Bot settings reset to defaults");
+    get_bot_settings(app)
+}
 
-    // Normalize realm to lowercase to match database storage
-    let normalized_realm = realm.to_lowercase();
+#[derive(Clone, Serialize, Deserialize)]
+struct TokenAlertStatus {
+    threshold: Option<i64>,
+    direction: String,
+    #[serde(rename = "thresholdSource")]
+    threshold_source: String,
+    #[serde(rename = "latestPrice")]
+    latest_price: Option<i64>,
+    #[serde(rename = "latestTimestamp")]
+    latest_timestamp: Option<String>,
+    #[serde(rename = "wouldFire")]
+    would_fire: bool,
+}
 
-    // Get database path
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
-    let data_dir = app_dir.join("data");
-    fs::create_dir_all(&data_dir)
-        .map_err(|e| format!("Failed to create data directory: {}", e))?;
-    let db_path = data_dir.join("mythic_runs.db");
+// Preview whether the bot's token alert would fire on the latest recorded price.
+// Mirrors the bot: the app-configured threshold wins, otherwise the one set via
+// /token set-threshold in token_settings is used.
+#[tauri::command]
+fn get_token_alert_status(app: tauri::AppHandle) -> Result<TokenAlertStatus, String> {
+    let settings = get_bot_settings(app.clone())?;
 
-    if !db_path.exists() {
-        return Err("Database not found. Please start the bot first to initialize the database.".to_string());
-    }
+    let app_dir = app_data_dir(&app)?;
+    let db_path = app_dir.join("data").join("mythic_runs.db");
 
-    // Open database connection
     let conn = Connection::open(&db_path)
         .map_err(|e| format!("Failed to open database: {}", e))?;
 
@@ -689,786 +6023,1173 @@ async fn insert_manual_run(app: tauri::AppHandle, run_data: serde_json::Value) -
     conn.pragma_update(None, "journal_mode", "WAL")
         .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
 
-    // Step 1: Upsert character
-    println!("Upserting character: {}-{} ({})", character_name, normalized_realm, region);
-
-    // Check if character exists
-    let character_id: Option<i64> = conn.query_row(
-        "SELECT id FROM characters WHERE name = ?1 AND realm = ?2 AND region = ?3",
-        [character_name, normalized_realm.as_str(), region],
-        |row| row.get(0)
-    ).ok();
+    // The token tables only exist once the bot's token tracker has initialized
+    let (threshold, threshold_source) = match settings.token_alert_threshold {
+        Some(threshold) => (Some(threshold), "app".to_string()),
+        None if table_exists(&conn, "token_settings")? => {
+            let threshold = conn.query_row(
+                "SELECT threshold FROM token_settings WHERE id = 1",
+                [],
+                |row| row.get(0)
+            ).optional()
+             .map_err(|e| format!("Failed to query token threshold: {}", e))?;
+            (threshold, "discord".to_string())
+        }
+        None => (None, "none".to_string()),
+    };
 
-    let character_id = if let Some(id) = character_id {
-        // Update existing character
-        conn.execute(
-            "UPDATE characters SET active_spec_name = ?1, active_spec_role = ?2, updated_at = ?3 WHERE id = ?4",
-            (spec, role, chrono::Utc::now().timestamp_millis(), id),
-        ).map_err(|e| format!("Failed to update character: {}", e))?;
-        println!("Updated existing character with ID: {}", id);
-        id
+    let latest: Option<(i64, String)> = if table_exists(&conn, "token_prices")? {
+        conn.query_row(
+            "SELECT price, timestamp FROM token_prices ORDER BY recorded_at DESC LIMIT 1",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?))
+        ).optional()
+         .map_err(|e| format!("Failed to query latest token price: {}", e))?
     } else {
-        // Insert new character
-        conn.execute(
-            "INSERT INTO characters (name, realm, region, class, active_spec_name, active_spec_role, created_at, updated_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
-            (
-                character_name,
-                normalized_realm.as_str(),
-                region,
-                "Unknown", // class
-                spec,
-                role,
-                chrono::Utc::now().timestamp_millis(),
-                chrono::Utc::now().timestamp_millis(),
-            ),
-        ).map_err(|e| format!("Failed to insert character: {}", e))?;
+        None
+    };
 
-        let id = conn.last_insert_rowid();
-        println!("Created new character with ID: {}", id);
-        id
+    let direction = settings.token_alert_direction.to_lowercase();
+    let would_fire = match (threshold, &latest) {
+        (Some(threshold), Some((price, _))) => match direction.as_str() {
+            "below" => *price <= threshold,
+            _ => *price >= threshold,
+        },
+        _ => false,
     };
 
-    // Step 2: Insert the run
-    println!("Inserting run for character ID: {}", character_id);
-    let completed_timestamp = chrono::Utc::now().timestamp_millis();
-    let keystone_run_id = completed_timestamp; // Use timestamp as unique ID
-    let is_completed_within_time = if upgraded_level > 0 { 1 } else { 0 };
+    let (latest_price, latest_timestamp) = match latest {
+        Some((price, timestamp)) => (Some(price), Some(timestamp)),
+        None => (None, None),
+    };
 
-    // Check for duplicate
-    let duplicate_check: Option<i64> = conn.query_row(
-        "SELECT id FROM mythic_runs WHERE character_id = ?1 AND dungeon = ?2 AND mythic_level = ?3 AND completed_timestamp = ?4",
-        (character_id, dungeon, keystone_level, completed_timestamp),
-        |row| row.get(0)
-    ).ok();
+    Ok(TokenAlertStatus {
+        threshold,
+        direction,
+        threshold_source,
+        latest_price,
+        latest_timestamp,
+        would_fire,
+    })
+}
 
-    if duplicate_check.is_some() {
-        return Ok(format!(
-            "⚠️  Run already exists (duplicate detected)\n\
-             Character: {}-{}\n\
-             Dungeon: {} +{}\n\
-             Spec: {} ({})",
-            character_name, realm, dungeon, keystone_level, spec, role
-        ));
+// Prices returned by get_token_prices when no limit is given
+const DEFAULT_TOKEN_PRICE_LIMIT: usize = 500;
+
+#[derive(Clone, Serialize, Deserialize)]
+struct TokenPrice {
+    // When the bot recorded the price, as RFC3339
+    timestamp: String,
+    price: i64,
+}
+
+// Most recent WoW Token prices the bot has recorded, oldest first for charting
+#[tauri::command]
+fn get_token_prices(app: tauri::AppHandle, limit: Option<usize>) -> Result<Vec<TokenPrice>, String> {
+    log::debug!("get_token_prices called with limit: {:?}", limit);
+
+    let db_path = read_database_path(&app)?;
+
+    if !db_path.exists() {
+        return Ok(Vec::new());
     }
 
-    conn.execute(
-        "INSERT INTO mythic_runs (
-            character_id, dungeon, mythic_level, completed_timestamp,
-            duration, keystone_run_id, is_completed_within_time, score,
-            num_keystone_upgrades, spec_name, spec_role, affixes, season, created_at
-        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
-        (
-            character_id,
-            dungeon,
-            keystone_level,
-            completed_timestamp,
-            completion_time,
-            keystone_run_id,
-            is_completed_within_time,
-            0, // score - manual runs don't have scores
-            upgraded_level,
-            spec,
-            role,
-            rusqlite::types::Null, // affixes - manual runs don't track affixes
-            season,
-            chrono::Utc::now().timestamp_millis(), // created_at
-        ),
-    ).map_err(|e| format!("Failed to insert run: {}", e))?;
+    let conn = open_read_database(&app, &db_path)?;
 
-    let run_id = conn.last_insert_rowid();
-    println!("Successfully inserted run with ID: {}", run_id);
+    // The table only exists once the bot's token tracker has initialized
+    if !table_exists(&conn, "token_prices")? {
+        return Ok(Vec::new());
+    }
 
-    Ok(format!(
-        "✅ Successfully inserted manual run!\n\
-         Run ID: {}\n\
-         Character: {}-{}\n\
-         Dungeon: {} +{}\n\
-         Spec: {} ({})\n\
-         Season: {}",
-        run_id, character_name, realm, dungeon, keystone_level, spec, role, season
-    ))
+    let limit = limit.unwrap_or(DEFAULT_TOKEN_PRICE_LIMIT) as i64;
+    let mut stmt = conn.prepare(
+        "SELECT recorded_at, price FROM (
+             SELECT recorded_at, price FROM token_prices ORDER BY recorded_at DESC LIMIT ?1
+         ) ORDER BY recorded_at"
+    ).map_err(|e| format!("Failed to prepare query: {}", e))?;
+
+    let prices = stmt.query_map([limit], |row| {
+        Ok(TokenPrice {
+            timestamp: timestamp_ms_to_rfc3339(row.get(0)?),
+            price: row.get(1)?,
+        })
+    })
+    .map_err(|e| format!("Failed to query token prices: {}", e))?
+    .collect::<Result<Vec<_>, _>>()
+    .map_err(|e| format!("Failed to read token price: {}", e))?;
+
+    Ok(prices)
+}
+
+#[tauri::command]
+fn get_startup_error(app: tauri::AppHandle) -> Result<Option<String>, String> {
+    // Without an AppData folder, setup writes its error to the temp dir instead
+    let error_path = match app_data_dir(&app) {
+        Ok(app_dir) => app_dir.join("startup-error.txt"),
+        Err(_) => fallback_startup_error_path(),
+    };
+
+    if !error_path.exists() {
+        return Ok(None);
+    }
+
+    match fs::read_to_string(&error_path) {
+        Ok(content) => {
+            // Delete the error file after reading it
+            let _ = fs::remove_file(&error_path);
+            Ok(Some(content))
+        }
+        Err(e) => Err(format!("Failed to read startup error: {}", e))
+    }
 }
 
-#[tauri::command]
-async fn delete_discord_commands(app: tauri::AppHandle) -> Result<String, String> {
-    println!("delete_discord_commands command called");
+#[tauri::command]
+fn get_logs(app: tauri::AppHandle, limit: Option<usize>, min_level: Option<String>) -> Result<Vec<LogEntry>, String> {
+    // Explicit limit wins, then the user's configured default, then 100
+    let limit = limit
+        .or_else(|| get_settings(app.clone()).ok().and_then(|s| s.default_log_limit))
+        .unwrap_or(100);
+
+    // Filtered before the limit, so asking for 100 errors returns 100 errors
+    let min_rank = min_level.as_deref().map(log_level_rank).unwrap_or(0);
+    let wanted = |entry: &LogEntry| log_level_rank(&entry.level) >= min_rank;
+
+    // Get app data directory
+    let app_dir = app_data_dir(&app)?;
+    let log_file = current_log_file(&app_dir.join("logs"))?;
+
+    if !log_file.exists() {
+        return Ok(Vec::new());
+    }
+
+    // Use a more efficient approach: read file from end backwards
+    let file = fs::File::open(&log_file)
+        .map_err(|e| format!("Failed to open log file: {}", e))?;
 
-    // Load config
-    let config = load_config(&app)?;
-    let client_id = config.get("clientId")
-        .and_then(|v| v.as_str())
-        .ok_or("Missing clientId in config")?;
-    let guild_id = config.get("guildId")
-        .and_then(|v| v.as_str())
-        .ok_or("Missing guildId in config")?;
-    let token = config.get("token")
-        .and_then(|v| v.as_str())
-        .ok_or("Missing token in config")?;
+    let metadata = file.metadata()
+        .map_err(|e| format!("Failed to get file metadata: {}", e))?;
+    let file_size = metadata.len();
 
-    // Get all registered commands
-    let client = reqwest::Client::new();
-    let list_url = format!("https://discord.com/api/v9/applications/{}/guilds/{}/commands", client_id, guild_id);
+    // If file is small, just read it all
+    if file_size < 1_000_000 {  // Less than 1MB
+        let reader = BufReader::new(file);
+        let mut logs = Vec::new();
 
-    let response = client
-        .get(&list_url)
-        .header("Authorization", format!("Bot {}", token))
-        .send()
-        .await
-        .map_err(|e| format!("Failed to fetch commands: {}", e))?;
+        for line in reader.lines().map_while(Result::ok) {
+            if let Ok(json) = serde_json::from_str::<serde_json::Value>(&line) {
+                let entry = parse_log_entry(json);
+                if wanted(&entry) {
+                    logs.push(entry);
+                }
+            }
+        }
 
-    if !response.status().is_success() {
-        let status = response.status();
-        let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-        return Err(format!("Discord API error ({}): {}", status, error_text));
+        // Return last N entries
+        let start = if logs.len() > limit { logs.len() - limit } else { 0 };
+        return Ok(logs[start..].to_vec());
     }
 
-    let commands: Vec<serde_json::Value> = response.json().await
-        .map_err(|e| format!("Failed to parse commands list: {}", e))?;
+    // For large files, read backwards from end to get most recent logs efficiently
+    // This prevents reading the entire file when we only need the last few lines
+    use std::io::{Seek, SeekFrom, Read};
+    let mut file = fs::File::open(&log_file)
+        .map_err(|e| format!("Failed to open log file: {}", e))?;
 
-    if commands.is_empty() {
-        return Ok("No commands to delete".to_string());
+    // Read fixed-size chunks backwards from the end until we have enough entries.
+    // Working on raw bytes avoids failing when a chunk boundary splits a UTF-8 character.
+    const CHUNK_SIZE: u64 = 64 * 1024;
+    let mut logs: Vec<LogEntry> = Vec::new();
+    // Leading bytes of the last chunk, which may be the tail of a line in the previous one
+    let mut partial: Vec<u8> = Vec::new();
+    let mut pos = file_size;
+
+    while pos > 0 && logs.len() < limit {
+        let read_size = std::cmp::min(CHUNK_SIZE, pos);
+        pos -= read_size;
+
+        file.seek(SeekFrom::Start(pos))
+            .map_err(|e| format!("Failed to seek in log file: {}", e))?;
+
+        let mut chunk = vec![0u8; read_size as usize];
+        file.read_exact(&mut chunk)
+            .map_err(|e| format!("Failed to read log file: {}", e))?;
+        chunk.extend_from_slice(&partial);
+
+        // Hold back the possibly partial first line unless we reached the start of the file
+        let content_start = if pos > 0 {
+            chunk.iter().position(|&b| b == b'\n').map(|i| i + 1).unwrap_or(chunk.len())
+        } else {
+            0
+        };
+        partial = chunk[..content_start].to_vec();
+
+        let mut entries: Vec<LogEntry> = String::from_utf8_lossy(&chunk[content_start..])
+            .lines()
+            .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+            .map(parse_log_entry)
+            .filter(|entry| wanted(entry))
+            .collect();
+        entries.append(&mut logs);
+        logs = entries;
     }
 
-    println!("Found {} commands to delete", commands.len());
-
-    // Delete each command
-    let mut deleted_count = 0;
-    for cmd in commands {
-        if let Some(cmd_id) = cmd.get("id").and_then(|v| v.as_str()) {
-            let delete_url = format!("https://discord.com/api/v9/applications/{}/guilds/{}/commands/{}",
-                client_id, guild_id, cmd_id);
+    // Return last N entries
+    let start = if logs.len() > limit { logs.len() - limit } else { 0 };
+    Ok(logs[start..].to_vec())
+}
 
-            match client
-                .delete(&delete_url)
-                .header("Authorization", format!("Bot {}", token))
-                .send()
-                .await
-            {
-                Ok(resp) if resp.status().is_success() => {
-                    deleted_count += 1;
-                    if let Some(name) = cmd.get("name").and_then(|v| v.as_str()) {
-                        println!("Deleted command: /{}", name);
-                    }
-                }
-                Ok(resp) => {
-                    println!("Failed to delete command {}: {}", cmd_id, resp.status());
-                }
-                Err(e) => {
-                    println!("Error deleting command {}: {}", cmd_id, e);
-                }
-            }
+// Helper function to find the bot's active log file
+fn current_log_file(logs_dir: &PathBuf) -> Result<PathBuf, String> {
+    // Read current log file path from marker
+    let marker_path = logs_dir.join("current.log");
+    if marker_path.exists() {
+        if let Ok(path) = fs::read_to_string(&marker_path) {
+            return Ok(PathBuf::from(path.trim()));
         }
     }
 
-    Ok(format!("Successfully deleted {} command(s)", deleted_count))
+    // Fallback: find most recent log file
+    get_most_recent_log_file(logs_dir)
 }
 
-// Helper function to load config
-fn load_config(app: &tauri::AppHandle) -> Result<serde_json::Value, String> {
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
-    let config_path = app_dir.join("config.json");
+// Entries in the last `max_bytes` of a log file; the first line is dropped if cut off
+fn read_log_tail(path: &Path, max_bytes: u64) -> Result<Vec<LogEntry>, String> {
+    use std::io::{Seek, SeekFrom};
 
-    let content = fs::read_to_string(&config_path)
-        .map_err(|e| format!("Failed to read config.json: {}", e))?;
+    let mut file = fs::File::open(path)
+        .map_err(|e| format!("Failed to open log file {:?}: {}", path, e))?;
+    let file_size = file.metadata()
+        .map_err(|e| format!("Failed to get file metadata: {}", e))?
+        .len();
+    let start = file_size.saturating_sub(max_bytes);
 
-    serde_json::from_str(&content)
-        .map_err(|e| format!("Failed to parse config.json: {}", e))
-}
+    file.seek(SeekFrom::Start(start))
+        .map_err(|e| format!("Failed to seek in log file: {}", e))?;
+    let mut buffer = Vec::new();
+    file.read_to_end(&mut buffer)
+        .map_err(|e| format!("Failed to read log file: {}", e))?;
 
-#[tauri::command]
-fn copy_commands_folder(app: tauri::AppHandle) -> Result<String, String> {
-    println!("copy_commands_folder command called");
+    let content_start = if start > 0 {
+        buffer.iter().position(|&b| b == b'\n').map(|i| i + 1).unwrap_or(buffer.len())
+    } else {
+        0
+    };
 
-    // Get AppData directory
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
-    let commands_dir = app_dir.join("commands");
+    Ok(String::from_utf8_lossy(&buffer[content_start..])
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .map(parse_log_entry)
+        .collect())
+}
 
-    // Get resource directory
-    let resource_path = app.path().resource_dir()
-        .map_err(|e| format!("Failed to get resource dir: {}", e))?;
+// Total bytes search_logs reads across all files, newest first
+const SEARCH_LOGS_MAX_BYTES: u64 = 32 * 1024 * 1024;
 
-    println!("Resource directory: {:?}", resource_path);
+// Most recent entries whose message or metadata contains `query` (case-insensitive).
+// Rolled-over daebot-*.log files are searched too unless includeRotated is false.
+#[tauri::command]
+fn search_logs(
+    app: tauri::AppHandle,
+    query: String,
+    limit: Option<usize>,
+    include_rotated: Option<bool>,
+) -> Result<Vec<LogEntry>, String> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Err("Search query cannot be empty".to_string());
+    }
+    let limit = limit.unwrap_or(100);
 
-    // Check multiple possible locations for commands
-    // 1. Direct path (dev builds): dist-backend/commands
-    // 2. _up_ subdirectory (production builds): _up_/dist-backend/commands
-    let possible_paths = vec![
-        resource_path.join("dist-backend").join("commands"),
-        resource_path.join("_up_").join("dist-backend").join("commands"),
-    ];
+    let app_dir = app_data_dir(&app)?;
+    let logs_dir = app_dir.join("logs");
+    if !logs_dir.exists() {
+        return Ok(Vec::new());
+    }
 
-    let mut source_commands_path = None;
-    for path in &possible_paths {
-        println!("Checking for commands at: {:?}", path);
-        if path.exists() {
-            source_commands_path = Some(path.clone());
-            println!("Found commands directory at: {:?}", path);
+    let current = current_log_file(&logs_dir)?;
+    let mut files = vec![current.clone()];
+    if include_rotated.unwrap_or(true) {
+        let mut rotated: Vec<(std::time::SystemTime, PathBuf)> = fs::read_dir(&logs_dir)
+            .map_err(|e| format!("Failed to read logs directory: {}", e))?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                entry.path().extension() == Some(OsStr::new("log"))
+                    && entry.file_name().to_string_lossy().starts_with("daebot-")
+                    && entry.path() != current
+            })
+            .filter_map(|entry| {
+                let modified = entry.metadata().ok()?.modified().ok()?;
+                Some((modified, entry.path()))
+            })
+            .collect();
+        rotated.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+        files.extend(rotated.into_iter().map(|(_, path)| path));
+    }
+
+    // Newest file first; each file's matches are prepended so the result stays chronological
+    let mut matches: Vec<LogEntry> = Vec::new();
+    let mut budget = SEARCH_LOGS_MAX_BYTES;
+    for file in files {
+        if budget == 0 || matches.len() >= limit {
             break;
         }
-    }
+        let Ok(size) = fs::metadata(&file).map(|m| m.len()) else {
+            continue;
+        };
 
-    let source_commands_path = source_commands_path.ok_or_else(|| {
-        format!(
-            "Commands not found. Checked:\n  - {:?}\n  - {:?}",
-            possible_paths[0],
-            possible_paths[1]
-        )
-    })?;
+        let mut found: Vec<LogEntry> = read_log_tail(&file, budget)?
+            .into_iter()
+            .filter(|entry| {
+                entry.message.to_lowercase().contains(&query)
+                    || entry.metadata.as_ref()
+                        .is_some_and(|metadata| metadata.to_string().to_lowercase().contains(&query))
+            })
+            .collect();
+        budget = budget.saturating_sub(size);
 
-    // Create commands directory if it doesn't exist
-    if !commands_dir.exists() {
-        fs::create_dir_all(&commands_dir)
-            .map_err(|e| format!("Failed to create commands directory: {}", e))?;
+        found.append(&mut matches);
+        matches = found;
     }
 
-    // Find all .js files in the bundled commands directory
-    let entries = fs::read_dir(&source_commands_path)
-        .map_err(|e| format!("Failed to read commands directory: {}", e))?;
-
-    let mut copied_files = Vec::new();
+    let start = matches.len().saturating_sub(limit);
+    Ok(matches.split_off(start))
+}
 
-    for entry in entries.flatten() {
-        let file_name = entry.file_name();
+// How much of the end of the current log file get_log_summary reads
+const LOG_SUMMARY_MAX_BYTES: u64 = 4 * 1024 * 1024;
+
+#[derive(Serialize)]
+struct LogSummary {
+    error: u64,
+    warn: u64,
+    info: u64,
+    debug: u64,
+    #[serde(rename = "lastErrorAt")]
+    last_error_at: Option<String>,
+    // Counts cover only the last bytesScanned bytes; partial is true if the file is larger
+    #[serde(rename = "bytesScanned")]
+    bytes_scanned: u64,
+    partial: bool,
+}
 
-        if let Some(name_str) = file_name.to_str() {
-            if name_str.ends_with(".js") {
-                let source_file = source_commands_path.join(&file_name);
-                let dest_file = commands_dir.join(&file_name);
+// Per-level counts for the current log file, for a quick status badge. Large files
+// are only scanned from the end (see LOG_SUMMARY_MAX_BYTES), so counts can be partial.
+#[tauri::command]
+fn get_log_summary(app: tauri::AppHandle) -> Result<LogSummary, String> {
+    let mut summary = LogSummary {
+        error: 0,
+        warn: 0,
+        info: 0,
+        debug: 0,
+        last_error_at: None,
+        bytes_scanned: 0,
+        partial: false,
+    };
 
-                println!("Copying {:?} to {:?}", source_file, dest_file);
-                fs::copy(&source_file, &dest_file)
-                    .map_err(|e| format!("Failed to copy {:?}: {}", file_name, e))?;
+    let app_dir = app_data_dir(&app)?;
+    let logs_dir = app_dir.join("logs");
+    if !logs_dir.exists() {
+        return Ok(summary);
+    }
+    let log_file = current_log_file(&logs_dir)?;
+    let Ok(file_size) = fs::metadata(&log_file).map(|m| m.len()) else {
+        return Ok(summary);
+    };
 
-                copied_files.push(name_str.to_string());
+    for entry in read_log_tail(&log_file, LOG_SUMMARY_MAX_BYTES)? {
+        // TRACE is folded into debug, anything unrecognized into info
+        match log_level_rank(&entry.level) {
+            4 => {
+                summary.error += 1;
+                summary.last_error_at = Some(entry.timestamp);
             }
+            3 => summary.warn += 1,
+            0 | 1 => summary.debug += 1,
+            _ => summary.info += 1,
         }
     }
 
-    if copied_files.is_empty() {
-        return Err("No command files found to copy".to_string());
+    summary.bytes_scanned = file_size.min(LOG_SUMMARY_MAX_BYTES);
+    summary.partial = file_size > LOG_SUMMARY_MAX_BYTES;
+    Ok(summary)
+}
+
+// Empties the current log file and returns how many bytes were cleared. The file is
+// truncated rather than deleted because the bot keeps it open (and locked on Windows);
+// with archive, the old contents are first copied to a timestamped file alongside it.
+#[tauri::command]
+fn clear_logs(app: tauri::AppHandle, archive: Option<bool>) -> Result<u64, String> {
+    let app_dir = app_data_dir(&app)?;
+    let log_file = current_log_file(&app_dir.join("logs"))?;
+    if !log_file.exists() {
+        return Ok(0);
     }
 
-    Ok(format!(
-        "Successfully copied {} command file(s) to:\n{:?}\n\nFiles:\n{}",
-        copied_files.len(),
-        commands_dir,
-        copied_files.join("\n")
-    ))
+    let cleared = fs::metadata(&log_file)
+        .map_err(|e| format!("Failed to get file metadata: {}", e))?
+        .len();
+
+    if archive.unwrap_or(false) && cleared > 0 {
+        let stem = log_file.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+        let archive_path = log_file.with_file_name(format!(
+            "{}-cleared-{}.log",
+            stem,
+            chrono::Local::now().format("%Y%m%d_%H%M%S")
+        ));
+        fs::copy(&log_file, &archive_path)
+            .map_err(|e| format!("Failed to archive log file: {}", e))?;
+        log::info!("Archived log file to: {:?}", archive_path);
+    }
+
+    fs::OpenOptions::new()
+        .write(true)
+        .open(&log_file)
+        .and_then(|file| file.set_len(0))
+        .map_err(|e| format!("Failed to clear log file: {}", e))?;
+
+    log::info!("Cleared {} bytes from {:?}", cleared, log_file);
+    Ok(cleared)
 }
 
-#[derive(Clone, Serialize, Deserialize)]
-struct UpdateInfo {
-    version: String,
-    #[serde(rename = "currentVersion")]
-    current_version: String,
-    available: bool,
-    #[serde(rename = "isPrerelease")]
-    is_prerelease: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    changelog: Option<String>,
+// Most get_logs_since reads in one call; a caller far behind catches up over several polls
+const LOGS_SINCE_MAX_BYTES: u64 = 1024 * 1024;
+
+#[derive(Serialize)]
+struct LogsSince {
+    entries: Vec<LogEntry>,
+    // Pass back as byteOffset on the next call
+    offset: u64,
 }
 
-// Helper struct for GitHub API response
-#[derive(Deserialize)]
-struct GitHubRelease {
-    body: Option<String>,
+// Entries written since `byte_offset`, for cheap polling. No offset starts at the
+// current end of the file; a file smaller than the offset (rotated) restarts at 0.
+#[tauri::command]
+fn get_logs_since(app: tauri::AppHandle, byte_offset: Option<u64>) -> Result<LogsSince, String> {
+    use std::io::{Seek, SeekFrom};
+
+    let app_dir = app_data_dir(&app)?;
+    let log_file = current_log_file(&app_dir.join("logs"))?;
+
+    let mut file = match fs::File::open(&log_file) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(LogsSince { entries: Vec::new(), offset: 0 });
+        }
+        Err(e) => return Err(format!("Failed to open log file: {}", e)),
+    };
+    let file_size = file.metadata()
+        .map_err(|e| format!("Failed to get file metadata: {}", e))?
+        .len();
+
+    let Some(offset) = byte_offset else {
+        return Ok(LogsSince { entries: Vec::new(), offset: file_size });
+    };
+    if file_size < offset {
+        return Ok(LogsSince { entries: Vec::new(), offset: 0 });
+    }
+
+    file.seek(SeekFrom::Start(offset))
+        .map_err(|e| format!("Failed to seek in log file: {}", e))?;
+    let mut buffer = Vec::new();
+    file.take(LOGS_SINCE_MAX_BYTES)
+        .read_to_end(&mut buffer)
+        .map_err(|e| format!("Failed to read log file: {}", e))?;
+
+    // Leave a line that is still being written for the next call
+    let complete = buffer.iter().rposition(|&b| b == b'\n').map(|i| i + 1).unwrap_or(0);
+    let entries = String::from_utf8_lossy(&buffer[..complete])
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .map(parse_log_entry)
+        .collect();
+
+    Ok(LogsSince {
+        entries,
+        offset: offset + complete as u64,
+    })
 }
 
-// Fetch changelog from GitHub releases
-async fn fetch_changelog(version: &str) -> Option<String> {
-    let url = format!("https://api.github.com/repos/Drizzyt77/DaeBotJS/releases/tags/v{}", version);
+// Severity order for get_logs' minLevel; unrecognized levels count as INFO
+fn log_level_rank(level: &str) -> u8 {
+    match level.to_ascii_uppercase().as_str() {
+        "TRACE" => 0,
+        "DEBUG" => 1,
+        "WARN" | "WARNING" => 3,
+        "ERROR" => 4,
+        _ => 2,
+    }
+}
 
-    match reqwest::Client::new()
-        .get(&url)
-        .header("User-Agent", "DaeBot")
-        .send()
-        .await
-    {
-        Ok(response) => {
-            match response.json::<GitHubRelease>().await {
-                Ok(release) => release.body,
-                Err(e) => {
-                    println!("Failed to parse GitHub release: {}", e);
-                    None
-                }
+// Helper function to parse a log entry
+fn parse_log_entry(json: serde_json::Value) -> LogEntry {
+    let timestamp = json["timestamp"].as_str().unwrap_or("").to_string();
+    let level = json["level"].as_str().unwrap_or("INFO").to_string();
+    let message = json["message"].as_str().unwrap_or("").to_string();
+
+    // Collect all other fields as metadata
+    let mut metadata = serde_json::Map::new();
+    if let Some(obj) = json.as_object() {
+        for (key, value) in obj {
+            if key != "timestamp" && key != "level" && key != "message" {
+                metadata.insert(key.clone(), value.clone());
             }
         }
-        Err(e) => {
-            println!("Failed to fetch changelog from GitHub: {}", e);
+    }
+
+    LogEntry {
+        timestamp,
+        level,
+        message,
+        metadata: if metadata.is_empty() {
             None
-        }
+        } else {
+            Some(serde_json::Value::Object(metadata))
+        },
+    }
+}
+
+// Helper function to find most recent log file
+fn get_most_recent_log_file(logs_dir: &PathBuf) -> Result<PathBuf, String> {
+    if !logs_dir.exists() {
+        return Err("Logs directory does not exist".to_string());
+    }
+
+    let mut log_files: Vec<_> = fs::read_dir(logs_dir)
+        .map_err(|e| format!("Failed to read logs directory: {}", e))?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry.path().extension() == Some(OsStr::new("log"))
+                && entry.file_name().to_string_lossy().starts_with("daebot-")
+        })
+        .collect();
+
+    if log_files.is_empty() {
+        return Err("No log files found".to_string());
     }
+
+    // Sort by modification time, most recent first
+    log_files.sort_by_key(|entry| {
+        entry.metadata().ok()
+            .and_then(|m| m.modified().ok())
+            .map(std::cmp::Reverse)
+    });
+
+    Ok(log_files[0].path())
+}
+
+// How many distinct messages get_error_summary returns
+const ERROR_SUMMARY_TOP_N: usize = 10;
+
+#[derive(Clone, Serialize, Deserialize)]
+struct ErrorGroup {
+    level: String,
+    message: String,
+    count: usize,
+    #[serde(rename = "lastSeen")]
+    last_seen: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct ErrorSummary {
+    #[serde(rename = "sinceMinutes")]
+    since_minutes: i64,
+    #[serde(rename = "errorCount")]
+    error_count: usize,
+    #[serde(rename = "warnCount")]
+    warn_count: usize,
+    #[serde(rename = "topMessages")]
+    top_messages: Vec<ErrorGroup>,
 }
 
+// Count ERROR/WARN entries in the bot logs from the last sinceMinutes
+// (default 60) and group them by message for a health overview
 #[tauri::command]
-async fn check_for_updates(app: tauri::AppHandle) -> Result<UpdateInfo, String> {
-    println!("Checking for updates...");
+fn get_error_summary(app: tauri::AppHandle, since_minutes: Option<i64>) -> Result<ErrorSummary, String> {
+    let since_minutes = since_minutes.unwrap_or(60);
+    if since_minutes <= 0 {
+        return Err("sinceMinutes must be greater than 0".to_string());
+    }
 
-    // Get bot settings to check beta channel preference
-    let settings = match get_bot_settings(app.clone()) {
-        Ok(s) => s,
-        Err(e) => {
-            println!("Failed to get bot settings: {}, defaulting to stable channel", e);
-            // If we can't get settings, default to stable channel (beta_channel = false)
-            BotSettings {
-                season_id: 0,
-                season_name: String::new(),
-                default_region: String::new(),
-                default_realm: String::new(),
-                active_dungeons: Vec::new(),
-                beta_channel: false,
-                updated_at: None,
-            }
-        }
-    };
+    let cutoff = chrono::Utc::now() - chrono::Duration::minutes(since_minutes);
+    let cutoff_time = std::time::SystemTime::from(cutoff);
 
-    let current_version = app.package_info().version.to_string();
-    println!("Current version: {}", current_version);
-    println!("Beta channel enabled: {}", settings.beta_channel);
+    let app_dir = app_data_dir(&app)?;
+    let logs_dir = app_dir.join("logs");
 
-    // Use different update endpoint based on beta channel setting
-    let update_endpoint = if settings.beta_channel {
-        "https://github.com/Drizzyt77/DaeBotJS/releases/latest/download/latest-beta.json"
-    } else {
-        "https://github.com/Drizzyt77/DaeBotJS/releases/latest/download/latest.json"
+    let mut summary = ErrorSummary {
+        since_minutes,
+        error_count: 0,
+        warn_count: 0,
+        top_messages: Vec::new(),
     };
-    println!("Using update endpoint: {}", update_endpoint);
 
-    // Parse the endpoint URL
-    let update_url = match Url::parse(update_endpoint) {
-        Ok(url) => url,
-        Err(e) => {
-            return Err(format!("Invalid update URL: {}", e));
-        }
-    };
+    if !logs_dir.exists() {
+        return Ok(summary);
+    }
 
-    // Try to check for updates using the updater API
-    let updater_builder = app.updater_builder()
-        .endpoints(vec![update_url])
-        .map_err(|e| format!("Failed to set update endpoints: {}", e))?;
+    // Files last written before the window can't contain entries inside it
+    let log_files: Vec<PathBuf> = fs::read_dir(&logs_dir)
+        .map_err(|e| format!("Failed to read logs directory: {}", e))?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry.path().extension() == Some(OsStr::new("log"))
+                && entry.file_name().to_string_lossy().starts_with("daebot-")
+                && entry.metadata().ok()
+                    .and_then(|m| m.modified().ok())
+                    .is_some_and(|modified| modified >= cutoff_time)
+        })
+        .map(|entry| entry.path())
+        .collect();
 
-    match updater_builder.build() {
-        Ok(updater) => {
-            match updater.check().await {
-                Ok(update_result) => {
-                    if let Some(update) = update_result {
-                        let new_version = update.version.clone();
-                        let is_prerelease = new_version.contains("beta") || new_version.contains("alpha") || new_version.contains("rc");
+    // (level, message) -> (count, last seen)
+    let mut groups: std::collections::HashMap<(String, String), (usize, String)> = std::collections::HashMap::new();
 
-                        println!("Update available: {}", new_version);
-                        println!("Is pre-release: {}", is_prerelease);
+    for log_file in log_files {
+        let file = match fs::File::open(&log_file) {
+            Ok(file) => file,
+            Err(e) => {
+                log::warn!("Failed to open log file {:?}: {}", log_file, e);
+                continue;
+            }
+        };
 
-                        // If user is on stable channel, don't show pre-release updates
-                        if !settings.beta_channel && is_prerelease {
-                            println!("Skipping pre-release update (user is on stable channel)");
-                            return Ok(UpdateInfo {
-                                version: current_version.clone(),
-                                current_version,
-                                available: false,
-                                is_prerelease: false,
-                                changelog: None,
-                            });
-                        }
+        for line in BufReader::new(file).lines().map_while(Result::ok) {
+            let Ok(json) = serde_json::from_str::<serde_json::Value>(&line) else {
+                continue;
+            };
+            let entry = parse_log_entry(json);
 
-                        // Fetch changelog from GitHub
-                        let changelog = fetch_changelog(&new_version).await;
+            let level = entry.level.to_uppercase();
+            if level != "ERROR" && level != "WARN" {
+                continue;
+            }
 
-                        Ok(UpdateInfo {
-                            version: new_version,
-                            current_version,
-                            available: true,
-                            is_prerelease,
-                            changelog,
-                        })
-                    } else {
-                        println!("No updates available");
-                        Ok(UpdateInfo {
-                            version: current_version.clone(),
-                            current_version,
-                            available: false,
-                            is_prerelease: false,
-                            changelog: None,
-                        })
-                    }
-                }
-                Err(e) => {
-                    println!("Error checking for updates: {}", e);
-                    // Return no update available on error
-                    Ok(UpdateInfo {
-                        version: current_version.clone(),
-                        current_version,
-                        available: false,
-                        is_prerelease: false,
-                        changelog: None,
-                    })
-                }
+            let in_window = DateTime::parse_from_rfc3339(&entry.timestamp)
+                .is_ok_and(|timestamp| timestamp >= cutoff);
+            if !in_window {
+                continue;
+            }
+
+            if level == "ERROR" {
+                summary.error_count += 1;
+            } else {
+                summary.warn_count += 1;
+            }
+
+            let group = groups.entry((level, entry.message)).or_insert((0, String::new()));
+            group.0 += 1;
+            if entry.timestamp > group.1 {
+                group.1 = entry.timestamp;
             }
-        }
-        Err(e) => {
-            println!("Error building updater: {}", e);
-            Ok(UpdateInfo {
-                version: current_version.clone(),
-                current_version,
-                available: false,
-                is_prerelease: false,
-                changelog: None,
-            })
         }
     }
+
+    let mut top_messages: Vec<ErrorGroup> = groups.into_iter()
+        .map(|((level, message), (count, last_seen))| ErrorGroup { level, message, count, last_seen })
+        .collect();
+
+    // Most frequent first; errors ahead of warnings on ties
+    top_messages.sort_by(|a, b| {
+        b.count.cmp(&a.count)
+            .then_with(|| a.level.cmp(&b.level))
+            .then_with(|| b.last_seen.cmp(&a.last_seen))
+    });
+    top_messages.truncate(ERROR_SUMMARY_TOP_N);
+    summary.top_messages = top_messages;
+
+    Ok(summary)
 }
 
-#[tauri::command]
-fn get_app_version(app: tauri::AppHandle) -> String {
-    app.package_info().version.to_string()
+// Returned in place of an ISO timestamp when the stored value can't be right
+const INVALID_TIMESTAMP: &str = "invalid timestamp";
+
+// 2019-01-01T00:00:00Z; DaeBot has no data from before this
+const EARLIEST_PLAUSIBLE_TIMESTAMP_MS: i64 = 1_546_300_800_000;
+
+// Allowance for a bot machine whose clock runs ahead of this one
+const MAX_FUTURE_SKEW_MS: i64 = 24 * 60 * 60 * 1000;
+
+// Converts a stored millisecond timestamp to ISO 8601. Corrupt or implausible
+// values become INVALID_TIMESTAMP instead of quietly showing as 1970.
+fn timestamp_ms_to_rfc3339(timestamp_ms: i64) -> String {
+    let latest = chrono::Utc::now().timestamp_millis() + MAX_FUTURE_SKEW_MS;
+    if !(EARLIEST_PLAUSIBLE_TIMESTAMP_MS..=latest).contains(&timestamp_ms) {
+        log::warn!("Ignoring implausible timestamp {} ms", timestamp_ms);
+        return INVALID_TIMESTAMP.to_string();
+    }
+
+    DateTime::from_timestamp_millis(timestamp_ms)
+        .map(|dt| dt.to_rfc3339())
+        .unwrap_or_else(|| INVALID_TIMESTAMP.to_string())
 }
 
 #[tauri::command]
-fn get_blizzard_credentials(app: tauri::AppHandle) -> Result<BlizzardCredentials, String> {
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+fn get_last_sync_time(app: tauri::AppHandle) -> Result<Option<String>, String> {
+    log::debug!("get_last_sync_time called");
 
-    fs::create_dir_all(&app_dir)
-        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    // Get app data directory
+    let db_path = read_database_path(&app)?;
 
-    let env_path = app_dir.join(".env");
-    println!("Loading .env from: {:?}", env_path);
+    log::debug!("Database path: {:?}", db_path);
 
-    if !env_path.exists() {
-        // Return empty credentials
-        return Ok(BlizzardCredentials {
-            client_id: String::new(),
-            client_secret: String::new(),
-        });
+    if !db_path.exists() {
+        log::debug!("Database does not exist yet");
+        return Ok(None);
     }
 
-    let content = fs::read_to_string(&env_path)
-        .map_err(|e| format!("Failed to read .env: {}", e))?;
+    let conn = open_read_database(&app, &db_path)?;
+    log::debug!("WAL mode enabled for reading");
 
-    let mut client_id = String::new();
-    let mut client_secret = String::new();
+    if !table_exists(&conn, "sync_history")? {
+        log::debug!("sync_history table does not exist yet - waiting for migration");
+        return Ok(None);
+    }
 
-    for line in content.lines() {
-        if let Some((key, value)) = line.split_once('=') {
-            let key = key.trim();
-            let value = value.trim();
-            match key {
-                "BLIZZARD_CLIENT_ID" => client_id = value.to_string(),
-                "BLIZZARD_CLIENT_SECRET" => client_secret = value.to_string(),
-                _ => {}
+    // First, check what's actually in the table for debugging
+    let total_count: Result<i64, rusqlite::Error> = conn.query_row(
+        "SELECT COUNT(*) FROM sync_history",
+        [],
+        |row| row.get(0)
+    );
+    log::debug!("Total sync_history entries: {:?}", total_count);
+
+    let success_count: Result<i64, rusqlite::Error> = conn.query_row(
+        "SELECT COUNT(*) FROM sync_history WHERE success = 1",
+        [],
+        |row| row.get(0)
+    );
+    log::debug!("Successful sync entries: {:?}", success_count);
+
+    // Show all entries for debugging
+    let mut stmt = conn.prepare("SELECT id, timestamp, sync_type, success FROM sync_history ORDER BY timestamp DESC LIMIT 5")
+        .map_err(|e| format!("Failed to prepare debug query: {}", e))?;
+    let rows = stmt.query_map([], |row| {
+        Ok(format!("id={}, timestamp={}, sync_type={}, success={}",
+            row.get::<_, i64>(0).unwrap_or(-1),
+            row.get::<_, i64>(1).unwrap_or(-1),
+            row.get::<_, String>(2).unwrap_or_else(|_| "?".to_string()),
+            row.get::<_, i64>(3).unwrap_or(-1)
+        ))
+    });
+    log::debug!("Recent sync_history entries:");
+    if let Ok(rows) = rows {
+        for row in rows {
+            if let Ok(row_str) = row {
+                log::debug!("{}", row_str);
             }
         }
     }
 
-    Ok(BlizzardCredentials {
-        client_id,
-        client_secret,
-    })
+    // Query the last successful sync time from sync_history table
+    let result: Result<i64, rusqlite::Error> = conn.query_row(
+        "SELECT timestamp FROM sync_history WHERE success = 1 ORDER BY timestamp DESC LIMIT 1",
+        [],
+        |row| row.get(0)
+    );
+
+    match result {
+        Ok(timestamp) => {
+            log::debug!("Found last sync timestamp: {}", timestamp);
+            // Convert millisecond timestamp to ISO 8601 string
+            let iso_time = timestamp_ms_to_rfc3339(timestamp);
+            log::debug!("Converted to ISO 8601: {}", iso_time);
+            Ok(Some(iso_time))
+        }
+        Err(rusqlite::Error::QueryReturnedNoRows) => {
+            log::debug!("No sync entries found with success=1");
+            Ok(None)
+        }
+        Err(e) => {
+            log::error!("Database query error: {}", e);
+            Err(format!("Database query failed: {}", e))
+        }
+    }
 }
 
 #[tauri::command]
-fn save_blizzard_credentials(app: tauri::AppHandle, credentials: BlizzardCredentials) -> Result<(), String> {
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+fn get_stats(app: tauri::AppHandle, season: Option<String>, region: Option<String>) -> Result<Stats, String> {
+    log::debug!("get_stats called with season: {:?}, region: {:?}", season, region);
 
-    fs::create_dir_all(&app_dir)
-        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    // Get project root directory
+    let db_path = read_database_path(&app)?;
 
-    let env_path = app_dir.join(".env");
-    println!("Saving .env to: {:?}", env_path);
+    log::debug!("Looking for database: {:?}", db_path);
 
-    let content = format!(
-        "BLIZZARD_CLIENT_ID={}\nBLIZZARD_CLIENT_SECRET={}\n",
-        credentials.client_id,
-        credentials.client_secret
-    );
+    if !db_path.exists() {
+        return Ok(Stats {
+            total_runs: 0,
+            total_characters: 0,
+            last_sync: None,
+            database_size: 0,
+        });
+    }
 
-    fs::write(&env_path, content)
-        .map_err(|e| format!("Failed to write .env: {}", e))
+    let conn = open_read_database(&app, &db_path)?;
+
+    // Regions are stored lowercase by the bot; region lives on the character.
+    // Only join for a region filter so runs without a character row still count.
+    let region = region.map(|r| r.to_lowercase());
+    let filter = if region.is_some() {
+        "FROM mythic_runs r
+         JOIN characters c ON c.id = r.character_id
+         WHERE (?1 IS NULL OR r.season = ?1)
+           AND c.region = ?2"
+    } else {
+        "FROM mythic_runs r
+         WHERE (?1 IS NULL OR r.season = ?1)
+           AND ?2 IS NULL"
+    };
+
+    // Get total runs (filtered by season/region if specified)
+    let total_runs: i64 = conn.query_row(
+        &format!("SELECT COUNT(*) {}", filter),
+        (&season, &region),
+        |row| row.get(0)
+    ).unwrap_or(0);
+
+    // Get total characters (filtered by season/region if specified)
+    let total_characters: i64 = conn.query_row(
+        &format!("SELECT COUNT(DISTINCT r.character_id) {}", filter),
+        (&season, &region),
+        |row| row.get(0)
+    ).unwrap_or(0);
+
+    // Get last sync time (most recent run completion, same filter as the counts)
+    let last_sync: Option<i64> = conn.query_row(
+        &format!("SELECT MAX(r.completed_timestamp) {}", filter),
+        (&season, &region),
+        |row| row.get(0)
+    ).ok().flatten();
+
+    let last_sync_str = last_sync.map(timestamp_ms_to_rfc3339);
+
+    // Get database size
+    let metadata = fs::metadata(&db_path)
+        .map_err(|e| format!("Failed to get database size: {}", e))?;
+    let database_size = metadata.len();
+
+    Ok(Stats {
+        total_runs,
+        total_characters,
+        last_sync: last_sync_str,
+        database_size,
+    })
 }
 
 #[tauri::command]
-fn import_database(app: tauri::AppHandle, file_path: String) -> Result<String, String> {
-    println!("[import_database] Called with file_path: '{}'", file_path);
-    println!("[import_database] file_path length: {}", file_path.len());
-    println!("[import_database] file_path is_empty: {}", file_path.is_empty());
+fn get_character_runs(
+    app: tauri::AppHandle,
+    name: String,
+    realm: String,
+    region: String,
+    season: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<CharacterRun>, String> {
+    log::debug!("get_character_runs called for {}-{} ({}), season: {:?}", name, realm, region, season);
+
+    let db_path = read_database_path(&app)?;
+
+    if !db_path.exists() {
+        return Ok(Vec::new());
+    }
 
-    let source_path = PathBuf::from(&file_path);
-    println!("[import_database] PathBuf created: {:?}", source_path);
-    println!("[import_database] PathBuf exists: {}", source_path.exists());
+    let conn = open_read_database(&app, &db_path)?;
 
-    // Verify source file exists
-    if !source_path.exists() {
-        let error_msg = format!("Source database file does not exist: '{}'", file_path);
-        println!("[import_database] ERROR: {}", error_msg);
-        return Err(error_msg);
-    }
+    // Realms and regions are stored lowercase by the bot
+    let normalized_realm = realm.to_lowercase();
+    let normalized_region = region.to_lowercase();
+    let limit = limit.unwrap_or(20) as i64;
 
-    // Verify it's a valid SQLite database by trying to open it
-    match Connection::open(&source_path) {
-        Ok(conn) => {
-            // Verify it has the expected tables
-            let table_check: Result<i64, _> = conn.query_row(
-                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND (name='mythic_runs' OR name='token_prices')",
-                [],
-                |row| row.get(0)
-            );
+    let mut stmt = conn.prepare(
+        "SELECT r.id, r.dungeon, r.mythic_level, r.score, r.is_completed_within_time, r.completed_timestamp
+         FROM mythic_runs r
+         JOIN characters c ON c.id = r.character_id
+         WHERE c.name = ?1 COLLATE NOCASE
+           AND c.realm = ?2
+           AND c.region = ?3
+           AND (?4 IS NULL OR r.season = ?4)
+         ORDER BY r.completed_timestamp DESC
+         LIMIT ?5"
+    ).map_err(|e| format!("Failed to prepare query: {}", e))?;
 
-            match table_check {
-                Ok(count) if count > 0 => {
-                    println!("Database validation passed, found {} expected tables", count);
-                }
-                _ => {
-                    return Err("Database does not contain expected tables (mythic_runs or token_prices)".to_string());
-                }
-            }
-        }
-        Err(e) => {
-            return Err(format!("Invalid SQLite database: {}", e));
+    let runs_iter = stmt.query_map(
+        (&name, &normalized_realm, &normalized_region, &season, limit),
+        |row| {
+            Ok(CharacterRun {
+                id: row.get(0)?,
+                dungeon: row.get(1)?,
+                key_level: row.get(2)?,
+                score: row.get(3)?,
+                timed: row.get::<_, i64>(4)? != 0,
+                completed_timestamp: row.get(5)?,
+            })
         }
+    ).map_err(|e| format!("Failed to query character runs: {}", e))?;
+
+    let mut runs = Vec::new();
+    for run in runs_iter {
+        runs.push(run.map_err(|e| format!("Failed to read run: {}", e))?);
     }
 
-    // Get destination path
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    Ok(runs)
+}
 
-    let data_dir = app_dir.join("data");
-    fs::create_dir_all(&data_dir)
-        .map_err(|e| format!("Failed to create data directory: {}", e))?;
+#[tauri::command]
+fn get_run_detail(app: tauri::AppHandle, run_id: i64) -> Result<RunDetail, String> {
+    log::debug!("get_run_detail called for run {}", run_id);
 
-    let dest_path = data_dir.join("mythic_runs.db");
+    let db_path = read_database_path(&app)?;
 
-    // Backup existing database if it exists
-    if dest_path.exists() {
-        let backup_path = data_dir.join(format!(
-            "mythic_runs_backup_{}.db",
-            chrono::Local::now().format("%Y%m%d_%H%M%S")
-        ));
-        println!("Backing up existing database to: {:?}", backup_path);
-        fs::copy(&dest_path, &backup_path)
-            .map_err(|e| format!("Failed to backup existing database: {}", e))?;
+    if !db_path.exists() {
+        return Err("Database not found".to_string());
     }
 
-    // Copy the new database
-    fs::copy(&source_path, &dest_path)
-        .map_err(|e| format!("Failed to copy database: {}", e))?;
-
-    println!("Database imported successfully to: {:?}", dest_path);
-    Ok(format!("Database imported successfully! Old database backed up if it existed."))
-}
+    let conn = open_read_database(&app, &db_path)?;
 
-// Helper function to log updater messages to a file
-fn log_updater(message: &str) {
-    // Write to AppData/Roaming/DaeBot/updater.log
-    let log_path = if let Some(appdata) = std::env::var_os("APPDATA") {
-        PathBuf::from(appdata).join("com.daebot.app").join("updater.log")
-    } else {
-        PathBuf::from("updater.log")
-    };
+    let detail = conn.query_row(
+        "SELECT r.id, r.dungeon, r.mythic_level, r.score, r.is_completed_within_time,
+                r.num_keystone_upgrades, r.duration, r.completed_timestamp, r.affixes,
+                r.season, r.spec_name, r.spec_role, c.name, c.realm, c.region, c.class
+         FROM mythic_runs r
+         JOIN characters c ON c.id = r.character_id
+         WHERE r.id = ?1",
+        [run_id],
+        |row| {
+            let affixes: Option<String> = row.get(8)?;
+            Ok(RunDetail {
+                id: row.get(0)?,
+                dungeon: row.get(1)?,
+                key_level: row.get(2)?,
+                score: row.get(3)?,
+                timed: row.get::<_, i64>(4)? != 0,
+                keystone_upgrades: row.get(5)?,
+                duration: row.get(6)?,
+                completed_timestamp: row.get(7)?,
+                // Keep unparseable values visible rather than dropping them
+                affixes: match affixes {
+                    Some(text) => serde_json::from_str(&text).unwrap_or(serde_json::Value::String(text)),
+                    None => serde_json::Value::Array(Vec::new()),
+                },
+                season: row.get(9)?,
+                spec_name: row.get(10)?,
+                spec_role: row.get(11)?,
+                character_name: row.get(12)?,
+                realm: row.get(13)?,
+                region: row.get(14)?,
+                class: row.get(15)?,
+            })
+        },
+    ).optional()
+        .map_err(|e| format!("Failed to query run: {}", e))?;
 
-    // Ensure directory exists
-    if let Some(parent) = log_path.parent() {
-        let _ = fs::create_dir_all(parent);
-    }
+    detail.ok_or_else(|| format!("Run {} not found", run_id))
+}
 
-    if let Ok(mut file) = fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&log_path)
-    {
-        let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-        let _ = writeln!(file, "[{}] {}", timestamp, message);
-        let _ = file.flush();
-    }
+#[derive(Clone, Serialize, Deserialize)]
+struct AffixStats {
+    // Affix names in the combination, sorted so the same week always groups together
+    affixes: Vec<String>,
+    runs: i64,
+    #[serde(rename = "averageKeyLevel")]
+    average_key_level: f64,
+    // Fraction of runs completed in time, 0.0-1.0
+    #[serde(rename = "timedRate")]
+    timed_rate: f64,
+}
 
-    // Also print to console
-    println!("{}", message);
+// Affix names from a stored affixes value: Raider.IO objects with a name, or plain strings
+fn affix_names(affixes: &serde_json::Value) -> Vec<String> {
+    let mut names: Vec<String> = affixes.as_array()
+        .map(|items| items.iter()
+            .filter_map(|affix| affix.get("name").and_then(|n| n.as_str()).or_else(|| affix.as_str()))
+            .map(|name| name.to_string())
+            .collect())
+        .unwrap_or_default();
+    names.sort();
+    names
 }
 
 #[tauri::command]
-async fn install_update(app: tauri::AppHandle) -> Result<String, String> {
-    log_updater("[UPDATER] Starting update installation...");
+fn get_affix_stats(app: tauri::AppHandle, season: Option<String>) -> Result<Vec<AffixStats>, String> {
+    log::debug!("get_affix_stats called, season: {:?}", season);
 
-    // Get bot settings to check beta channel preference (same as check_for_updates)
-    let settings = match get_bot_settings(app.clone()) {
-        Ok(s) => s,
-        Err(e) => {
-            log_updater(&format!("[UPDATER] Failed to get bot settings: {}, defaulting to stable channel", e));
-            BotSettings {
-                season_id: 0,
-                season_name: String::new(),
-                default_region: String::new(),
-                default_realm: String::new(),
-                active_dungeons: Vec::new(),
-                beta_channel: false,
-                updated_at: None,
-            }
-        }
-    };
+    let db_path = read_database_path(&app)?;
 
-    // Use different update endpoint based on beta channel setting
-    let update_endpoint = if settings.beta_channel {
-        "https://github.com/Drizzyt77/DaeBotJS/releases/latest/download/latest-beta.json"
-    } else {
-        "https://github.com/Drizzyt77/DaeBotJS/releases/latest/download/latest.json"
-    };
-    log_updater(&format!("[UPDATER] Using update endpoint: {}", update_endpoint));
+    if !db_path.exists() {
+        return Ok(Vec::new());
+    }
 
-    // Parse the endpoint URL
-    let update_url = match Url::parse(update_endpoint) {
-        Ok(url) => url,
-        Err(e) => {
-            return Err(format!("[UPDATER ERROR] Invalid update URL: {}", e));
-        }
-    };
+    let conn = open_read_database(&app, &db_path)?;
 
-    // Build updater with the correct endpoint
-    let updater_builder = app.updater_builder()
-        .endpoints(vec![update_url])
-        .map_err(|e| format!("[UPDATER ERROR] Failed to set endpoints: {}", e))?;
+    // Databases from before affixes were recorded have no column to group on
+    let has_affixes: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('mythic_runs') WHERE name='affixes'",
+        [],
+        |row| row.get(0)
+    ).map_err(|e| format!("Failed to check mythic_runs columns: {}", e))?;
+    if has_affixes == 0 {
+        return Ok(Vec::new());
+    }
 
-    match updater_builder.build() {
-        Ok(updater) => {
-            log_updater("[UPDATER] Updater builder created successfully");
+    let mut stmt = conn.prepare(
+        "SELECT affixes, mythic_level, is_completed_within_time
+         FROM mythic_runs
+         WHERE affixes IS NOT NULL
+           AND (?1 IS NULL OR season = ?1)"
+    ).map_err(|e| format!("Failed to prepare query: {}", e))?;
 
-            match updater.check().await {
-                Ok(update_result) => {
-                    if let Some(update) = update_result {
-                        log_updater(&format!("[UPDATER] Update found: version {}", update.version));
-                        log_updater(&format!("[UPDATER] Download URL: {}", update.download_url));
+    let rows = stmt.query_map([&season], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?, row.get::<_, i64>(2)? != 0))
+    }).map_err(|e| format!("Failed to query runs: {}", e))?;
 
-                        // Download and install the update
-                        match update.download_and_install(|chunk_length, content_length| {
-                            log_updater(&format!("[UPDATER] Download progress: {} of {:?} bytes", chunk_length, content_length));
-                        }, || {
-                            log_updater("[UPDATER] Download finished, starting installation...");
-                        }).await {
-                            Ok(_) => {
-                                log_updater("[UPDATER] Update installed successfully, restarting...");
-                                app.restart();
-                            }
-                            Err(e) => {
-                                let error_msg = format!("[UPDATER ERROR] Failed to install update: {:?}", e);
-                                log_updater(&error_msg);
-                                Err(error_msg)
-                            }
-                        }
-                    } else {
-                        let msg = "[UPDATER] No updates available";
-                        log_updater(msg);
-                        Err(msg.to_string())
-                    }
-                }
-                Err(e) => {
-                    let error_msg = format!("[UPDATER ERROR] Error checking for updates: {:?}", e);
-                    log_updater(&error_msg);
-                    Err(error_msg)
-                }
-            }
-        }
-        Err(e) => {
-            let error_msg = format!("[UPDATER ERROR] Error building updater: {:?}", e);
-            log_updater(&error_msg);
-            Err(error_msg)
+    // (runs, key level total, timed runs) per combination
+    let mut groups: std::collections::HashMap<Vec<String>, (i64, i64, i64)> = std::collections::HashMap::new();
+    for row in rows {
+        let (affixes_json, key_level, timed) = row.map_err(|e| format!("Failed to read run: {}", e))?;
+        let Ok(affixes) = serde_json::from_str::<serde_json::Value>(&affixes_json) else {
+            continue;
+        };
+        let names = affix_names(&affixes);
+        if names.is_empty() {
+            continue;
         }
-    }
-}
 
-#[derive(Clone, Serialize, Deserialize)]
-struct LogEntry {
-    timestamp: String,
-    level: String,
-    message: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    metadata: Option<serde_json::Value>,
-}
+        let entry = groups.entry(names).or_insert((0, 0, 0));
+        entry.0 += 1;
+        entry.1 += key_level;
+        entry.2 += timed as i64;
+    }
 
-#[derive(Clone, Serialize, Deserialize)]
-struct Stats {
-    #[serde(rename = "totalRuns")]
-    total_runs: i64,
-    #[serde(rename = "totalCharacters")]
-    total_characters: i64,
-    #[serde(rename = "lastSync")]
-    last_sync: Option<String>,
-    #[serde(rename = "databaseSize")]
-    database_size: u64,
-}
+    let mut stats: Vec<AffixStats> = groups.into_iter()
+        .map(|(affixes, (runs, key_total, timed))| AffixStats {
+            affixes,
+            runs,
+            average_key_level: key_total as f64 / runs as f64,
+            timed_rate: timed as f64 / runs as f64,
+        })
+        .collect();
+    stats.sort_by(|a, b| b.runs.cmp(&a.runs).then_with(|| a.affixes.cmp(&b.affixes)));
 
-#[derive(Clone, Serialize, Deserialize)]
-struct SyncHistoryEntry {
-    timestamp: String,
-    success: bool,
-    #[serde(rename = "syncType")]
-    sync_type: String,
-    #[serde(rename = "runsAdded", skip_serializing_if = "Option::is_none")]
-    runs_added: Option<i64>,
-    #[serde(rename = "charactersProcessed", skip_serializing_if = "Option::is_none")]
-    characters_processed: Option<i64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    duration: Option<i64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
+    Ok(stats)
 }
 
 #[derive(Clone, Serialize, Deserialize)]
-struct BotSettings {
-    #[serde(rename = "seasonId")]
-    season_id: i64,
-    #[serde(rename = "seasonName")]
-    season_name: String,
-    #[serde(rename = "defaultRegion")]
-    default_region: String,
-    #[serde(rename = "defaultRealm")]
-    default_realm: String,
-    #[serde(rename = "activeDungeons")]
-    active_dungeons: Vec<String>,
-    #[serde(rename = "betaChannel")]
-    beta_channel: bool,
-    #[serde(rename = "updatedAt", skip_serializing_if = "Option::is_none")]
-    updated_at: Option<i64>,
+struct DungeonStats {
+    dungeon: String,
+    runs: i64,
+    #[serde(rename = "bestKeyLevel")]
+    best_key_level: i64,
+    // Manual runs are stored with score 0 and left out; None when no run has a score
+    #[serde(rename = "averageScore")]
+    average_score: Option<f64>,
 }
 
 #[tauri::command]
-fn get_available_seasons(app: tauri::AppHandle) -> Result<Vec<String>, String> {
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
-    let db_path = app_dir.join("data").join("mythic_runs.db");
+fn get_dungeon_stats(app: tauri::AppHandle, season: Option<String>) -> Result<Vec<DungeonStats>, String> {
+    log::debug!("get_dungeon_stats called, season: {:?}", season);
+
+    let db_path = read_database_path(&app)?;
 
     if !db_path.exists() {
         return Ok(Vec::new());
     }
 
-    let conn = Connection::open(&db_path)
-        .map_err(|e| format!("Failed to open database: {}", e))?;
-
-    // Enable WAL mode
-    conn.pragma_update(None, "journal_mode", "WAL")
-        .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
+    let conn = open_read_database(&app, &db_path)?;
 
-    // Query distinct seasons ordered by most recent
     let mut stmt = conn.prepare(
-        "SELECT DISTINCT season FROM mythic_runs WHERE season IS NOT NULL ORDER BY season DESC"
+        "SELECT dungeon, COUNT(*), MAX(mythic_level), AVG(NULLIF(score, 0))
+         FROM mythic_runs
+         WHERE (?1 IS NULL OR season = ?1)
+         GROUP BY dungeon
+         ORDER BY COUNT(*) DESC, dungeon"
     ).map_err(|e| format!("Failed to prepare query: {}", e))?;
 
-    let seasons_iter = stmt.query_map([], |row| {
-        row.get(0)
-    }).map_err(|e| format!("Failed to query seasons: {}", e))?;
+    let stats = stmt.query_map([&season], |row| {
+        Ok(DungeonStats {
+            dungeon: row.get(0)?,
+            runs: row.get(1)?,
+            best_key_level: row.get(2)?,
+            average_score: row.get(3)?,
+        })
+    })
+    .map_err(|e| format!("Failed to query dungeon stats: {}", e))?
+    .collect::<Result<Vec<_>, _>>()
+    .map_err(|e| format!("Failed to read dungeon stats: {}", e))?;
 
-    let mut seasons = Vec::new();
-    for season in seasons_iter {
-        seasons.push(season.map_err(|e| format!("Failed to read season: {}", e))?);
+    Ok(stats)
+}
+
+#[tauri::command]
+fn get_runs_timeline(
+    app: tauri::AppHandle,
+    start_timestamp: i64,
+    end_timestamp: i64,
+    season: Option<String>,
+) -> Result<Vec<TimelineBucket>, String> {
+    log::debug!("get_runs_timeline called for {}..{}, season: {:?}", start_timestamp, end_timestamp, season);
+
+    if end_timestamp < start_timestamp {
+        return Err("End of range is before the start".to_string());
     }
 
-    Ok(seasons)
+    // Buckets are UTC days; timestamps are milliseconds like completed_timestamp
+    let first_day = start_timestamp.div_euclid(MS_PER_DAY);
+    let last_day = end_timestamp.div_euclid(MS_PER_DAY);
+    if last_day - first_day >= MAX_TIMELINE_DAYS {
+        return Err(format!("Range is too large (max {} days)", MAX_TIMELINE_DAYS));
+    }
+
+    let db_path = read_database_path(&app)?;
+
+    let mut per_day = std::collections::HashMap::new();
+
+    if db_path.exists() {
+        let conn = open_read_database(&app, &db_path)?;
+
+        let mut stmt = conn.prepare(
+            "SELECT completed_timestamp / ?4 AS day, COUNT(*), MAX(mythic_level)
+             FROM mythic_runs
+             WHERE completed_timestamp >= ?1
+               AND completed_timestamp <= ?2
+               AND (?3 IS NULL OR season = ?3)
+             GROUP BY day"
+        ).map_err(|e| format!("Failed to prepare query: {}", e))?;
+
+        let rows = stmt.query_map(
+            (start_timestamp, end_timestamp, &season, MS_PER_DAY),
+            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?, row.get::<_, Option<i64>>(2)?))
+        ).map_err(|e| format!("Failed to query runs timeline: {}", e))?;
+
+        for row in rows {
+            let (day, count, best_key) = row.map_err(|e| format!("Failed to read timeline row: {}", e))?;
+            per_day.insert(day, (count, best_key));
+        }
+    }
+
+    // Emit every day in the range so the chart has a continuous x-axis
+    let buckets = (first_day..=last_day)
+        .map(|day| {
+            let day_start = day * MS_PER_DAY;
+            let (run_count, best_key) = per_day.get(&day).copied().unwrap_or((0, None));
+            TimelineBucket {
+                date: DateTime::from_timestamp_millis(day_start)
+                    .map(|dt| dt.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default(),
+                day_start,
+                run_count,
+                best_key,
+            }
+        })
+        .collect();
+
+    Ok(buckets)
 }
 
 #[tauri::command]
-fn get_bot_settings(app: tauri::AppHandle) -> Result<BotSettings, String> {
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+fn get_database_schema(app: tauri::AppHandle) -> Result<Vec<TableSchema>, String> {
+    log::debug!("get_database_schema called");
+
+    let app_dir = app_data_dir(&app)?;
     let db_path = app_dir.join("data").join("mythic_runs.db");
 
     if !db_path.exists() {
@@ -1478,510 +7199,536 @@ fn get_bot_settings(app: tauri::AppHandle) -> Result<BotSettings, String> {
     let conn = Connection::open(&db_path)
         .map_err(|e| format!("Failed to open database: {}", e))?;
 
-    // Enable WAL mode
+    // Enable WAL mode to read from the WAL file
     conn.pragma_update(None, "journal_mode", "WAL")
         .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
 
-    // Query bot settings
-    let settings = conn.query_row(
-        "SELECT current_season_id, current_season_name, default_region, default_realm, active_dungeons, beta_channel, updated_at
-         FROM bot_settings WHERE id = 1",
-        [],
-        |row| {
-            let dungeons_json: String = row.get(4)?;
-            let dungeons: Vec<String> = serde_json::from_str(&dungeons_json).unwrap_or_default();
-            let beta_channel_int: i64 = row.get(5)?;
+    // List user tables (skip SQLite internals like sqlite_sequence)
+    let mut stmt = conn.prepare(
+        "SELECT name FROM sqlite_master WHERE type='table' AND name NOT LIKE 'sqlite_%' ORDER BY name"
+    ).map_err(|e| format!("Failed to prepare query: {}", e))?;
 
-            Ok(BotSettings {
-                season_id: row.get(0)?,
-                season_name: row.get(1)?,
-                default_region: row.get(2)?,
-                default_realm: row.get(3)?,
-                active_dungeons: dungeons,
-                beta_channel: beta_channel_int != 0,
-                updated_at: Some(row.get(6)?),
+    let table_names: Vec<String> = stmt.query_map([], |row| row.get(0))
+        .map_err(|e| format!("Failed to query tables: {}", e))?
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Failed to read table name: {}", e))?;
+
+    let mut column_stmt = conn.prepare(
+        "SELECT name, type, \"notnull\", pk FROM pragma_table_info(?1) ORDER BY cid"
+    ).map_err(|e| format!("Failed to prepare column query: {}", e))?;
+
+    let mut schema = Vec::new();
+    for table_name in table_names {
+        let columns: Vec<ColumnInfo> = column_stmt.query_map([&table_name], |row| {
+            Ok(ColumnInfo {
+                name: row.get(0)?,
+                column_type: row.get(1)?,
+                not_null: row.get::<_, i64>(2)? != 0,
+                primary_key: row.get::<_, i64>(3)? != 0,
             })
-        }
-    ).map_err(|e| format!("Failed to query bot settings: {}", e))?;
+        })
+        .map_err(|e| format!("Failed to query columns for {}: {}", table_name, e))?
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Failed to read column for {}: {}", table_name, e))?;
 
-    Ok(settings)
+        schema.push(TableSchema {
+            name: table_name,
+            columns,
+        });
+    }
+
+    Ok(schema)
 }
 
+// Tables the bot creates in mythic_runs.db; any of these missing means the
+// file is from somewhere else or was damaged
+const EXPECTED_DATABASE_TABLES: [&str; 4] = ["mythic_runs", "token_prices", "sync_history", "bot_settings"];
+
+// "ok" when SQLite finds no corruption and every expected table is present,
+// otherwise one problem per line
 #[tauri::command]
-fn update_bot_settings(app: tauri::AppHandle, settings: BotSettings) -> Result<(), String> {
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+fn check_database_integrity(app: tauri::AppHandle) -> Result<String, String> {
+    let app_dir = app_data_dir(&app)?;
     let db_path = app_dir.join("data").join("mythic_runs.db");
 
     if !db_path.exists() {
         return Err("Database not found".to_string());
     }
 
-    let conn = Connection::open(&db_path)
+    let conn = Connection::open_with_flags(&db_path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
         .map_err(|e| format!("Failed to open database: {}", e))?;
+    conn.busy_timeout(std::time::Duration::from_secs(5))
+        .map_err(|e| format!("Failed to set busy timeout: {}", e))?;
+
+    let mut problems: Vec<String> = conn
+        .prepare("PRAGMA integrity_check")
+        .and_then(|mut stmt| {
+            stmt.query_map([], |row| row.get(0))?
+                .collect::<Result<Vec<String>, _>>()
+        })
+        .map_err(|e| format!("Failed to run integrity check: {}", e))?
+        .into_iter()
+        .filter(|line| line != "ok")
+        .collect();
 
-    // Enable WAL mode
-    conn.pragma_update(None, "journal_mode", "WAL")
-        .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
+    for table in EXPECTED_DATABASE_TABLES {
+        if !table_exists(&conn, table)? {
+            problems.push(format!("Missing table: {}", table));
+        }
+    }
 
-    // Validate season name format
-    if !settings.season_name.starts_with("season-") {
-        return Err("Season name must start with 'season-' (e.g., season-mid-1)".to_string());
+    if problems.is_empty() {
+        log::info!("Database integrity check passed");
+        return Ok("ok".to_string());
     }
 
-    // Serialize dungeons to JSON
-    let dungeons_json = serde_json::to_string(&settings.active_dungeons)
-        .map_err(|e| format!("Failed to serialize dungeons: {}", e))?;
+    log::warn!("Database integrity check found {} problem(s)", problems.len());
+    Ok(problems.join("\n"))
+}
 
-    // Update bot settings
-    conn.execute(
-        "UPDATE bot_settings
-         SET current_season_id = ?1,
-             current_season_name = ?2,
-             default_region = ?3,
-             default_realm = ?4,
-             active_dungeons = ?5,
-             beta_channel = ?6,
-             updated_at = ?7
-         WHERE id = 1",
-        (
-            settings.season_id,
-            &settings.season_name,
-            &settings.default_region,
-            &settings.default_realm,
-            &dungeons_json,
-            settings.beta_channel as i64,
-            chrono::Utc::now().timestamp_millis(),
-        ),
-    ).map_err(|e| format!("Failed to update bot settings: {}", e))?;
+#[derive(Serialize)]
+struct DatabaseOptimizeResult {
+    #[serde(rename = "sizeBefore")]
+    size_before: u64,
+    #[serde(rename = "sizeAfter")]
+    size_after: u64,
+}
 
-    Ok(())
+// Main file plus its WAL, since that's what the database costs on disk
+fn database_disk_size(db_path: &Path) -> u64 {
+    let mut wal_path = db_path.as_os_str().to_owned();
+    wal_path.push("-wal");
+    [db_path, Path::new(&wal_path)]
+        .iter()
+        .filter_map(|path| fs::metadata(path).ok())
+        .map(|metadata| metadata.len())
+        .sum()
 }
 
+// Rebuilds the database file to reclaim space left by deletes and imports
 #[tauri::command]
-fn get_startup_error(app: tauri::AppHandle) -> Result<Option<String>, String> {
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+fn optimize_database(app: tauri::AppHandle) -> Result<DatabaseOptimizeResult, String> {
+    {
+        let state = app.state::<AppState>();
+        let bot = state.bot.lock().unwrap();
+        // A --start-bot bot or one from an earlier session is only in bot.pid
+        if bot.process.is_some() || running_bot_pid(&app).is_some() {
+            return Err("Stop the bot before optimizing the database".to_string());
+        }
+    }
 
-    let error_path = app_dir.join("startup-error.txt");
+    let app_dir = app_data_dir(&app)?;
+    let db_path = app_dir.join("data").join("mythic_runs.db");
 
-    if !error_path.exists() {
-        return Ok(None);
+    if !db_path.exists() {
+        return Err("Database not found".to_string());
     }
 
-    match fs::read_to_string(&error_path) {
-        Ok(content) => {
-            // Delete the error file after reading it
-            let _ = fs::remove_file(&error_path);
-            Ok(Some(content))
-        }
-        Err(e) => Err(format!("Failed to read startup error: {}", e))
-    }
+    let size_before = database_disk_size(&db_path);
+
+    let conn = Connection::open(&db_path)
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    conn.busy_timeout(std::time::Duration::from_secs(5))
+        .map_err(|e| format!("Failed to set busy timeout: {}", e))?;
+    conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))
+        .map_err(|e| format!("Failed to checkpoint database: {}", e))?;
+    conn.execute_batch("VACUUM")
+        .map_err(|e| format!("Failed to vacuum database: {}", e))?;
+    conn.execute_batch("PRAGMA optimize")
+        .map_err(|e| format!("Failed to optimize database: {}", e))?;
+    // VACUUM goes through the WAL too; fold it back so the size is accurate
+    conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))
+        .map_err(|e| format!("Failed to checkpoint database: {}", e))?;
+    drop(conn);
+
+    let size_after = database_disk_size(&db_path);
+    log::info!("Optimized database: {} -> {} bytes", size_before, size_after);
+
+    Ok(DatabaseOptimizeResult {
+        size_before,
+        size_after,
+    })
 }
 
 #[tauri::command]
-fn get_logs(app: tauri::AppHandle, limit: Option<usize>) -> Result<Vec<LogEntry>, String> {
-    let limit = limit.unwrap_or(100);
+fn get_sync_history(app: tauri::AppHandle, limit: Option<usize>) -> Result<Vec<SyncHistoryEntry>, String> {
+    log::debug!("get_sync_history called with limit: {:?}", limit);
 
-    // Get app data directory
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
-    let logs_dir = app_dir.join("logs");
+    let db_path = read_database_path(&app)?;
 
-    // Read current log file path from marker
-    let marker_path = logs_dir.join("current.log");
-    let log_file = if marker_path.exists() {
-        match fs::read_to_string(&marker_path) {
-            Ok(path) => PathBuf::from(path.trim()),
-            Err(_) => {
-                // Fallback: find most recent log file
-                get_most_recent_log_file(&logs_dir)?
-            }
-        }
-    } else {
-        // Fallback: find most recent log file
-        get_most_recent_log_file(&logs_dir)?
-    };
+    log::debug!("Looking for database: {:?}", db_path);
 
-    if !log_file.exists() {
+    if !db_path.exists() {
         return Ok(Vec::new());
     }
 
-    // Use a more efficient approach: read file from end backwards
-    let file = fs::File::open(&log_file)
-        .map_err(|e| format!("Failed to open log file: {}", e))?;
-
-    let metadata = file.metadata()
-        .map_err(|e| format!("Failed to get file metadata: {}", e))?;
-    let file_size = metadata.len();
-
-    // If file is small, just read it all
-    if file_size < 1_000_000 {  // Less than 1MB
-        let reader = BufReader::new(file);
-        let mut logs = Vec::new();
+    let conn = open_read_database(&app, &db_path)?;
 
-        for line in reader.lines() {
-            if let Ok(line) = line {
-                if let Ok(json) = serde_json::from_str::<serde_json::Value>(&line) {
-                    logs.push(parse_log_entry(json));
-                }
-            }
+    if is_read_copy(&app, &db_path) {
+        // The read-only copy can't be given a table; it just has no history yet
+        if !table_exists(&conn, "sync_history")? {
+            return Ok(Vec::new());
         }
-
-        // Return last N entries
-        let start = if logs.len() > limit { logs.len() - limit } else { 0 };
-        return Ok(logs[start..].to_vec());
+    } else {
+        // Create sync_history table if it doesn't exist (must match Node.js schema)
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS sync_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                timestamp INTEGER NOT NULL,
+                sync_type TEXT NOT NULL DEFAULT 'auto',
+                runs_added INTEGER NOT NULL DEFAULT 0,
+                characters_processed INTEGER NOT NULL DEFAULT 0,
+                duration_ms INTEGER,
+                success INTEGER NOT NULL DEFAULT 1,
+                error_message TEXT
+            )",
+            [],
+        ).map_err(|e| format!("Failed to create sync_history table: {}", e))?;
     }
 
-    // For large files, read backwards from end to get most recent logs efficiently
-    // This prevents reading the entire file when we only need the last few lines
-    use std::io::{Seek, SeekFrom, Read};
-    let mut file = fs::File::open(&log_file)
-        .map_err(|e| format!("Failed to open log file: {}", e))?;
+    // Explicit limit wins, then the user's configured default, then 4
+    let limit = limit
+        .or_else(|| get_settings(app.clone()).ok().and_then(|s| s.default_sync_history_limit))
+        .unwrap_or(4);
 
-    // Read last 500KB (should contain way more than limit lines)
-    let read_size = std::cmp::min(500_000, file_size);
-    let seek_pos = file_size.saturating_sub(read_size);
+    // Query sync history
+    let mut stmt = conn.prepare(
+        "SELECT timestamp, success, sync_type, runs_added, characters_processed, duration_ms, error_message
+         FROM sync_history
+         ORDER BY timestamp DESC
+         LIMIT ?1"
+    ).map_err(|e| format!("Failed to prepare query: {}", e))?;
 
-    file.seek(SeekFrom::Start(seek_pos))
-        .map_err(|e| format!("Failed to seek in log file: {}", e))?;
+    let history_iter = stmt.query_map([limit], |row| {
+        // Convert INTEGER timestamp (milliseconds) to ISO 8601 string
+        let timestamp_ms: i64 = row.get(0)?;
+        let timestamp_str = timestamp_ms_to_rfc3339(timestamp_ms);
 
-    let mut buffer = String::new();
-    file.read_to_string(&mut buffer)
-        .map_err(|e| format!("Failed to read log file: {}", e))?;
+        Ok(SyncHistoryEntry {
+            timestamp: timestamp_str,
+            success: row.get::<_, i64>(1)? != 0,
+            sync_type: row.get(2)?,
+            runs_added: row.get(3)?,
+            characters_processed: row.get(4)?,
+            duration: row.get(5)?,
+            error: row.get(6)?,
+        })
+    }).map_err(|e| format!("Failed to query sync history: {}", e))?;
 
-    // Split into lines and parse
-    let mut logs = Vec::new();
-    for line in buffer.lines() {
-        if let Ok(json) = serde_json::from_str::<serde_json::Value>(line) {
-            logs.push(parse_log_entry(json));
-        }
+    let mut history = Vec::new();
+    for entry in history_iter {
+        history.push(entry.map_err(|e| format!("Failed to read history entry: {}", e))?);
     }
 
-    // Return last N entries
-    let start = if logs.len() > limit { logs.len() - limit } else { 0 };
-    Ok(logs[start..].to_vec())
+    Ok(history)
 }
 
-// Helper function to parse a log entry
-fn parse_log_entry(json: serde_json::Value) -> LogEntry {
-    let timestamp = json["timestamp"].as_str().unwrap_or("").to_string();
-    let level = json["level"].as_str().unwrap_or("INFO").to_string();
-    let message = json["message"].as_str().unwrap_or("").to_string();
-
-    // Collect all other fields as metadata
-    let mut metadata = serde_json::Map::new();
-    if let Some(obj) = json.as_object() {
-        for (key, value) in obj {
-            if key != "timestamp" && key != "level" && key != "message" {
-                metadata.insert(key.clone(), value.clone());
-            }
-        }
-    }
+// Queries slower than this get a note about missing indexes in benchmark_database
+const SLOW_QUERY_MS: f64 = 200.0;
 
-    LogEntry {
-        timestamp,
-        level,
-        message,
-        metadata: if metadata.is_empty() {
-            None
-        } else {
-            Some(serde_json::Value::Object(metadata))
-        },
-    }
+#[derive(Clone, Serialize, Deserialize)]
+struct QueryBenchmark {
+    name: String,
+    #[serde(rename = "durationMs")]
+    duration_ms: f64,
+    #[serde(rename = "rowsReturned")]
+    rows_returned: usize,
+    #[serde(rename = "tableRows")]
+    table_rows: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
 }
 
-// Helper function to find most recent log file
-fn get_most_recent_log_file(logs_dir: &PathBuf) -> Result<PathBuf, String> {
-    if !logs_dir.exists() {
-        return Err("Logs directory does not exist".to_string());
-    }
-
-    let mut log_files: Vec<_> = fs::read_dir(logs_dir)
-        .map_err(|e| format!("Failed to read logs directory: {}", e))?
-        .filter_map(|entry| entry.ok())
-        .filter(|entry| {
-            entry.path().extension().and_then(|s| s.to_str()) == Some("log")
-                && entry.path().file_name().and_then(|s| s.to_str())
-                    .map(|name| name.starts_with("daebot-"))
-                    .unwrap_or(false)
-        })
-        .collect();
+#[derive(Clone, Serialize, Deserialize)]
+struct DatabaseBenchmark {
+    queries: Vec<QueryBenchmark>,
+    #[serde(rename = "missingIndexes")]
+    missing_indexes: Vec<String>,
+}
 
-    if log_files.is_empty() {
-        return Err("No log files found".to_string());
-    }
+// Columns that lead at least one index on the table, i.e. can be used for lookups
+fn indexed_columns(conn: &Connection, table: &str) -> Result<Vec<String>, String> {
+    let mut stmt = conn.prepare(
+        "SELECT ii.name FROM pragma_index_list(?1) AS il, pragma_index_info(il.name) AS ii WHERE ii.seqno = 0"
+    ).map_err(|e| format!("Failed to prepare index query: {}", e))?;
 
-    // Sort by modification time, most recent first
-    log_files.sort_by_key(|entry| {
-        entry.metadata().ok()
-            .and_then(|m| m.modified().ok())
-            .map(|t| std::cmp::Reverse(t))
-    });
+    let columns = stmt.query_map([table], |row| row.get(0))
+        .map_err(|e| format!("Failed to query indexes: {}", e))?
+        .collect::<Result<Vec<String>, _>>()
+        .map_err(|e| format!("Failed to read index: {}", e))?;
 
-    Ok(log_files[0].path())
+    Ok(columns)
 }
 
-#[tauri::command]
-fn get_last_sync_time(app: tauri::AppHandle) -> Result<Option<String>, String> {
-    println!("get_last_sync_time called");
-
-    // Get app data directory
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
-    let db_path = app_dir.join("data").join("mythic_runs.db");
-
-    println!("Database path: {:?}", db_path);
+// Indexes the dashboard queries rely on. The bot's schema creates these, but
+// older or imported databases may not have them; names match the bot's.
+const MYTHIC_RUNS_INDEXES: [(&str, &str); 3] = [
+    ("idx_runs_season", "season"),
+    ("idx_runs_character", "character_id"),
+    ("idx_runs_timestamp", "completed_timestamp"),
+];
+
+fn table_exists(conn: &Connection, table: &str) -> Result<bool, String> {
+    conn.query_row(
+        "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type='table' AND name=?1",
+        [table],
+        |row| row.get(0)
+    ).map_err(|e| format!("Failed to check for {} table: {}", table, e))
+}
 
-    if !db_path.exists() {
-        println!("Database does not exist yet");
-        return Ok(None);
+// Current sync_history columns, each with the expression that fills it when
+// rebuilding from an older table. Very old databases used duration/error.
+const SYNC_HISTORY_COLUMNS: [(&str, &str, &str); 8] = [
+    ("id", "INTEGER PRIMARY KEY AUTOINCREMENT", "NULL"),
+    ("timestamp", "INTEGER NOT NULL", "0"),
+    ("sync_type", "TEXT NOT NULL DEFAULT 'auto'", "'auto'"),
+    ("runs_added", "INTEGER NOT NULL DEFAULT 0", "0"),
+    ("characters_processed", "INTEGER NOT NULL DEFAULT 0", "0"),
+    ("duration_ms", "INTEGER", "NULL"),
+    ("success", "INTEGER NOT NULL DEFAULT 1", "1"),
+    ("error_message", "TEXT", "NULL"),
+];
+
+fn legacy_sync_history_column(column: &str) -> Option<&'static str> {
+    match column {
+        "duration_ms" => Some("duration"),
+        "error_message" => Some("error"),
+        _ => None,
     }
+}
 
-    let conn = Connection::open(&db_path)
-        .map_err(|e| format!("Failed to open database: {}", e))?;
-
-    // Enable WAL mode to read from the WAL file (same as Node.js bot)
-    conn.pragma_update(None, "journal_mode", "WAL")
-        .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
-    println!("WAL mode enabled for reading");
+fn table_columns(conn: &Connection, table: &str) -> Result<Vec<String>, String> {
+    conn.prepare("SELECT name FROM pragma_table_info(?1)")
+        .and_then(|mut stmt| {
+            stmt.query_map([table], |row| row.get(0))?
+                .collect::<Result<Vec<String>, _>>()
+        })
+        .map_err(|e| format!("Failed to read {} columns: {}", table, e))
+}
 
-    // Migrate sync_history table if it exists with old schema
-    let table_exists: Result<i64, rusqlite::Error> = conn.query_row(
-        "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='sync_history'",
-        [],
-        |row| row.get(0)
-    );
+// Expression filling `column` when copying from a sync_history table that has
+// `existing` columns, falling back to the legacy name or the default
+fn sync_history_source(column: &str, fallback: &str, existing: &[String]) -> String {
+    let source = if existing.iter().any(|c| c == column) {
+        Some(column)
+    } else {
+        legacy_sync_history_column(column).filter(|legacy| existing.iter().any(|c| c == legacy))
+    };
+    match source {
+        Some(source) => format!("COALESCE({}, {})", source, fallback),
+        None => fallback.to_string(),
+    }
+}
 
-    if let Ok(1) = table_exists {
-        // Check if sync_type column exists
-        let has_sync_type: Result<i64, rusqlite::Error> = conn.query_row(
-            "SELECT COUNT(*) FROM pragma_table_info('sync_history') WHERE name='sync_type'",
-            [],
-            |row| row.get(0)
+// Brings sync_history to the current schema, whatever state an older app or an
+// interrupted migration left it in. Safe to run repeatedly.
+fn migrate_sync_history(conn: &mut Connection) -> Result<Vec<String>, String> {
+    let mut actions = Vec::new();
+
+    let has_current = table_exists(conn, "sync_history")?;
+    let has_old = table_exists(conn, "sync_history_old")?;
+
+    // An earlier migration died between renaming the table and dropping the copy
+    if has_old && !has_current {
+        conn.execute("ALTER TABLE sync_history_old RENAME TO sync_history", [])
+            .map_err(|e| format!("Failed to restore sync_history_old: {}", e))?;
+        actions.push("Restored sync_history from an interrupted migration".to_string());
+    } else if has_old {
+        // A crash between creating the new table and copying into it leaves the
+        // history only in the old one, so merge it back before dropping it
+        let old_columns = table_columns(conn, "sync_history_old")?;
+        let current_columns = table_columns(conn, "sync_history")?;
+        // Leave id out so SQLite assigns fresh ones: the bot may have written new
+        // rows since, starting again at 1. Rows already copied over are matched on
+        // (timestamp, sync_type) instead.
+        let (names, sources): (Vec<&str>, Vec<String>) = SYNC_HISTORY_COLUMNS.iter()
+            .filter(|(name, _, _)| *name != "id" && current_columns.iter().any(|c| c == name))
+            .map(|(name, _, fallback)| (*name, format!("{} AS {}", sync_history_source(name, fallback, &old_columns), name)))
+            .unzip();
+        let missing = format!(
+            "FROM (SELECT {} FROM sync_history_old) AS o WHERE NOT EXISTS \
+             (SELECT 1 FROM sync_history s WHERE s.timestamp = o.timestamp AND s.sync_type = o.sync_type)",
+            sources.join(", ")
         );
 
-        if let Ok(0) = has_sync_type {
-            println!("Migrating sync_history table to add missing columns...");
-            // Add missing columns from old schema to new schema
-            let _ = conn.execute("ALTER TABLE sync_history ADD COLUMN sync_type TEXT NOT NULL DEFAULT 'auto'", []);
-            let _ = conn.execute("ALTER TABLE sync_history ADD COLUMN duration_ms INTEGER", []);
-
-            // Rename columns if needed - SQLite doesn't support RENAME COLUMN in older versions
-            // So we'll check if we need to migrate data
-            let has_error_message: Result<i64, rusqlite::Error> = conn.query_row(
-                "SELECT COUNT(*) FROM pragma_table_info('sync_history') WHERE name='error_message'",
-                [],
-                |row| row.get(0)
-            );
-
-            if let Ok(0) = has_error_message {
-                // Old schema detected - need to recreate table
-                println!("Old schema detected - recreating sync_history table with new schema...");
-                conn.execute("ALTER TABLE sync_history RENAME TO sync_history_old", [])
-                    .map_err(|e| format!("Failed to rename old table: {}", e))?;
-
-                conn.execute(
-                    "CREATE TABLE sync_history (
-                        id INTEGER PRIMARY KEY AUTOINCREMENT,
-                        timestamp INTEGER NOT NULL,
-                        sync_type TEXT NOT NULL DEFAULT 'auto',
-                        runs_added INTEGER NOT NULL DEFAULT 0,
-                        characters_processed INTEGER NOT NULL DEFAULT 0,
-                        duration_ms INTEGER,
-                        success INTEGER NOT NULL DEFAULT 1,
-                        error_message TEXT
-                    )",
-                    [],
-                ).map_err(|e| format!("Failed to create new table: {}", e))?;
-
-                // Copy data from old table to new table
-                conn.execute(
-                    "INSERT INTO sync_history (id, timestamp, success, runs_added, characters_processed, duration_ms, error_message)
-                     SELECT id, timestamp, success, COALESCE(runs_added, 0), COALESCE(characters_processed, 0), duration, error
-                     FROM sync_history_old",
-                    [],
-                ).map_err(|e| format!("Failed to migrate data: {}", e))?;
-
-                // Drop old table
-                conn.execute("DROP TABLE sync_history_old", [])
-                    .map_err(|e| format!("Failed to drop old table: {}", e))?;
-
-                println!("Migration completed successfully!");
-            }
+        let tx = conn.transaction()
+            .map_err(|e| format!("Failed to start migration: {}", e))?;
+        let merged = tx.execute(
+            &format!("INSERT INTO sync_history ({}) SELECT {} {}", names.join(", "), names.join(", "), missing),
+            [],
+        ).map_err(|e| format!("Failed to merge leftover sync_history_old: {}", e))?;
+        let unmerged: i64 = tx.query_row(&format!("SELECT COUNT(*) {}", missing), [], |row| row.get(0))
+            .map_err(|e| format!("Failed to verify sync_history_old merge: {}", e))?;
+        if unmerged > 0 {
+            return Err(format!("{} sync_history_old rows could not be merged; leaving the table in place", unmerged));
         }
+        tx.execute("DROP TABLE sync_history_old", [])
+            .map_err(|e| format!("Failed to drop leftover sync_history_old: {}", e))?;
+        tx.commit()
+            .map_err(|e| format!("Failed to commit migration: {}", e))?;
+        actions.push(format!("Merged {} rows from leftover sync_history_old table and dropped it", merged));
+    } else if !has_current {
+        // The bot creates it on first start
+        return Ok(actions);
     }
 
-    // Check if sync_history table exists
-    let table_exists: Result<i64, rusqlite::Error> = conn.query_row(
-        "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='sync_history'",
-        [],
-        |row| row.get(0)
-    );
+    let existing = table_columns(conn, "sync_history")?;
 
-    match table_exists {
-        Ok(count) if count == 0 => {
-            println!("sync_history table does not exist yet - waiting for migration");
-            return Ok(None);
-        }
-        Err(e) => {
-            println!("Error checking for table existence: {}", e);
-            return Err(format!("Failed to check table existence: {}", e));
-        }
-        _ => {}
+    if SYNC_HISTORY_COLUMNS.iter().all(|(name, _, _)| existing.iter().any(|c| c == name)) {
+        return Ok(actions);
     }
 
-    // First, check what's actually in the table for debugging
-    let total_count: Result<i64, rusqlite::Error> = conn.query_row(
-        "SELECT COUNT(*) FROM sync_history",
-        [],
-        |row| row.get(0)
-    );
-    println!("Total sync_history entries: {:?}", total_count);
+    log::info!("Rebuilding sync_history with the current schema (had: {})", existing.join(", "));
 
-    let success_count: Result<i64, rusqlite::Error> = conn.query_row(
-        "SELECT COUNT(*) FROM sync_history WHERE success = 1",
+    let definitions: Vec<String> = SYNC_HISTORY_COLUMNS.iter()
+        .map(|(name, definition, _)| format!("{} {}", name, definition))
+        .collect();
+    let names: Vec<&str> = SYNC_HISTORY_COLUMNS.iter().map(|(name, _, _)| *name).collect();
+    let sources: Vec<String> = SYNC_HISTORY_COLUMNS.iter()
+        .map(|(name, _, fallback)| sync_history_source(name, fallback, &existing))
+        .collect();
+
+    let tx = conn.transaction()
+        .map_err(|e| format!("Failed to start migration: {}", e))?;
+    tx.execute("ALTER TABLE sync_history RENAME TO sync_history_old", [])
+        .map_err(|e| format!("Failed to rename old table: {}", e))?;
+    tx.execute(&format!("CREATE TABLE sync_history ({})", definitions.join(", ")), [])
+        .map_err(|e| format!("Failed to create new table: {}", e))?;
+    let copied = tx.execute(
+        &format!(
+            "INSERT INTO sync_history ({}) SELECT {} FROM sync_history_old",
+            names.join(", "),
+            sources.join(", ")
+        ),
         [],
-        |row| row.get(0)
-    );
-    println!("Successful sync entries: {:?}", success_count);
+    ).map_err(|e| format!("Failed to migrate data: {}", e))?;
+    tx.execute("DROP TABLE sync_history_old", [])
+        .map_err(|e| format!("Failed to drop old table: {}", e))?;
+    tx.execute("CREATE INDEX IF NOT EXISTS idx_sync_history_timestamp ON sync_history(timestamp DESC)", [])
+        .map_err(|e| format!("Failed to create sync_history index: {}", e))?;
+    tx.commit()
+        .map_err(|e| format!("Failed to commit migration: {}", e))?;
+
+    actions.push(format!("Rebuilt sync_history with the current schema ({} rows kept)", copied));
+    Ok(actions)
+}
 
-    // Show all entries for debugging
-    let mut stmt = conn.prepare("SELECT id, timestamp, sync_type, success FROM sync_history ORDER BY timestamp DESC LIMIT 5")
-        .map_err(|e| format!("Failed to prepare debug query: {}", e))?;
-    let rows = stmt.query_map([], |row| {
-        Ok(format!("id={}, timestamp={}, sync_type={}, success={}",
-            row.get::<_, i64>(0).unwrap_or(-1),
-            row.get::<_, i64>(1).unwrap_or(-1),
-            row.get::<_, String>(2).unwrap_or_else(|_| "?".to_string()),
-            row.get::<_, i64>(3).unwrap_or(-1)
-        ))
-    });
-    println!("Recent sync_history entries:");
-    if let Ok(rows) = rows {
-        for row in rows {
-            if let Ok(row_str) = row {
-                println!("  {}", row_str);
-            }
-        }
+// Repairs tables that have drifted from the schema the app expects. Runs at
+// startup and after an import; returns a description of each change made.
+#[tauri::command]
+fn migrate_database(app: tauri::AppHandle) -> Result<Vec<String>, String> {
+    let app_dir = app_data_dir(&app)?;
+    let db_path = app_dir.join("data").join("mythic_runs.db");
+
+    if !db_path.exists() {
+        return Ok(Vec::new());
     }
 
-    // Query the last successful sync time from sync_history table
-    let result: Result<i64, rusqlite::Error> = conn.query_row(
-        "SELECT timestamp FROM sync_history WHERE success = 1 ORDER BY timestamp DESC LIMIT 1",
-        [],
-        |row| row.get(0)
-    );
+    let mut conn = Connection::open(&db_path)
+        .map_err(|e| format!("Failed to open database: {}", e))?;
 
-    match result {
-        Ok(timestamp) => {
-            println!("Found last sync timestamp: {}", timestamp);
-            // Convert millisecond timestamp to ISO 8601 string
-            let dt = DateTime::from_timestamp_millis(timestamp).unwrap_or_default();
-            let iso_time = dt.to_rfc3339();
-            println!("Converted to ISO 8601: {}", iso_time);
-            Ok(Some(iso_time))
-        }
-        Err(rusqlite::Error::QueryReturnedNoRows) => {
-            println!("No sync entries found with success=1");
-            Ok(None)
-        }
-        Err(e) => {
-            println!("Database query error: {}", e);
-            Err(format!("Database query failed: {}", e))
+    // Enable WAL mode
+    conn.pragma_update(None, "journal_mode", "WAL")
+        .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
+
+    // The bot may be mid-write; wait for it rather than failing right away
+    conn.busy_timeout(std::time::Duration::from_secs(5))
+        .map_err(|e| format!("Failed to set busy timeout: {}", e))?;
+
+    let mut actions = migrate_sync_history(&mut conn)?;
+
+    if table_exists(&conn, "bot_settings")? {
+        let before: i64 = conn.query_row("SELECT COUNT(*) FROM pragma_table_info('bot_settings')", [], |row| row.get(0))
+            .map_err(|e| format!("Failed to read bot_settings columns: {}", e))?;
+        ensure_bot_settings_columns(&conn)?;
+        let after: i64 = conn.query_row("SELECT COUNT(*) FROM pragma_table_info('bot_settings')", [], |row| row.get(0))
+            .map_err(|e| format!("Failed to read bot_settings columns: {}", e))?;
+        if after > before {
+            actions.push(format!("Added {} missing bot_settings column(s)", after - before));
         }
     }
-}
 
-#[tauri::command]
-fn get_stats(app: tauri::AppHandle, season: Option<String>) -> Result<Stats, String> {
-    println!("get_stats called with season: {:?}", season);
+    for action in &actions {
+        log::info!("Database migration: {}", action);
+    }
+    Ok(actions)
+}
 
-    // Get project root directory
-    let app_dir = app.path().app_data_dir()
-            .map_err(|e| format!("Failed to get app data dir: {}", e))?;
-    let db_path = app_dir.join("data").join("mythic_runs.db");
+// Database the read-only dashboard commands open: Settings.readDatabasePath
+// when set, otherwise the bot's live database
+fn read_database_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    match get_settings(app.clone()).ok().and_then(|settings| settings.read_database_path) {
+        Some(path) => Ok(PathBuf::from(path)),
+        None => Ok(app_data_dir(app)?.join("data").join("mythic_runs.db")),
+    }
+}
 
-    println!("Looking for database: {:?}", db_path);
+// True when db_path is the readDatabasePath copy rather than the live database
+fn is_read_copy(app: &tauri::AppHandle, db_path: &Path) -> bool {
+    app_data_dir(app)
+        .map(|dir| dir.join("data").join("mythic_runs.db") != db_path)
+        .unwrap_or(true)
+}
 
-    if !db_path.exists() {
-        return Ok(Stats {
-            total_runs: 0,
-            total_characters: 0,
-            last_sync: None,
-            database_size: 0,
-        });
+// Opens the database read_database_path chose. The copy is replaced on a
+// schedule, so it is opened read-only and never switched to WAL mode.
+fn open_read_database(app: &tauri::AppHandle, db_path: &Path) -> Result<Connection, String> {
+    if is_read_copy(app, db_path) {
+        return Connection::open_with_flags(db_path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(|e| format!("Failed to open database: {}", e));
     }
 
-    let conn = Connection::open(&db_path)
+    let conn = Connection::open(db_path)
         .map_err(|e| format!("Failed to open database: {}", e))?;
 
     // Enable WAL mode to read from the WAL file
     conn.pragma_update(None, "journal_mode", "WAL")
         .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
+    Ok(conn)
+}
 
-    // Build queries with optional season filter
-    let (runs_query, chars_query) = if let Some(ref s) = season {
-        (
-            format!("SELECT COUNT(*) FROM mythic_runs WHERE season = '{}'", s),
-            format!("SELECT COUNT(DISTINCT character_id) FROM mythic_runs WHERE season = '{}'", s)
-        )
-    } else {
-        (
-            "SELECT COUNT(*) FROM mythic_runs".to_string(),
-            "SELECT COUNT(DISTINCT character_id) FROM mythic_runs".to_string()
-        )
-    };
-
-    // Get total runs (filtered by season if specified)
-    let total_runs: i64 = conn.query_row(
-        &runs_query,
-        [],
-        |row| row.get(0)
-    ).unwrap_or(0);
+fn validate_read_database(path: &Path) -> Result<(), String> {
+    if !path.is_file() {
+        return Err(format!("Read database not found: '{}'", path.display()));
+    }
 
-    // Get total characters (filtered by season if specified)
-    let total_characters: i64 = conn.query_row(
-        &chars_query,
-        [],
-        |row| row.get(0)
-    ).unwrap_or(0);
+    let conn = Connection::open_with_flags(path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(|e| format!("Read database '{}' could not be opened: {}", path.display(), e))?;
+    for table in ["mythic_runs", "characters"] {
+        if !table_exists(&conn, table)? {
+            return Err(format!("Read database '{}' has no {} table", path.display(), table));
+        }
+    }
+    Ok(())
+}
 
-    // Get last sync time (most recent run completion)
-    let last_sync: Option<i64> = conn.query_row(
-        "SELECT MAX(completed_timestamp) FROM mythic_runs",
-        [],
-        |row| row.get(0)
-    ).ok().flatten();
+// Create any of MYTHIC_RUNS_INDEXES whose column isn't already indexed.
+// Returns the names of the indexes that were created.
+fn create_missing_indexes(conn: &Connection) -> Result<Vec<String>, String> {
+    if !table_exists(conn, "mythic_runs")? {
+        return Ok(Vec::new());
+    }
 
-    let last_sync_str = last_sync.map(|ts| {
-        let dt = DateTime::from_timestamp_millis(ts).unwrap_or_default();
-        dt.to_rfc3339()
-    });
+    let indexed = indexed_columns(conn, "mythic_runs")?;
+    let mut created = Vec::new();
+    for (name, column) in MYTHIC_RUNS_INDEXES {
+        if indexed.iter().any(|c| c == column) {
+            continue;
+        }
 
-    // Get database size
-    let metadata = fs::metadata(&db_path)
-        .map_err(|e| format!("Failed to get database size: {}", e))?;
-    let database_size = metadata.len();
+        conn.execute(&format!("CREATE INDEX IF NOT EXISTS {} ON mythic_runs({})", name, column), [])
+            .map_err(|e| format!("Failed to create index {}: {}", name, e))?;
+        created.push(name.to_string());
+    }
 
-    Ok(Stats {
-        total_runs,
-        total_characters,
-        last_sync: last_sync_str,
-        database_size,
-    })
+    Ok(created)
 }
 
 #[tauri::command]
-fn get_sync_history(app: tauri::AppHandle, limit: Option<usize>) -> Result<Vec<SyncHistoryEntry>, String> {
-    println!("get_sync_history called with limit: {:?}", limit);
-
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+fn ensure_indexes(app: tauri::AppHandle) -> Result<Vec<String>, String> {
+    let app_dir = app_data_dir(&app)?;
     let db_path = app_dir.join("data").join("mythic_runs.db");
 
-    println!("Looking for database: {:?}", db_path);
-
     if !db_path.exists() {
         return Ok(Vec::new());
     }
@@ -1989,66 +7736,210 @@ fn get_sync_history(app: tauri::AppHandle, limit: Option<usize>) -> Result<Vec<S
     let conn = Connection::open(&db_path)
         .map_err(|e| format!("Failed to open database: {}", e))?;
 
-    // Enable WAL mode to read from the WAL file
+    // Enable WAL mode
     conn.pragma_update(None, "journal_mode", "WAL")
         .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
 
-    // Create sync_history table if it doesn't exist (must match Node.js schema)
-    conn.execute(
-        "CREATE TABLE IF NOT EXISTS sync_history (
-            id INTEGER PRIMARY KEY AUTOINCREMENT,
-            timestamp INTEGER NOT NULL,
-            sync_type TEXT NOT NULL DEFAULT 'auto',
-            runs_added INTEGER NOT NULL DEFAULT 0,
-            characters_processed INTEGER NOT NULL DEFAULT 0,
-            duration_ms INTEGER,
-            success INTEGER NOT NULL DEFAULT 1,
-            error_message TEXT
-        )",
-        [],
-    ).map_err(|e| format!("Failed to create sync_history table: {}", e))?;
+    // The bot may be mid-write; wait for it rather than failing right away
+    conn.busy_timeout(std::time::Duration::from_secs(5))
+        .map_err(|e| format!("Failed to set busy timeout: {}", e))?;
 
-    let limit = limit.unwrap_or(4);
+    let created = create_missing_indexes(&conn)?;
+    if !created.is_empty() {
+        log::info!("Created mythic_runs indexes: {}", created.join(", "));
+    }
+    Ok(created)
+}
 
-    // Query sync history
-    let mut stmt = conn.prepare(
-        "SELECT timestamp, success, sync_type, runs_added, characters_processed, duration_ms, error_message
-         FROM sync_history
-         ORDER BY timestamp DESC
-         LIMIT ?1"
-    ).map_err(|e| format!("Failed to prepare query: {}", e))?;
+// Time the queries behind the dashboard to tell database slowness from UI slowness
+#[tauri::command]
+fn benchmark_database(app: tauri::AppHandle) -> Result<DatabaseBenchmark, String> {
+    // The timed commands read through read_database_path, so the row counts
+    // and index advice have to come from the same file
+    let db_path = read_database_path(&app)?;
 
-    let history_iter = stmt.query_map([limit], |row| {
-        // Convert INTEGER timestamp (milliseconds) to ISO 8601 string
-        let timestamp_ms: i64 = row.get(0)?;
-        let dt = DateTime::from_timestamp_millis(timestamp_ms).unwrap_or_default();
-        let timestamp_str = dt.to_rfc3339();
+    if !db_path.exists() {
+        return Err("Database not found".to_string());
+    }
 
-        Ok(SyncHistoryEntry {
-            timestamp: timestamp_str,
-            success: row.get::<_, i64>(1)? != 0,
-            sync_type: row.get(2)?,
-            runs_added: row.get(3)?,
-            characters_processed: row.get(4)?,
-            duration: row.get(5)?,
-            error: row.get(6)?,
+    let conn = open_read_database(&app, &db_path)?;
+
+    let count_rows = |table: &str| -> i64 {
+        conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| row.get(0))
+            .unwrap_or(0)
+    };
+    let run_rows = count_rows("mythic_runs");
+    let sync_rows = count_rows("sync_history");
+
+    let indexed = indexed_columns(&conn, "mythic_runs")?;
+    let missing_indexes: Vec<String> = ["season", "character_id"].iter()
+        .filter(|column| !indexed.iter().any(|c| c == *column))
+        .map(|column| format!("mythic_runs({})", column))
+        .collect();
+
+    let slow_note = |duration_ms: f64, columns: &[&str]| -> Option<String> {
+        if duration_ms < SLOW_QUERY_MS {
+            return None;
+        }
+        let missing: Vec<String> = columns.iter()
+            .map(|column| format!("mythic_runs({})", column))
+            .filter(|index| missing_indexes.contains(index))
+            .collect();
+        Some(if missing.is_empty() {
+            "Slow, but the relevant indexes exist".to_string()
+        } else {
+            format!("Slow; missing index on {}", missing.join(", "))
         })
-    }).map_err(|e| format!("Failed to query sync history: {}", e))?;
+    };
 
-    let mut history = Vec::new();
-    for entry in history_iter {
-        history.push(entry.map_err(|e| format!("Failed to read history entry: {}", e))?);
+    let time = |f: &dyn Fn() -> Result<usize, String>| -> Result<(f64, usize), String> {
+        let start = std::time::Instant::now();
+        let rows = f()?;
+        Ok((start.elapsed().as_secs_f64() * 1000.0, rows))
+    };
+
+    let mut queries = Vec::new();
+
+    let (duration_ms, rows_returned) = time(&|| get_available_seasons(app.clone()).map(|s| s.len()))?;
+    queries.push(QueryBenchmark {
+        name: "get_available_seasons".to_string(),
+        duration_ms,
+        rows_returned,
+        table_rows: run_rows,
+        note: slow_note(duration_ms, &["season"]),
+    });
+
+    let (duration_ms, rows_returned) = time(&|| get_stats(app.clone(), None, None).map(|_| 1))?;
+    queries.push(QueryBenchmark {
+        name: "get_stats".to_string(),
+        duration_ms,
+        rows_returned,
+        table_rows: run_rows,
+        note: slow_note(duration_ms, &["character_id"]),
+    });
+
+    // The season filter is what the dashboard usually runs with
+    let latest_season: Option<String> = conn.query_row(
+        "SELECT MAX(season) FROM mythic_runs",
+        [],
+        |row| row.get(0)
+    ).unwrap_or(None);
+    if let Some(season) = latest_season {
+        let (duration_ms, rows_returned) = time(&|| get_stats(app.clone(), Some(season.clone()), None).map(|_| 1))?;
+        queries.push(QueryBenchmark {
+            name: format!("get_stats ({})", season),
+            duration_ms,
+            rows_returned,
+            table_rows: run_rows,
+            note: slow_note(duration_ms, &["season", "character_id"]),
+        });
     }
 
-    Ok(history)
+    let (duration_ms, rows_returned) = time(&|| get_sync_history(app.clone(), Some(50)).map(|h| h.len()))?;
+    queries.push(QueryBenchmark {
+        name: "get_sync_history".to_string(),
+        duration_ms,
+        rows_returned,
+        table_rows: sync_rows,
+        note: None,
+    });
+
+    Ok(DatabaseBenchmark {
+        queries,
+        missing_indexes,
+    })
+}
+
+// Matches SYNC_INTERVAL in services/periodic-sync.js
+const BOT_AUTO_SYNC_INTERVAL_MS: i64 = 60 * 60 * 1000;
+
+// Auto syncs closer together than this can't come from a single bot
+const CONCURRENT_SYNC_GAP_MS: i64 = BOT_AUTO_SYNC_INTERVAL_MS / 2;
+
+// A restart legitimately produces one short gap, so require a few
+const CONCURRENT_SYNC_MIN_SHORT_GAPS: usize = 3;
+
+#[derive(Clone, Serialize)]
+struct ConcurrentBotReport {
+    suspected: bool,
+    #[serde(rename = "autoSyncCount")]
+    auto_sync_count: usize,
+    #[serde(rename = "expectedSyncCount")]
+    expected_sync_count: i64,
+    #[serde(rename = "shortGapCount")]
+    short_gap_count: usize,
+    #[serde(rename = "windowHours")]
+    window_hours: i64,
+    message: String,
+}
+
+// The bot doesn't record an instance id in sync_history, so this looks for
+// successful auto syncs arriving faster than one bot's schedule allows
+#[tauri::command]
+fn detect_concurrent_bots(app: tauri::AppHandle, window_hours: Option<i64>) -> Result<ConcurrentBotReport, String> {
+    let window_hours = window_hours.unwrap_or(24).clamp(1, 24 * 30);
+    let expected_sync_count = window_hours * 60 * 60 * 1000 / BOT_AUTO_SYNC_INTERVAL_MS;
+
+    let db_path = read_database_path(&app)?;
+
+    let mut timestamps: Vec<i64> = Vec::new();
+
+    if db_path.exists() {
+        let conn = open_read_database(&app, &db_path)?;
+
+        if table_exists(&conn, "sync_history")? {
+            let since = chrono::Utc::now().timestamp_millis() - window_hours * 60 * 60 * 1000;
+            let mut stmt = conn.prepare(
+                "SELECT timestamp FROM sync_history
+                 WHERE sync_type = 'auto' AND success = 1 AND timestamp >= ?1
+                 ORDER BY timestamp ASC"
+            ).map_err(|e| format!("Failed to prepare query: {}", e))?;
+
+            let rows = stmt.query_map([since], |row| row.get(0))
+                .map_err(|e| format!("Failed to query sync history: {}", e))?;
+            for row in rows {
+                timestamps.push(row.map_err(|e| format!("Failed to read sync history: {}", e))?);
+            }
+        }
+    }
+
+    let short_gap_count = timestamps.windows(2)
+        .filter(|pair| pair[1] - pair[0] < CONCURRENT_SYNC_GAP_MS)
+        .count();
+    let auto_sync_count = timestamps.len();
+
+    // Too many syncs overall, or several that are too close together
+    let too_many = auto_sync_count as i64 > expected_sync_count + expected_sync_count / 2 + 1;
+    let suspected = too_many || short_gap_count >= CONCURRENT_SYNC_MIN_SHORT_GAPS;
+
+    let message = if suspected {
+        format!(
+            "Found {} successful auto syncs in the last {} hour(s) (about {} expected), {} of them less than {} minutes apart. \
+             Another DaeBot instance may be running against the same database or Discord server.",
+            auto_sync_count, window_hours, expected_sync_count, short_gap_count, CONCURRENT_SYNC_GAP_MS / 60_000
+        )
+    } else {
+        format!(
+            "No sign of another bot instance: {} auto syncs in the last {} hour(s).",
+            auto_sync_count, window_hours
+        )
+    };
+
+    Ok(ConcurrentBotReport {
+        suspected,
+        auto_sync_count,
+        expected_sync_count,
+        short_gap_count,
+        window_hours,
+        message,
+    })
 }
 
 #[tauri::command]
 fn add_sync_history(app: tauri::AppHandle, entry: SyncHistoryEntry) -> Result<(), String> {
-    println!("add_sync_history called");
+    log::debug!("add_sync_history called");
 
-    let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    let app_dir = app_data_dir(&app)?;
 
     let data_dir = app_dir.join("data");
     fs::create_dir_all(&data_dir)
@@ -2101,143 +7992,145 @@ fn add_sync_history(app: tauri::AppHandle, entry: SyncHistoryEntry) -> Result<()
         ),
     ).map_err(|e| format!("Failed to insert sync history: {}", e))?;
 
-    println!("Sync history entry added successfully");
+    log::info!("Sync history entry added successfully");
     Ok(())
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+  // `--start-bot [--wait]` turns the app into a CLI launcher for automation
+  let headless_start = std::env::args().any(|arg| arg == "--start-bot");
+
   tauri::Builder::default()
     .manage(AppState {
         bot: Mutex::new(BotState {
             process: None,
             status: "stopped".to_string(),
+            ready: false,
+            last_probe: None,
+            last_exit: None,
+            started_at: None,
         }),
+        output: Mutex::new(VecDeque::with_capacity(BOT_OUTPUT_BUFFER_SIZE)),
+        app_data_dir: OnceLock::new(),
+        window_state_generation: AtomicU64::new(0),
+        initialization_complete: AtomicBool::new(false),
+        initialization_error: Mutex::new(None),
+        update_in_progress: AtomicBool::new(false),
+        pending_update: Mutex::new(None),
     })
-    .setup(|app| {
-      if cfg!(debug_assertions) {
+    .setup(move |app| {
+      // Resolve the AppData directory once; commands read it from AppState.
+      // If this fails, say so clearly instead of every command failing on its own.
+      let resolved_app_dir = app.path().app_data_dir()
+        .map_err(|e| format!(
+          "DaeBot could not locate its AppData folder ({}). This can happen on systems where \
+           the user profile is locked down or redirected. Check that your user account has a \
+           writable AppData folder and restart DaeBot.", e));
+
+      let _ = app.state::<AppState>().app_data_dir.set(resolved_app_dir.clone());
+
+      // Headless launcher mode: start the bot, report, and exit before the
+      // single-instance plugin, window or tray come into play
+      if headless_start {
+        let wait_for_ready = std::env::args().any(|arg| arg == "--wait");
+        std::process::exit(run_headless_start(app.handle(), wait_for_ready));
+      }
+
+      // The main window is declared with "create": false so headless runs never
+      // build a webview; the GUI creates it here instead
+      for window_config in app.config().app.windows.iter() {
+        tauri::WebviewWindowBuilder::from_config(app.handle(), window_config)?.build()?;
+      }
+
+      // Debug builds log to the terminal; release builds have no console, so
+      // they log to a file in AppData instead. Level comes from Settings.logLevel.
+      {
+        use tauri_plugin_log::{RotationStrategy, Target, TargetKind};
+
+        let default_level = if cfg!(debug_assertions) { log::LevelFilter::Debug } else { log::LevelFilter::Info };
+        let level = get_settings(app.handle().clone()).ok()
+          .and_then(|settings| settings.log_level)
+          .and_then(|level| parse_log_level(&level))
+          .unwrap_or(default_level);
+
+        let target = match (&resolved_app_dir, cfg!(debug_assertions)) {
+          (_, true) => Target::new(TargetKind::Stdout),
+          (Ok(app_dir), false) => Target::new(TargetKind::Folder {
+            path: app_dir.clone(),
+            file_name: Some(APP_LOG_FILE_NAME.to_string()),
+          }),
+          (Err(_), false) => Target::new(TargetKind::LogDir { file_name: Some(APP_LOG_FILE_NAME.to_string()) }),
+        };
+
         app.handle().plugin(
-          tauri_plugin_log::Builder::default()
-            .level(log::LevelFilter::Info)
+          tauri_plugin_log::Builder::new()
+            .clear_targets()
+            .target(target)
+            .level(level)
+            .format(|out, message, record| {
+              out.finish(format_args!(
+                "{}[{}][{}] {}",
+                chrono::Local::now().format("[%Y-%m-%d][%H:%M:%S]"),
+                record.target(),
+                record.level(),
+                redact_secrets(&message.to_string())
+              ))
+            })
+            .max_file_size(5 * 1024 * 1024)
+            .rotation_strategy(RotationStrategy::KeepOne)
             .build(),
         )?;
       }
 
+      // Learn the configured credentials up front so early log lines are redacted too
+      let _ = get_config(app.handle().clone());
+      let _ = get_blizzard_credentials(app.handle().clone());
+
+      if let Err(ref message) = resolved_app_dir {
+        log::error!("{}", message);
+        if let Err(e) = fs::write(fallback_startup_error_path(), message) {
+          log::warn!("Failed to write startup error file: {}", e);
+        }
+      }
+
       // Initialize updater plugin (only in release builds)
       if !cfg!(debug_assertions) {
         app.handle().plugin(tauri_plugin_updater::Builder::new().build())?;
       }
 
-      // Initialize single-instance plugin to prevent multiple app instances
-      app.handle().plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
-        println!("Second instance detected, focusing existing window");
-
-        // Bring existing window to front
-        if let Some(window) = app.get_webview_window("main") {
-          let _ = window.show();
-          let _ = window.set_focus();
-          let _ = window.unminimize();
-        }
-      }))?;
-
-      // Initialize dialog plugin for file/folder pickers
-      app.handle().plugin(tauri_plugin_dialog::init())?;
-
-      // Initialize AppData directory and files on first run
-      let app_dir = app.path().app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
-
-      // Create AppData directory if it doesn't exist
-      if let Err(e) = fs::create_dir_all(&app_dir) {
-        println!("Warning: Failed to create app data dir: {}", e);
+      // Initialize single-instance plugin to prevent multiple app instances,
+      // unless the user opted into running several side by side
+      let allow_multiple_instances = get_settings(app.handle().clone())
+        .map(|settings| settings.allow_multiple_instances)
+        .unwrap_or(false);
+      if allow_multiple_instances {
+        log::info!("Multiple instances allowed, skipping single-instance plugin");
       } else {
-        println!("AppData directory initialized: {:?}", app_dir);
-
-        // Create blank config.json if it doesn't exist
-        let config_path = app_dir.join("config.json");
-        if !config_path.exists() {
-          let blank_config = Config {
-            token: None,
-            client_id: String::new(),
-            guild_id: String::new(),
-            token_channel: String::new(),
-            characters: Vec::new(),
-          };
-          if let Ok(content) = serde_json::to_string_pretty(&blank_config) {
-            if let Err(e) = fs::write(&config_path, content) {
-              println!("Warning: Failed to create blank config: {}", e);
-            } else {
-              println!("Created blank config.json at {:?}", config_path);
-            }
-          }
-        }
+        app.handle().plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
+          log::info!("Second instance detected, focusing existing window");
 
-        // Create blank .env if it doesn't exist
-        let env_path = app_dir.join(".env");
-        if !env_path.exists() {
-          let blank_env = "BLIZZARD_CLIENT_ID=\nBLIZZARD_CLIENT_SECRET=\n";
-          if let Err(e) = fs::write(&env_path, blank_env) {
-            println!("Warning: Failed to create blank .env: {}", e);
-          } else {
-            println!("Created blank .env at {:?}", env_path);
+          // Bring existing window to front
+          if let Some(window) = app.get_webview_window("main") {
+            let _ = window.show();
+            let _ = window.set_focus();
+            let _ = window.unminimize();
           }
-        }
-
-        // Copy command files from bundled resources to AppData if they don't exist
-        let commands_dir = app_dir.join("commands");
-        if !commands_dir.exists() {
-          println!("Commands folder not found in AppData, copying command files from resources...");
-
-          // Get the resource path where bundled files are stored
-          if let Ok(resource_path) = app.path().resource_dir() {
-            println!("Resource directory: {:?}", resource_path);
-
-            // Commands are bundled in _up_/dist/commands subdirectory
-            let source_commands_path = resource_path.join("_up_").join("dist").join("commands");
-            println!("Looking for command files at: {:?}", source_commands_path);
-
-            if source_commands_path.exists() {
-              // Create commands directory
-              if let Err(e) = fs::create_dir_all(&commands_dir) {
-                println!("Warning: Failed to create commands directory: {}", e);
-              } else {
-                // Copy all .js files from bundled commands to AppData commands directory
-                let mut copied_count = 0;
-                if let Ok(entries) = fs::read_dir(&source_commands_path) {
-                  for entry in entries.flatten() {
-                    let file_name = entry.file_name();
-                    if let Some(name_str) = file_name.to_str() {
-                      if name_str.ends_with(".js") {
-                        let source_file = source_commands_path.join(&file_name);
-                        let dest_file = commands_dir.join(&file_name);
+        }))?;
+      }
 
-                        match fs::copy(&source_file, &dest_file) {
-                          Ok(_) => {
-                            println!("  Copied: {:?}", file_name);
-                            copied_count += 1;
-                          }
-                          Err(e) => println!("  Warning: Failed to copy {:?}: {}", file_name, e),
-                        }
-                      }
-                    }
-                  }
-                }
+      // Initialize dialog plugin for file/folder pickers
+      app.handle().plugin(tauri_plugin_dialog::init())?;
 
-                if copied_count > 0 {
-                  println!("Successfully copied {} command file(s) to AppData: {:?}", copied_count, commands_dir);
-                } else {
-                  println!("Warning: No .js command files found in bundled resources");
-                }
-              }
-            } else {
-              println!("Warning: Commands not found at: {:?}", source_commands_path);
-            }
-          } else {
-            println!("Warning: Could not get resource directory");
-          }
-        } else {
-          println!("Commands folder already exists in AppData: {:?}", commands_dir);
+      // First-run file setup can be slow on cold disks; do it in the background
+      // and report progress so the window doesn't look hung
+      match resolved_app_dir {
+        Ok(app_dir) => {
+          let app_handle = app.handle().clone();
+          std::thread::spawn(move || initialize_app_data(&app_handle, &app_dir));
         }
+        // The error is already in the fallback startup-error file get_startup_error reads
+        Err(_) => finish_initialization(app.handle(), None),
       }
 
       // Setup system tray
@@ -2256,25 +8149,8 @@ pub fn run() {
             }
           }
           "quit" => {
-            // Stop bot before quitting
-            if let Some(state) = app.try_state::<AppState>() {
-              let mut bot = state.bot.lock().unwrap();
-              if let Some(process) = bot.process.take() {
-                println!("Stopping bot process from tray quit...");
-                #[cfg(target_os = "windows")]
-                {
-                  let pid = process.id();
-                  let _ = Command::new("taskkill")
-                    .args(["/F", "/T", "/PID", &pid.to_string()])
-                    .output();
-                }
-                #[cfg(not(target_os = "windows"))]
-                {
-                  let _ = process.kill();
-                }
-              }
-            }
-            app.exit(0);
+            log::info!("Quit selected from tray, stopping bot and exiting");
+            shutdown_bot_and_exit(app);
           }
           _ => {}
         })
@@ -2297,7 +8173,7 @@ pub fn run() {
       let settings = match get_settings(app.handle().clone()) {
           Ok(s) => s,
           Err(e) => {
-              println!("Warning: Failed to load settings: {}", e);
+              log::warn!("Failed to load settings: {}", e);
               Settings {
                   first_run: true,
                   auto_start: false,
@@ -2305,31 +8181,54 @@ pub fn run() {
                   start_minimized: false,
                   open_on_startup: false,
                   auto_start_bot: false,
+                  close_behavior: None,
+                  default_log_limit: None,
+                  default_sync_history_limit: None,
+                  bot_presence: None,
+                  bot_working_dir: None,
+                  bot_executable_path: None,
+                  node_path: None,
+                  log_level: None,
+                  theme: None,
+                  allow_multiple_instances: false,
+                  selected_season: None,
+                  read_database_path: None,
+                  auto_restart_after_update: false,
+                  shutdown_grace_secs: None,
+                  database_backup_retention: None,
               }
           }
       };
 
-      // Handle window visibility based on settings and arguments
-      if is_minimized_arg || settings.start_minimized {
-          if let Some(window) = app.get_webview_window("main") {
-              let _ = window.hide();
-              println!("Started minimized to tray");
+      // Only start hidden when launched by the Windows startup entry. That entry
+      // is written with --minimized when start_minimized is enabled, so a plain
+      // user launch always opens the window normally.
+      // The window starts hidden (see tauri.conf.json) so the saved geometry
+      // is applied before it's ever visible
+      if let Some(window) = app.get_webview_window("main") {
+          restore_window_state(&window);
+          apply_theme(&window, settings.effective_theme());
+
+          if is_minimized_arg {
+              log::info!("Started minimized to tray");
+          } else {
+              let _ = window.show();
           }
       }
 
       // Auto-start bot if enabled
       if settings.auto_start_bot {
-          println!("Auto-starting bot...");
+          log::info!("Auto-starting bot...");
           let app_handle = app.handle().clone();
           tauri::async_runtime::spawn(async move {
-              // Small delay to ensure everything is initialized
-              std::thread::sleep(std::time::Duration::from_secs(2));
+              // The bot needs config.json and the commands folder in place
+              wait_for_initialization(&app_handle);
 
               // Access state and app handle from within the task
               if let Some(state) = app_handle.try_state::<AppState>() {
                   match start_bot(state, app_handle.clone()) {
-                      Ok(_) => println!("Bot auto-started successfully"),
-                      Err(e) => println!("Failed to auto-start bot: {}", e),
+                      Ok(_) => log::info!("Bot auto-started successfully"),
+                      Err(e) => log::error!("Failed to auto-start bot: {}", e),
                   }
               }
           });
@@ -2338,38 +8237,137 @@ pub fn run() {
       Ok(())
     })
     .on_window_event(|window, event| {
+      if let tauri::WindowEvent::Moved(_) | tauri::WindowEvent::Resized(_) = event {
+        schedule_window_state_save(window);
+      }
+
       if let tauri::WindowEvent::CloseRequested { api, .. } = event {
-        // Prevent window from closing and hide it instead
-        window.hide().unwrap();
+        let app = window.app_handle();
+        let close_behavior = match get_settings(app.clone()) {
+          Ok(settings) => settings.effective_close_behavior(),
+          Err(e) => {
+            log::warn!("Failed to load settings in close handler: {}", e);
+            CloseBehavior::Tray
+          }
+        };
+
+        match close_behavior {
+          CloseBehavior::Tray => {
+            // Prevent window from closing and hide it instead
+            window.hide().unwrap();
+            api.prevent_close();
+            return;
+          }
+          CloseBehavior::Ask => {
+            // Let the frontend ask the user, it will call quit_app or hide_to_tray
+            api.prevent_close();
+            if let Err(e) = app.emit("close-requested", ()) {
+              log::warn!("Failed to emit close-requested event: {}", e);
+            }
+            return;
+          }
+          CloseBehavior::Quit => {}
+        }
+
+        // Close behavior is quit - stop the bot and exit. Keep the app alive
+        // until the bot has stopped; shutdown_bot_and_exit exits it after
+        log::info!("Window closed with close behavior set to quit, stopping bot and exiting");
+        let _ = window.hide();
         api.prevent_close();
+        shutdown_bot_and_exit(app);
       }
     })
     .invoke_handler(tauri::generate_handler![
         get_settings,
         save_settings,
+        get_theme,
+        is_first_run,
+        complete_first_run,
+        set_bot_presence,
+        pause_sync,
+        resume_sync,
+        get_sync_paused,
         get_config,
+        get_token_channels,
+        set_token_channels,
+        add_character,
+        remove_character,
+        verify_character,
         save_config,
+        reload_bot_config,
+        import_guild_roster,
         start_bot,
         stop_bot,
+        restart_bot,
+        kill_hung_bot,
         get_bot_status,
+        get_last_exit_code,
+        get_bot_uptime,
+        get_bot_output,
         quit_app,
+        hide_to_tray,
+        show_window,
+        minimize_window,
         check_for_updates,
+        get_updater_config,
+        get_latest_release_notes,
+        check_connectivity,
         install_update,
+        confirm_restart,
+        get_update_history,
         get_app_version,
+        get_paths,
         get_logs,
+        get_logs_since,
+        search_logs,
+        clear_logs,
+        get_log_summary,
+        get_error_summary,
         get_startup_error,
         get_last_sync_time,
         get_stats,
+        get_character_runs,
+        get_run_detail,
+        get_affix_stats,
+        get_dungeon_stats,
+        get_runs_timeline,
+        get_database_schema,
+        check_database_integrity,
+        optimize_database,
         get_available_seasons,
+        get_regions,
+        get_season_breakdown,
         get_blizzard_credentials,
         save_blizzard_credentials,
         import_database,
+        export_database,
+        export_anonymized_database,
+        list_database_backups,
+        restore_database_backup,
+        export_backup,
+        import_backup,
+        apply_initial_setup,
         get_sync_history,
+        benchmark_database,
+        ensure_indexes,
+        migrate_database,
+        verify_resources,
+        is_initialization_complete,
+        get_initialization_error,
+        detect_concurrent_bots,
         add_sync_history,
         get_bot_settings,
+        get_bot_settings_raw,
+        get_token_alert_status,
+        get_token_prices,
+        get_next_sync_estimate,
         update_bot_settings,
+        reset_bot_settings,
         deploy_discord_commands,
         delete_discord_commands,
+        check_channel_access,
+        list_bot_guilds,
+        validate_discord_token,
         copy_commands_folder,
         insert_manual_run
     ])
@@ -1,17 +1,106 @@
 import React, { useState, useEffect } from 'react';
 import Dashboard from './components/Dashboard';
 import SetupWizard from './components/SetupWizard';
-import { getSettings, saveSettings } from './tauriApi';
+import { listen } from '@tauri-apps/api/event';
+import { ask, message } from '@tauri-apps/plugin-dialog';
+import { getSettings, quitApp, hideToTray, killHungBot, getStartupError, completeFirstRun, isInitializationComplete, getInitializationError } from './tauriApi';
 
 function App() {
     const [showSetup, setShowSetup] = useState(false);
     const [settings, setSettings] = useState(null);
     const [loading, setLoading] = useState(true);
+    const [initializing, setInitializing] = useState(false);
+    const [initProgress, setInitProgress] = useState(null);
 
     useEffect(() => {
         loadSettings();
     }, []);
 
+    useEffect(() => {
+        // Backend sets up AppData files in the background on startup
+        let cancelled = false;
+        let errorShown = false;
+        const unlisteners = [];
+
+        // Startup checks (e.g. missing bundled files) report their findings here
+        const showInitializationError = async (error) => {
+            if (error && !errorShown && !cancelled) {
+                errorShown = true;
+                await message(error, { title: 'DaeBot', kind: 'warning' });
+            }
+        };
+
+        (async () => {
+            unlisteners.push(await listen('initialization-progress', (event) => {
+                setInitializing(true);
+                setInitProgress(event.payload);
+            }));
+            unlisteners.push(await listen('initialization-complete', (event) => {
+                setInitializing(false);
+                showInitializationError(event.payload.error);
+            }));
+
+            // Listeners are in place, so anything we missed is already finished
+            const done = await isInitializationComplete().catch(() => true);
+            if (!done && !cancelled) {
+                setInitializing(true);
+            } else if (done) {
+                showInitializationError(await getInitializationError().catch(() => null));
+            }
+        })();
+
+        return () => {
+            cancelled = true;
+            unlisteners.forEach(unlisten => unlisten());
+        };
+    }, []);
+
+    useEffect(() => {
+        // Backend asks us what to do when closeBehavior is set to 'Ask'
+        const unlistenPromise = listen('close-requested', async () => {
+            const shouldQuit = await ask('Do you want to quit DaeBot or minimize it to the system tray?', {
+                title: 'Close DaeBot',
+                kind: 'info',
+                okLabel: 'Quit',
+                cancelLabel: 'Minimize to Tray'
+            });
+
+            if (shouldQuit) {
+                await quitApp();
+            } else {
+                await hideToTray();
+            }
+        });
+
+        return () => {
+            unlistenPromise.then(unlisten => unlisten());
+        };
+    }, []);
+
+    useEffect(() => {
+        // Backend reports a bot that started but never finished initializing
+        const unlistenPromise = listen('bot-start-timeout', async (event) => {
+            const shouldKill = await ask(`The bot has not finished starting after ${event.payload.timeoutSecs} seconds. Do you want to stop it?`, {
+                title: 'Bot Not Responding',
+                kind: 'warning',
+                okLabel: 'Stop Bot',
+                cancelLabel: 'Keep Waiting'
+            });
+
+            if (shouldKill) {
+                try {
+                    await killHungBot();
+                } catch (error) {
+                    console.error('Failed to kill hung bot:', error);
+                }
+            }
+        });
+
+        return () => {
+            unlistenPromise.then(unlisten => unlisten());
+        };
+    }, []);
+
     const loadSettings = async () => {
         try {
             const result = await getSettings();
@@ -19,6 +108,13 @@ function App() {
             setShowSetup(result.firstRun);
         } catch (error) {
             console.error('Failed to load settings:', error);
+
+            // Surface problems setup recorded (e.g. no usable AppData folder)
+            const startupError = await getStartupError().catch(() => null);
+            if (startupError) {
+                await message(startupError, { title: 'DaeBot', kind: 'error' });
+            }
+
             // Fallback to default settings
             const defaultSettings = {
                 firstRun: true,
@@ -35,19 +131,25 @@ function App() {
 
     const handleSetupComplete = async (newSettings) => {
         try {
-            // Mark first run as complete
-            const updatedSettings = {
-                ...newSettings,
-                firstRun: false
-            };
-            await saveSettings(updatedSettings);
-            setSettings(updatedSettings);
+            // The wizard already persisted settings; make sure first run is marked done
+            await completeFirstRun();
+            setSettings({ ...newSettings, firstRun: false });
             setShowSetup(false);
         } catch (error) {
             console.error('Failed to save setup settings:', error);
         }
     };
 
+    if (initializing) {
+        return (
+            <div className="loading-container">
+                <div className="spinner"></div>
+                <p>Setting things up...</p>
+                {initProgress && <small>{initProgress.message}</small>}
+            </div>
+        );
+    }
+
     if (loading) {
         return (
             <div className="loading-container">
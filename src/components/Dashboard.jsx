@@ -7,20 +7,43 @@ import StatsChart from './StatsChart';
 import UpdateNotification from './UpdateNotification';
 import useBot from '../hooks/useBot';
 import useStats from '../hooks/useStats';
-import { quitApp, getAppVersion, getAvailableSeasons } from '../tauriApi';
+import { quitApp, getBotUptime, getAppVersion, getAvailableSeasons, getRegions, getSettings, saveSettings } from '../tauriApi';
 import { message, ask } from '@tauri-apps/plugin-dialog';
 
+// e.g. "2h 13m"; minutes only under an hour
+const formatUptime = (seconds) => {
+    const hours = Math.floor(seconds / 3600);
+    const minutes = Math.floor((seconds % 3600) / 60);
+    return hours > 0 ? `${hours}h ${minutes}m` : `${minutes}m`;
+};
+
 function Dashboard({ settings }) {
     const [activeTab, setActiveTab] = useState('overview');
     const [appVersion, setAppVersion] = useState('');
     const [selectedSeason, setSelectedSeason] = useState(null);
     const [availableSeasons, setAvailableSeasons] = useState([]);
+    const [selectedRegion, setSelectedRegion] = useState(null);
+    const [availableRegions, setAvailableRegions] = useState([]);
+    const [uptime, setUptime] = useState(null);
     const { botStatus, startBot, stopBot, restartBot } = useBot();
-    const { stats, refreshStats } = useStats(selectedSeason);
+    const { stats, refreshStats } = useStats(selectedSeason, selectedRegion);
+
+    useEffect(() => {
+        if (botStatus.status !== 'running') {
+            setUptime(null);
+            return;
+        }
+
+        const loadUptime = () => getBotUptime().then(setUptime).catch(() => setUptime(null));
+        loadUptime();
+        const interval = setInterval(loadUptime, 60000);
+        return () => clearInterval(interval);
+    }, [botStatus.status]);
 
     useEffect(() => {
         loadVersion();
         loadSeasons();
+        loadRegions();
     }, []);
 
     const loadVersion = async () => {
@@ -36,11 +59,39 @@ function Dashboard({ settings }) {
         try {
             const seasons = await getAvailableSeasons();
             setAvailableSeasons(seasons);
+
+            // Restore the saved filter, dropping it if that season is gone (e.g. after an import)
+            const saved = await getSettings();
+            if (saved.selectedSeason && seasons.includes(saved.selectedSeason)) {
+                setSelectedSeason(saved.selectedSeason);
+            } else if (saved.selectedSeason) {
+                await saveSettings({ ...saved, selectedSeason: null });
+            }
         } catch (error) {
             console.error('Failed to load seasons:', error);
         }
     };
 
+    const changeSeason = async (season) => {
+        setSelectedSeason(season);
+        try {
+            // Re-read so we don't overwrite changes made in the Settings tab
+            const saved = await getSettings();
+            await saveSettings({ ...saved, selectedSeason: season });
+        } catch (error) {
+            console.error('Failed to save season filter:', error);
+        }
+    };
+
+    const loadRegions = async () => {
+        try {
+            const regions = await getRegions();
+            setAvailableRegions(regions);
+        } catch (error) {
+            console.error('Failed to load regions:', error);
+        }
+    };
+
     const tabs = [
         { id: 'overview', label: 'Overview', icon: '📊' },
         { id: 'characters', label: 'Characters', icon: '👥' },
@@ -108,6 +159,9 @@ function Dashboard({ settings }) {
                         {botStatus.status === 'running' ? 'Online' :
                          botStatus.status === 'stopping' ? 'Stopping...' :
                          'Offline'}
+                        {botStatus.status === 'running' && uptime !== null && (
+                            <span className="status-uptime"> · {formatUptime(uptime)}</span>
+                        )}
                     </div>
 
                     {botStatus.online ? (
@@ -171,7 +225,7 @@ function Dashboard({ settings }) {
                                             id="seasonFilter"
                                             className="input"
                                             value={selectedSeason || ''}
-                                            onChange={(e) => setSelectedSeason(e.target.value || null)}
+                                            onChange={(e) => changeSeason(e.target.value || null)}
                                             style={{
                                                 padding: '0.25rem 0.5rem',
                                                 fontSize: '0.9rem',
@@ -185,6 +239,27 @@ function Dashboard({ settings }) {
                                         </select>
                                     </div>
                                 )}
+                                {availableRegions.length > 1 && (
+                                    <div style={{ marginTop: '0.5rem' }}>
+                                        <label htmlFor="regionFilter" style={{ fontSize: '0.9rem', marginRight: '0.5rem' }}>Region:</label>
+                                        <select
+                                            id="regionFilter"
+                                            className="input"
+                                            value={selectedRegion || ''}
+                                            onChange={(e) => setSelectedRegion(e.target.value || null)}
+                                            style={{
+                                                padding: '0.25rem 0.5rem',
+                                                fontSize: '0.9rem',
+                                                width: 'auto'
+                                            }}
+                                        >
+                                            <option value="">All Regions</option>
+                                            {availableRegions.map(region => (
+                                                <option key={region} value={region}>{region.toUpperCase()}</option>
+                                            ))}
+                                        </select>
+                                    </div>
+                                )}
                             </div>
 
                             <div className="stat-grid">
@@ -247,7 +322,7 @@ function Dashboard({ settings }) {
                                             id="statsSeasonFilter"
                                             className="input"
                                             value={selectedSeason || ''}
-                                            onChange={(e) => setSelectedSeason(e.target.value || null)}
+                                            onChange={(e) => changeSeason(e.target.value || null)}
                                             style={{
                                                 padding: '0.4rem 0.75rem',
                                                 fontSize: '0.95rem',
@@ -261,8 +336,29 @@ function Dashboard({ settings }) {
                                         </select>
                                     </div>
                                 )}
+                                {availableRegions.length > 1 && (
+                                    <div style={{ marginTop: '0.75rem' }}>
+                                        <label htmlFor="statsRegionFilter" style={{ fontSize: '0.9rem', marginRight: '0.5rem', fontWeight: 'bold' }}>Filter by Region:</label>
+                                        <select
+                                            id="statsRegionFilter"
+                                            className="input"
+                                            value={selectedRegion || ''}
+                                            onChange={(e) => setSelectedRegion(e.target.value || null)}
+                                            style={{
+                                                padding: '0.4rem 0.75rem',
+                                                fontSize: '0.95rem',
+                                                width: 'auto'
+                                            }}
+                                        >
+                                            <option value="">All Regions</option>
+                                            {availableRegions.map(region => (
+                                                <option key={region} value={region}>{region.toUpperCase()}</option>
+                                            ))}
+                                        </select>
+                                    </div>
+                                )}
                             </div>
-                            <StatsChart season={selectedSeason} />
+                            <StatsChart season={selectedSeason} region={selectedRegion} />
                         </div>
                     </div>
                 )}
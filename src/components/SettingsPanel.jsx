@@ -1,7 +1,19 @@
 import React, { useState, useEffect } from 'react';
-import { getSettings, saveSettings, getConfig, saveConfig, getAppVersion, getBlizzardCredentials, saveBlizzardCredentials, importDatabase, deployDiscordCommands, deleteDiscordCommands, copyCommandsFolder, getBotSettings, updateBotSettings, insertManualRun } from '../tauriApi';
+import { getSettings, saveSettings, getConfig, saveConfig, reloadBotConfig, getAppVersion, getBlizzardCredentials, saveBlizzardCredentials, importDatabase, exportBackup, importBackup, deployDiscordCommands, deleteDiscordCommands, copyCommandsFolder, getBotSettings, getBotSettingsRaw, getTokenAlertStatus, updateBotSettings, importGuildRoster, insertManualRun } from '../tauriApi';
 import useUpdateManager from '../hooks/useUpdateManager';
-import { open, message, ask } from '@tauri-apps/plugin-dialog';
+import { loadTheme } from '../theme';
+import { open, save, message, ask } from '@tauri-apps/plugin-dialog';
+
+// Regions that can have their own WoW token channel (matches the token region list)
+const TOKEN_CHANNEL_REGIONS = ['us', 'eu', 'kr', 'tw', 'cn'];
+
+// tokenChannel is a region -> channel id map; older configs used a single id string
+const normalizeTokenChannels = (value) => {
+    if (typeof value === 'string') {
+        return value ? { default: value } : {};
+    }
+    return value && typeof value === 'object' ? value : {};
+};
 
 function SettingsPanel({ settings: initialSettings }) {
     const [settings, setSettings] = useState({
@@ -16,7 +28,7 @@ function SettingsPanel({ settings: initialSettings }) {
         token: '',
         clientId: '',
         guildId: '',
-        tokenChannel: '',
+        tokenChannel: {},
         characters: []
     });
     const [blizzardCreds, setBlizzardCreds] = useState({
@@ -29,14 +41,27 @@ function SettingsPanel({ settings: initialSettings }) {
         defaultRegion: 'us',
         defaultRealm: 'thrall',
         activeDungeons: [],
-        betaChannel: false
+        betaChannel: false,
+        tokenRegion: 'us',
+        reportSchedule: '',
+        tokenAlertThreshold: null,
+        tokenAlertDirection: 'above'
     });
+    const [tokenAlertStatus, setTokenAlertStatus] = useState(null);
     const [newDungeon, setNewDungeon] = useState('');
     const [newCharacter, setNewCharacter] = useState({
         name: '',
         realm: '',
         region: 'us'
     });
+    const [guildImport, setGuildImport] = useState({
+        guildName: '',
+        realm: '',
+        region: 'us'
+    });
+    const [rosterCharacters, setRosterCharacters] = useState([]);
+    const [selectedRoster, setSelectedRoster] = useState([]);
+    const [loadingRoster, setLoadingRoster] = useState(false);
     const [activeTab, setActiveTab] = useState('general');
     const [saving, setSaving] = useState(false);
     const [appVersion, setAppVersion] = useState('');
@@ -66,6 +91,7 @@ function SettingsPanel({ settings: initialSettings }) {
         loadConfig();
         loadBlizzardCreds();
         loadBotSettings();
+        loadTokenAlertStatus();
         loadVersion();
     }, [initialSettings]);
 
@@ -82,9 +108,12 @@ function SettingsPanel({ settings: initialSettings }) {
         try {
             const result = await getSettings();
             if (result) {
+                const minimizeToTray = result.minimizeToTray !== undefined ? result.minimizeToTray : true;
                 setSettings({
+                    ...result, // Keep any settings this panel doesn't edit
                     syncInterval: result.syncInterval || 3600000,
-                    minimizeToTray: result.minimizeToTray !== undefined ? result.minimizeToTray : true,
+                    minimizeToTray,
+                    closeBehavior: result.closeBehavior || (minimizeToTray ? 'Tray' : 'Quit'),
                     autoUpdate: result.autoUpdate !== undefined ? result.autoUpdate : true,
                     openOnStartup: result.openOnStartup !== undefined ? result.openOnStartup : false,
                     startMinimized: result.startMinimized !== undefined ? result.startMinimized : false,
@@ -104,7 +133,7 @@ function SettingsPanel({ settings: initialSettings }) {
                     token: '', // Never load token for security
                     clientId: result.clientId || '',
                     guildId: result.guildId || '',
-                    tokenChannel: result.tokenChannel || '',
+                    tokenChannel: normalizeTokenChannels(result.tokenChannel),
                     characters: result.characters || []
                 });
             }
@@ -137,11 +166,46 @@ function SettingsPanel({ settings: initialSettings }) {
                     defaultRegion: result.defaultRegion || 'us',
                     defaultRealm: result.defaultRealm || 'thrall',
                     activeDungeons: result.activeDungeons || [],
-                    betaChannel: result.betaChannel || false
+                    betaChannel: result.betaChannel || false,
+                    tokenRegion: result.tokenRegion || 'us',
+                    reportSchedule: result.reportSchedule || '',
+                    tokenAlertThreshold: result.tokenAlertThreshold ?? null,
+                    tokenAlertDirection: result.tokenAlertDirection || 'above'
                 });
             }
         } catch (error) {
             console.error('Failed to load bot settings:', error);
+            await loadBotSettingsRaw();
+        }
+    };
+
+    // Fallback for databases whose bot_settings columns don't match what we expect
+    const loadBotSettingsRaw = async () => {
+        try {
+            const raw = await getBotSettingsRaw();
+            console.warn('Loaded bot settings from raw row (schema mismatch):', raw);
+
+            let activeDungeons = [];
+            try {
+                activeDungeons = JSON.parse(raw.active_dungeons || '[]');
+            } catch {
+                activeDungeons = [];
+            }
+
+            setBotSettings({
+                seasonId: raw.current_season_id || 15,
+                seasonName: raw.current_season_name || 'season-tww-3',
+                defaultRegion: raw.default_region || 'us',
+                defaultRealm: raw.default_realm || 'thrall',
+                activeDungeons,
+                betaChannel: Boolean(raw.beta_channel),
+                tokenRegion: raw.token_region || 'us',
+                reportSchedule: raw.report_schedule || '',
+                tokenAlertThreshold: raw.token_alert_threshold ?? null,
+                tokenAlertDirection: raw.token_alert_direction || 'above'
+            });
+        } catch (error) {
+            console.error('Failed to load raw bot settings:', error);
         }
     };
 
@@ -168,6 +232,7 @@ function SettingsPanel({ settings: initialSettings }) {
             }
 
             await updateBotSettings(botSettings);
+            loadTokenAlertStatus();
             await message('Season settings saved successfully! The bot will use these settings for all future syncs.', {
                 title: 'Settings Saved',
                 kind: 'info'
@@ -183,6 +248,15 @@ function SettingsPanel({ settings: initialSettings }) {
         }
     };
 
+    const loadTokenAlertStatus = async () => {
+        try {
+            setTokenAlertStatus(await getTokenAlertStatus());
+        } catch (error) {
+            console.error('Failed to load token alert status:', error);
+            setTokenAlertStatus(null);
+        }
+    };
+
     const handleAddDungeon = () => {
         if (newDungeon.trim()) {
             setBotSettings(prev => ({
@@ -216,6 +290,7 @@ function SettingsPanel({ settings: initialSettings }) {
         try {
             setSaving(true);
             await saveSettings(settings);
+            await loadTheme();
 
             // Try to save bot settings (for beta channel preference)
             // This will fail gracefully if the database doesn't exist yet (bot not started)
@@ -239,6 +314,13 @@ function SettingsPanel({ settings: initialSettings }) {
         }
     };
 
+    const setTokenChannel = (region, channelId) => {
+        setConfig(prev => ({
+            ...prev,
+            tokenChannel: { ...prev.tokenChannel, [region]: channelId.trim() }
+        }));
+    };
+
     const handleSaveConfig = async () => {
         try {
             setSaving(true);
@@ -258,7 +340,16 @@ function SettingsPanel({ settings: initialSettings }) {
             console.log('[SettingsPanel] Saving config:', configToSave);
             await saveConfig(configToSave);
             console.log('[SettingsPanel] Config saved successfully');
-            await message('Configuration saved successfully! Please restart the bot for changes to take effect.', { title: 'DaeBot', kind: 'info' });
+
+            // A running bot can pick the change up without a restart
+            let savedMessage = 'Configuration saved successfully! Changes will apply when the bot starts.';
+            try {
+                const reload = await reloadBotConfig();
+                savedMessage = 'Configuration saved successfully! ' + reload.message;
+            } catch (reloadError) {
+                console.log('[SettingsPanel] Config reload skipped:', reloadError);
+            }
+            await message(savedMessage, { title: 'DaeBot', kind: 'info' });
             // Clear token field after saving
             setConfig(prev => ({ ...prev, token: '' }));
         } catch (error) {
@@ -272,6 +363,43 @@ function SettingsPanel({ settings: initialSettings }) {
         }
     };
 
+    const handleFetchRoster = async () => {
+        try {
+            setLoadingRoster(true);
+            const members = await importGuildRoster(guildImport.realm.trim(), guildImport.region, guildImport.guildName.trim());
+            setRosterCharacters(members);
+            setSelectedRoster([]);
+
+            if (members.length === 0) {
+                await message('No new characters found in that guild.', { title: 'DaeBot', kind: 'info' });
+            }
+        } catch (error) {
+            console.error('Failed to import guild roster:', error);
+            const errorMsg = typeof error === 'string' ? error : (error?.message || String(error) || 'Unknown error');
+            await message('Failed to import guild roster:\n\n' + errorMsg, { title: 'DaeBot', kind: 'error' });
+        } finally {
+            setLoadingRoster(false);
+        }
+    };
+
+    const handleToggleRosterCharacter = (index) => {
+        setSelectedRoster(prev => (
+            prev.includes(index) ? prev.filter(i => i !== index) : [...prev, index]
+        ));
+    };
+
+    const handleAddSelectedRoster = () => {
+        const toAdd = rosterCharacters.filter((_, index) => selectedRoster.includes(index));
+
+        setConfig(prev => ({
+            ...prev,
+            characters: [...prev.characters, ...toAdd]
+        }));
+
+        setRosterCharacters(prev => prev.filter((_, index) => !selectedRoster.includes(index)));
+        setSelectedRoster([]);
+    };
+
     const handleAddCharacter = () => {
         if (!newCharacter.name || !newCharacter.realm) {
             return;
@@ -332,7 +460,7 @@ function SettingsPanel({ settings: initialSettings }) {
                 ...prev,
                 clientId: importedConfig.clientId || prev.clientId,
                 guildId: importedConfig.guildId || prev.guildId,
-                tokenChannel: importedConfig.tokenChannel || prev.tokenChannel,
+                tokenChannel: importedConfig.tokenChannel ? normalizeTokenChannels(importedConfig.tokenChannel) : prev.tokenChannel,
                 characters: importedConfig.characters || prev.characters
             }));
 
@@ -367,7 +495,7 @@ function SettingsPanel({ settings: initialSettings }) {
             console.log('[SettingsPanel] Importing database from:', filePath);
             const result = await importDatabase(filePath);
             console.log('[SettingsPanel] Import result:', result);
-            await message(result, { title: 'DaeBot', kind: 'info' });
+            await message(result, { title: 'DaeBot', kind: result.includes('Warning:') ? 'warning' : 'info' });
         } catch (error) {
             console.error('[SettingsPanel] Database import error:', error);
             console.error('[SettingsPanel] Error type:', typeof error);
@@ -379,6 +507,58 @@ function SettingsPanel({ settings: initialSettings }) {
         }
     };
 
+    const handleExportBackup = async () => {
+        try {
+            const destPath = await save({
+                title: 'Export Full Backup',
+                defaultPath: `daebot-backup-${new Date().toISOString().slice(0, 10)}.zip`,
+                filters: [{ name: 'Backup', extensions: ['zip'] }]
+            });
+            if (!destPath) {
+                return;
+            }
+
+            setSaving(true);
+            const result = await exportBackup(destPath);
+            await message(result, { title: 'DaeBot', kind: 'info' });
+        } catch (error) {
+            console.error('[SettingsPanel] Backup export error:', error);
+            const errorMsg = typeof error === 'string' ? error : (error?.message || String(error));
+            await message('Failed to export backup: ' + errorMsg, { title: 'DaeBot', kind: 'error' });
+        } finally {
+            setSaving(false);
+        }
+    };
+
+    const handleImportBackup = async () => {
+        try {
+            const srcPath = await open({
+                title: 'Select Backup File',
+                filters: [{ name: 'Backup', extensions: ['zip'] }],
+                multiple: false,
+                directory: false
+            });
+            if (!srcPath) {
+                return;
+            }
+
+            const confirmed = await ask('Importing a backup replaces your config, settings, database and commands. Your current files are saved to the data folder first.\n\nContinue?', { title: 'DaeBot', kind: 'warning' });
+            if (!confirmed) {
+                return;
+            }
+
+            setSaving(true);
+            const result = await importBackup(srcPath);
+            await message(result, { title: 'DaeBot', kind: 'info' });
+        } catch (error) {
+            console.error('[SettingsPanel] Backup import error:', error);
+            const errorMsg = typeof error === 'string' ? error : (error?.message || String(error));
+            await message('Failed to import backup: ' + errorMsg, { title: 'DaeBot', kind: 'error' });
+        } finally {
+            setSaving(false);
+        }
+    };
+
     const handleCheckForUpdates = async () => {
         try {
             // Force show the update notification even if previously dismissed
@@ -394,10 +574,10 @@ function SettingsPanel({ settings: initialSettings }) {
         }
     };
 
-    const handleDeployCommands = async () => {
+    const handleDeployCommands = async (global = false) => {
         try {
             setSaving(true);
-            const result = await deployDiscordCommands();
+            const result = await deployDiscordCommands(null, global);
             await message(result, { title: 'DaeBot', kind: 'info' });
         } catch (error) {
             console.error('Failed to deploy commands:', error);
@@ -427,8 +607,15 @@ function SettingsPanel({ settings: initialSettings }) {
 
     const handleCopyCommandsFolder = async () => {
         try {
+            const clean = await ask('Also remove command files that are no longer bundled with DaeBot?', {
+                title: 'Copy Commands Folder',
+                kind: 'info',
+                okLabel: 'Remove Stale Files',
+                cancelLabel: 'Keep Them'
+            });
+
             setSaving(true);
-            const result = await copyCommandsFolder();
+            const result = await copyCommandsFolder(clean);
             await message(result, { title: 'DaeBot', kind: 'info' });
         } catch (error) {
             console.error('Failed to copy commands folder:', error);
@@ -528,15 +715,22 @@ function SettingsPanel({ settings: initialSettings }) {
                     </div>
 
                     <div className="form-group">
-                        <label className="checkbox-label">
-                            <input className="checkbox-input"
-                                type="checkbox"
-                                checked={settings.minimizeToTray}
-                                onChange={(e) => setSettings({ ...settings, minimizeToTray: e.target.checked })}
-                            />
-                            Minimize to system tray
-                        </label>
-                        <small className="tooltip" style={{ display: 'block', marginTop: '0.5rem' }}>When enabled, closing the window minimizes to tray instead of quitting</small>
+                        <label htmlFor="closeBehavior">When closing the window</label>
+                        <select
+                            id="closeBehavior"
+                            className="input"
+                            value={settings.closeBehavior || (settings.minimizeToTray ? 'Tray' : 'Quit')}
+                            onChange={(e) => setSettings({
+                                ...settings,
+                                closeBehavior: e.target.value,
+                                minimizeToTray: e.target.value !== 'Quit'
+                            })}
+                        >
+                            <option value="Tray">Minimize to system tray</option>
+                            <option value="Quit">Quit DaeBot</option>
+                            <option value="Ask">Ask every time</option>
+                        </select>
+                        <small className="tooltip" style={{ display: 'block', marginTop: '0.5rem' }}>What happens when you click the window's close button</small>
                     </div>
 
                     <div className="form-group">
@@ -576,7 +770,7 @@ function SettingsPanel({ settings: initialSettings }) {
                             />
                             Start minimized to tray
                         </label>
-                        <small className="tooltip" style={{ display: 'block', marginTop: '0.5rem' }}>Open minimized to system tray on startup</small>
+                        <small className="tooltip" style={{ display: 'block', marginTop: '0.5rem' }}>Open minimized to system tray when launched at Windows startup</small>
                     </div>
 
                     <div className="form-group">
@@ -591,6 +785,143 @@ function SettingsPanel({ settings: initialSettings }) {
                         <small className="tooltip" style={{ display: 'block', marginTop: '0.5rem' }}>Automatically start the Discord bot when the app opens</small>
                     </div>
 
+                    <div className="form-group">
+                        <label className="checkbox-label">
+                            <input className="checkbox-input"
+                                type="checkbox"
+                                checked={!!settings.allowMultipleInstances}
+                                onChange={(e) => setSettings({ ...settings, allowMultipleInstances: e.target.checked })}
+                            />
+                            Allow multiple DaeBot windows
+                        </label>
+                        <small className="tooltip" style={{ display: 'block', marginTop: '0.5rem' }}>Launching DaeBot again opens another instance instead of focusing this one. All instances share the same data, so only run the bot from one. Takes effect after restarting DaeBot</small>
+                    </div>
+
+                    <div className="form-group">
+                        <label className="checkbox-label">
+                            <input className="checkbox-input"
+                                type="checkbox"
+                                checked={!!settings.autoRestartAfterUpdate}
+                                onChange={(e) => setSettings({ ...settings, autoRestartAfterUpdate: e.target.checked })}
+                            />
+                            Restart automatically after updating
+                        </label>
+                        <small className="tooltip" style={{ display: 'block', marginTop: '0.5rem' }}>Restart as soon as an update finishes installing instead of asking first</small>
+                    </div>
+
+                    <div className="form-group">
+                        <label htmlFor="shutdownGraceSecs">Shutdown grace period (seconds)</label>
+                        <input
+                            id="shutdownGraceSecs"
+                            type="number"
+                            className="input"
+                            min="0"
+                            max="60"
+                            placeholder="5"
+                            value={settings.shutdownGraceSecs ?? ''}
+                            onChange={(e) => setSettings({ ...settings, shutdownGraceSecs: e.target.value === '' ? null : parseInt(e.target.value, 10) })}
+                        />
+                        <small className="tooltip" style={{ display: 'block', marginTop: '0.5rem' }}>How long the bot gets to close its database and Discord connection when stopped before it is force-killed</small>
+                    </div>
+
+                    <div className="form-group">
+                        <label htmlFor="databaseBackupRetention">Database backups to keep</label>
+                        <input
+                            id="databaseBackupRetention"
+                            type="number"
+                            className="input"
+                            min="1"
+                            placeholder="5"
+                            value={settings.databaseBackupRetention ?? ''}
+                            onChange={(e) => setSettings({ ...settings, databaseBackupRetention: e.target.value === '' ? null : parseInt(e.target.value, 10) })}
+                        />
+                        <small className="tooltip" style={{ display: 'block', marginTop: '0.5rem' }}>Importing a database backs up the current one first; older backups beyond this number are deleted</small>
+                    </div>
+
+                    <div className="form-group">
+                        <label htmlFor="botWorkingDir">Bot working directory (optional)</label>
+                        <input
+                            id="botWorkingDir"
+                            type="text"
+                            className="input"
+                            placeholder="Leave blank to use the default location"
+                            value={settings.botWorkingDir || ''}
+                            onChange={(e) => setSettings({ ...settings, botWorkingDir: e.target.value || null })}
+                        />
+                        <small className="tooltip" style={{ display: 'block', marginTop: '0.5rem' }}>Folder the bot process is started in; must already exist</small>
+                    </div>
+
+                    <div className="form-group">
+                        <label htmlFor="botExecutablePath">Bot executable (optional)</label>
+                        <input
+                            id="botExecutablePath"
+                            type="text"
+                            className="input"
+                            placeholder="Leave blank to use the bundled bot"
+                            value={settings.botExecutablePath || ''}
+                            onChange={(e) => setSettings({ ...settings, botExecutablePath: e.target.value || null })}
+                        />
+                        <small className="tooltip" style={{ display: 'block', marginTop: '0.5rem' }}>Full path to bot.exe, or to main.js to run the bot with Node</small>
+                    </div>
+
+                    <div className="form-group">
+                        <label htmlFor="nodePath">Node path (optional)</label>
+                        <input
+                            id="nodePath"
+                            type="text"
+                            className="input"
+                            placeholder="Leave blank to use node from PATH"
+                            value={settings.nodePath || ''}
+                            onChange={(e) => setSettings({ ...settings, nodePath: e.target.value || null })}
+                        />
+                        <small className="tooltip" style={{ display: 'block', marginTop: '0.5rem' }}>Node executable used when the bot runs from a .js file</small>
+                    </div>
+
+                    <div className="form-group">
+                        <label htmlFor="readDatabasePath">Read database path (optional)</label>
+                        <input
+                            id="readDatabasePath"
+                            type="text"
+                            className="input"
+                            placeholder="Leave blank to read the bot's database directly"
+                            value={settings.readDatabasePath || ''}
+                            onChange={(e) => setSettings({ ...settings, readDatabasePath: e.target.value || null })}
+                        />
+                        <small className="tooltip" style={{ display: 'block', marginTop: '0.5rem' }}>A synced copy of mythic_runs.db the dashboard reads stats from; changes are still written to the bot's database</small>
+                    </div>
+
+                    <div className="form-group">
+                        <label htmlFor="theme">Theme</label>
+                        <select
+                            id="theme"
+                            className="input"
+                            value={settings.theme || 'Dark'}
+                            onChange={(e) => setSettings({ ...settings, theme: e.target.value })}
+                        >
+                            <option value="System">Match system</option>
+                            <option value="Light">Light</option>
+                            <option value="Dark">Dark</option>
+                        </select>
+                    </div>
+
+                    <div className="form-group">
+                        <label htmlFor="logLevel">App log level</label>
+                        <select
+                            id="logLevel"
+                            className="input"
+                            value={settings.logLevel || ''}
+                            onChange={(e) => setSettings({ ...settings, logLevel: e.target.value || null })}
+                        >
+                            <option value="">Default</option>
+                            <option value="error">Error</option>
+                            <option value="warn">Warn</option>
+                            <option value="info">Info</option>
+                            <option value="debug">Debug</option>
+                            <option value="trace">Trace</option>
+                        </select>
+                        <small className="tooltip" style={{ display: 'block', marginTop: '0.5rem' }}>Detail written to daebot-app.log in the app data folder; takes effect after restarting DaeBot</small>
+                    </div>
+
                     <button
                         className="btn btn-success"
                         onClick={handleSaveSettings}
@@ -698,9 +1029,25 @@ function SettingsPanel({ settings: initialSettings }) {
                             type="text"
                             className="input"
                             placeholder="Channel for WoW token updates"
-                            value={config.tokenChannel}
-                            onChange={(e) => setConfig({ ...config, tokenChannel: e.target.value })}
+                            value={config.tokenChannel.default || ''}
+                            onChange={(e) => setTokenChannel('default', e.target.value)}
                         />
+                        <small className="tooltip" style={{ display: 'block', marginTop: '0.5rem' }}>Per-region channels (optional, override the one above for that token region):</small>
+                        <div style={{ display: 'grid', gridTemplateColumns: 'auto 1fr', gap: '0.5rem', alignItems: 'center', marginTop: '0.5rem' }}>
+                            {TOKEN_CHANNEL_REGIONS.map(region => (
+                                <React.Fragment key={region}>
+                                    <label htmlFor={`tokenChannel-${region}`}>{region.toUpperCase()}</label>
+                                    <input
+                                        id={`tokenChannel-${region}`}
+                                        type="text"
+                                        className="input"
+                                        placeholder="Use channel above"
+                                        value={config.tokenChannel[region] || ''}
+                                        onChange={(e) => setTokenChannel(region, e.target.value)}
+                                    />
+                                </React.Fragment>
+                            ))}
+                        </div>
                     </div>
 
                     <div className="button-group" style={{ display: 'flex', gap: '0.75rem', flexWrap: 'wrap' }}>
@@ -737,6 +1084,22 @@ function SettingsPanel({ settings: initialSettings }) {
                         >
                             Import Database
                         </button>
+
+                        <button
+                            className="btn btn-secondary"
+                            onClick={handleExportBackup}
+                            disabled={saving}
+                        >
+                            Export Full Backup
+                        </button>
+
+                        <button
+                            className="btn btn-secondary"
+                            onClick={handleImportBackup}
+                            disabled={saving}
+                        >
+                            Import Full Backup
+                        </button>
                     </div>
 
                     <hr style={{ margin: '2rem 0', border: 'none', borderTop: '1px solid var(--border)' }} />
@@ -750,12 +1113,21 @@ function SettingsPanel({ settings: initialSettings }) {
                     <div className="button-group" style={{ display: 'flex', gap: '0.75rem', flexWrap: 'wrap' }}>
                         <button
                             className="btn btn-primary"
-                            onClick={handleDeployCommands}
+                            onClick={() => handleDeployCommands(false)}
                             disabled={saving}
                         >
                             {saving ? 'Deploying...' : 'Deploy Commands'}
                         </button>
 
+                        <button
+                            className="btn btn-secondary"
+                            onClick={() => handleDeployCommands(true)}
+                            disabled={saving}
+                            title="Register commands for every server the bot is in"
+                        >
+                            {saving ? 'Deploying...' : 'Deploy Globally'}
+                        </button>
+
                         <button
                             className="btn btn-danger"
                             onClick={handleDeleteCommands}
@@ -877,6 +1249,81 @@ function SettingsPanel({ settings: initialSettings }) {
                         </div>
                     </div>
 
+                    <div className="character-input">
+                        <h4>Import from Guild</h4>
+                        <div className="form-row">
+                            <div className="form-group">
+                                <label htmlFor="guildName">Guild Name</label>
+                                <input
+                                    id="guildName"
+                                    type="text"
+                                    className="input"
+                                    placeholder="Guild name"
+                                    value={guildImport.guildName}
+                                    onChange={(e) => setGuildImport({ ...guildImport, guildName: e.target.value })}
+                                />
+                            </div>
+
+                            <div className="form-group">
+                                <label htmlFor="guildRealm">Realm</label>
+                                <input
+                                    id="guildRealm"
+                                    type="text"
+                                    className="input"
+                                    placeholder="Realm name"
+                                    value={guildImport.realm}
+                                    onChange={(e) => setGuildImport({ ...guildImport, realm: e.target.value })}
+                                />
+                            </div>
+
+                            <div className="form-group">
+                                <label htmlFor="guildRegion">Region</label>
+                                <select
+                                    id="guildRegion"
+                                    className="input"
+                                    value={guildImport.region}
+                                    onChange={(e) => setGuildImport({ ...guildImport, region: e.target.value })}
+                                >
+                                    <option value="us">US</option>
+                                    <option value="eu">EU</option>
+                                    <option value="kr">KR</option>
+                                    <option value="tw">TW</option>
+                                </select>
+                            </div>
+
+                            <button
+                                className="btn btn-primary"
+                                onClick={handleFetchRoster}
+                                disabled={loadingRoster || !guildImport.guildName || !guildImport.realm}
+                            >
+                                {loadingRoster ? 'Loading...' : 'Fetch Roster'}
+                            </button>
+                        </div>
+
+                        {rosterCharacters.length > 0 && (
+                            <div className="mt-md">
+                                {rosterCharacters.map((char, index) => (
+                                    <label key={`${char.name}-${char.realm}-${char.region}`} className="checkbox-label">
+                                        <input className="checkbox-input"
+                                            type="checkbox"
+                                            checked={selectedRoster.includes(index)}
+                                            onChange={() => handleToggleRosterCharacter(index)}
+                                        />
+                                        {char.name} ({char.realm})
+                                    </label>
+                                ))}
+
+                                <button
+                                    className="btn btn-success mt-md"
+                                    onClick={handleAddSelectedRoster}
+                                    disabled={selectedRoster.length === 0}
+                                >
+                                    Add Selected ({selectedRoster.length})
+                                </button>
+                            </div>
+                        )}
+                    </div>
+
                     {config.characters.length > 0 ? (
                         <div className="character-list-settings">
                             <h4>Tracked Characters ({config.characters.length})</h4>
@@ -986,6 +1433,74 @@ function SettingsPanel({ settings: initialSettings }) {
                             <small className="tooltip">Default region for API calls and character lookups.</small>
                         </div>
 
+                        <div className="form-group">
+                            <label htmlFor="tokenRegion">WoW Token Region</label>
+                            <select
+                                id="tokenRegion"
+                                className="input"
+                                value={botSettings.tokenRegion}
+                                onChange={(e) => setBotSettings({ ...botSettings, tokenRegion: e.target.value })}
+                            >
+                                <option value="us">US (Americas)</option>
+                                <option value="eu">EU (Europe)</option>
+                                <option value="kr">KR (Korea)</option>
+                                <option value="tw">TW (Taiwan)</option>
+                                <option value="cn">CN (China)</option>
+                            </select>
+                            <small className="tooltip">Region used for WoW token prices, regardless of character regions.</small>
+                        </div>
+
+                        <div className="form-group">
+                            <label htmlFor="reportSchedule">Report Schedule</label>
+                            <input
+                                id="reportSchedule"
+                                type="text"
+                                className="input"
+                                placeholder="e.g., daily 09:00, weekly tue 15:00, every 6h"
+                                value={botSettings.reportSchedule}
+                                onChange={(e) => setBotSettings({ ...botSettings, reportSchedule: e.target.value })}
+                            />
+                            <small className="tooltip">When the bot posts scheduled reports. Leave blank to disable.</small>
+                        </div>
+
+                        <div className="form-group">
+                            <label htmlFor="tokenAlertThreshold">Token Price Alert</label>
+                            <div style={{ display: 'flex', gap: '0.5rem' }}>
+                                <select
+                                    id="tokenAlertDirection"
+                                    className="input"
+                                    value={botSettings.tokenAlertDirection}
+                                    onChange={(e) => setBotSettings({ ...botSettings, tokenAlertDirection: e.target.value })}
+                                >
+                                    <option value="above">At or above</option>
+                                    <option value="below">At or below</option>
+                                </select>
+                                <input
+                                    id="tokenAlertThreshold"
+                                    type="number"
+                                    className="input"
+                                    min="50000"
+                                    max="1000000"
+                                    step="5000"
+                                    placeholder="Use /token set-threshold value"
+                                    value={botSettings.tokenAlertThreshold ?? ''}
+                                    onChange={(e) => setBotSettings({
+                                        ...botSettings,
+                                        tokenAlertThreshold: e.target.value === '' ? null : parseInt(e.target.value, 10)
+                                    })}
+                                />
+                            </div>
+                            <small className="tooltip">
+                                Gold price (50,000 - 1,000,000) that triggers Discord token alerts. Leave blank to use the threshold set in Discord.
+                                {tokenAlertStatus && tokenAlertStatus.latestPrice !== null && tokenAlertStatus.threshold !== null && (
+                                    <>
+                                        <br />Latest price {tokenAlertStatus.latestPrice.toLocaleString()}g vs threshold {tokenAlertStatus.threshold.toLocaleString()}g
+                                        {' '}({tokenAlertStatus.wouldFire ? 'an alert would fire' : 'no alert would fire'}).
+                                    </>
+                                )}
+                            </small>
+                        </div>
+
                         <div className="form-group">
                             <label htmlFor="defaultRealm">Default Realm</label>
                             <input
@@ -1,5 +1,5 @@
 import React, { useState } from 'react';
-import { saveConfig, saveSettings, importDatabase, saveBlizzardCredentials } from '../tauriApi';
+import { saveSettings, importDatabase, applyInitialSetup, completeFirstRun } from '../tauriApi';
 import { open, message } from '@tauri-apps/plugin-dialog';
 
 function SetupWizard({ onComplete }) {
@@ -51,7 +51,10 @@ function SetupWizard({ onComplete }) {
                 token: importedConfig.token || '',
                 clientId: importedConfig.clientId || '',
                 guildId: importedConfig.guildId || '',
-                tokenChannel: importedConfig.tokenChannel || '',
+                // Per-region maps only carry over their default channel here
+                tokenChannel: typeof importedConfig.tokenChannel === 'string'
+                    ? importedConfig.tokenChannel
+                    : (importedConfig.tokenChannel?.default || ''),
                 characters: importedConfig.characters || []
             });
 
@@ -125,17 +128,12 @@ function SetupWizard({ onComplete }) {
 
     const handleComplete = async () => {
         try {
-            // Save configuration
-            await saveConfig(config);
-
-            // Save Blizzard credentials if provided
-            if (blizzardCreds.clientId && blizzardCreds.clientSecret) {
-                await saveBlizzardCredentials(blizzardCreds);
-            }
+            // Save configuration and Blizzard credentials (if provided) together
+            const credentials = blizzardCreds.clientId && blizzardCreds.clientSecret ? blizzardCreds : null;
+            await applyInitialSetup(config, credentials);
 
             // Save settings with sync interval
             const settings = {
-                firstRun: false,
                 autoStart: false,
                 minimizeToTray: true,
                 startMinimized: false,
@@ -143,10 +141,14 @@ function SetupWizard({ onComplete }) {
             };
             await saveSettings(settings);
 
+            // Onboarding is done; don't show the wizard on the next launch
+            await completeFirstRun();
+
             // Notify parent with new settings
-            onComplete(settings);
+            onComplete({ ...settings, firstRun: false });
         } catch (error) {
-            await message('Failed to save configuration: ' + error.message, { title: 'DaeBot', kind: 'error' });
+            const errorMsg = typeof error === 'string' ? error : error.message;
+            await message('Failed to save configuration: ' + errorMsg, { title: 'DaeBot', kind: 'error' });
         }
     };
 
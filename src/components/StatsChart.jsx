@@ -1,8 +1,9 @@
 import React from 'react';
 import useStats from '../hooks/useStats';
+import { INVALID_TIMESTAMP } from '../tauriApi';
 
-function StatsChart({ season = null }) {
-    const { stats, loading, refreshStats } = useStats(season);
+function StatsChart({ season = null, region = null }) {
+    const { stats, loading, refreshStats } = useStats(season, region);
 
     const formatBytes = (bytes) => {
         if (!bytes) return '0 B';
@@ -14,6 +15,7 @@ function StatsChart({ season = null }) {
 
     const formatDate = (dateString) => {
         if (!dateString) return 'N/A';
+        if (dateString === INVALID_TIMESTAMP) return 'Invalid timestamp';
         const date = new Date(dateString);
         return date.toLocaleDateString() + ' ' + date.toLocaleTimeString();
     };
@@ -67,7 +69,7 @@ function StatsChart({ season = null }) {
                     <div className="stat-icon">📅</div>
                     <div className="stat-details">
                         <div className="stat-value">
-                            {stats.lastSync ? new Date(stats.lastSync).toLocaleDateString() : 'N/A'}
+                            {stats.lastSync === INVALID_TIMESTAMP ? 'Invalid timestamp' : (stats.lastSync ? new Date(stats.lastSync).toLocaleDateString() : 'N/A')}
                         </div>
                         <div className="stat-label">Latest Run</div>
                     </div>
@@ -1,5 +1,5 @@
 import React, { useState, useEffect } from 'react';
-import { getSyncHistory, addSyncHistory, getLastSyncTime } from '../tauriApi';
+import { getSyncHistory, addSyncHistory, getLastSyncTime, detectConcurrentBots, INVALID_TIMESTAMP } from '../tauriApi';
 
 function SyncStatus() {
     const [syncStatus, setSyncStatus] = useState({
@@ -12,6 +12,7 @@ function SyncStatus() {
         error: null
     });
     const [history, setHistory] = useState([]);
+    const [concurrentWarning, setConcurrentWarning] = useState(null);
     const [nextSyncInfo, setNextSyncInfo] = useState({
         timeUntilNext: null,
         percentComplete: 0,
@@ -108,11 +109,18 @@ function SyncStatus() {
 
     const loadSyncHistory = async () => {
         try {
-            const historyData = await getSyncHistory(4);
+            const historyData = await getSyncHistory();
             setHistory(historyData);
         } catch (error) {
             console.error('Failed to load sync history:', error);
         }
+
+        try {
+            const report = await detectConcurrentBots();
+            setConcurrentWarning(report.suspected ? report.message : null);
+        } catch (error) {
+            console.error('Failed to check for concurrent bots:', error);
+        }
     };
 
     const loadLastSyncTime = async () => {
@@ -155,8 +163,8 @@ function SyncStatus() {
         let checkForSyncTimer = null;
 
         const updateCountdown = () => {
-            if (!syncStatus.lastSync) {
-                // No sync data yet - set up polling to check for first sync
+            if (!syncStatus.lastSync || syncStatus.lastSync === INVALID_TIMESTAMP) {
+                // No usable sync data yet - set up polling to check for first sync
                 setNextSyncInfo({
                     timeUntilNext: null,
                     percentComplete: 0,
@@ -236,6 +244,7 @@ function SyncStatus() {
 
     const formatTimestamp = (iso) => {
         if (!iso) return 'Never';
+        if (iso === INVALID_TIMESTAMP) return 'Invalid timestamp';
         const date = new Date(iso);
         const now = new Date();
         const diffMs = now - date;
@@ -308,6 +317,12 @@ function SyncStatus() {
                                 {syncStatus.error}
                             </div>
                         )}
+
+                        {concurrentWarning && (
+                            <div className="alert alert-error">
+                                {concurrentWarning}
+                            </div>
+                        )}
                     </div>
 
                     {/* Progress Bar */}
@@ -358,7 +373,7 @@ function SyncStatus() {
                                     </div>
                                     <div className="history-details">
                                         <div className="history-time">
-                                            {new Date(entry.timestamp).toLocaleString()}
+                                            {entry.timestamp === INVALID_TIMESTAMP ? 'Invalid timestamp' : new Date(entry.timestamp).toLocaleString()}
                                             <span className="sync-type-badge" style={{
                                                 marginLeft: '8px',
                                                 padding: '2px 6px',
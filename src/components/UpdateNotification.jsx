@@ -1,10 +1,33 @@
 import { useEffect } from 'react';
 import useUpdateManager from '../hooks/useUpdateManager';
 
+const CHANGELOG_CATEGORIES = [
+    { key: 'breaking', title: 'Breaking Changes' },
+    { key: 'features', title: 'Features' },
+    { key: 'fixes', title: 'Fixes' },
+    { key: 'other', title: 'Other' }
+];
+
+function ChangelogSections({ sections }) {
+    return CHANGELOG_CATEGORIES
+        .filter(({ key }) => sections[key] && sections[key].length > 0)
+        .map(({ key, title }) => (
+            <div key={key} className="changelog-section">
+                <strong>{title}</strong>
+                <ul>
+                    {sections[key].map((item, index) => (
+                        <li key={index}>{item}</li>
+                    ))}
+                </ul>
+            </div>
+        ));
+}
+
 function UpdateNotification() {
     const {
         updateInfo,
         installing,
+        downloadProgress,
         error,
         dismissed,
         checkUpdates,
@@ -26,6 +49,14 @@ function UpdateNotification() {
         return () => clearInterval(intervalId);
     }, [checkUpdates]);
 
+    const installingLabel = () => {
+        if (downloadProgress && downloadProgress.total) {
+            const percent = Math.min(100, Math.round((downloadProgress.downloaded / downloadProgress.total) * 100));
+            return `Downloading... ${percent}%`;
+        }
+        return downloadProgress ? 'Downloading...' : 'Installing...';
+    };
+
     // Don't show if dismissed or no update available
     if (dismissed || !updateInfo || !updateInfo.available) {
         return null;
@@ -43,7 +74,9 @@ function UpdateNotification() {
                             <details>
                                 <summary>What's New</summary>
                                 <div className="changelog-content">
-                                    {updateInfo.changelog}
+                                    {updateInfo.changelogSections
+                                        ? <ChangelogSections sections={updateInfo.changelogSections} />
+                                        : updateInfo.changelog}
                                 </div>
                             </details>
                         </div>
@@ -52,7 +85,7 @@ function UpdateNotification() {
                 <div className="update-notification-actions">
                     {installing ? (
                         <button className="btn btn-primary btn-small" disabled>
-                            Installing...
+                            {installingLabel()}
                         </button>
                     ) : (
                         <>
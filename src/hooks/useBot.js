@@ -1,4 +1,5 @@
 import { useState, useEffect } from 'react';
+import { listen } from '@tauri-apps/api/event';
 import { getBotStatus, startBot as apiStartBot, stopBot as apiStopBot, restartBot as apiRestartBot, getStartupError } from '../tauriApi';
 
 /**
@@ -14,20 +15,32 @@ function useBot() {
     useEffect(() => {
         loadBotStatus();
 
-        // Poll for status updates every 2 seconds
-        const interval = setInterval(loadBotStatus, 2000);
+        // Update immediately whenever the backend reports a status transition
+        const unlistenPromise = listen('bot-status-changed', (event) => {
+            applyStatus(event.payload.status);
+        });
 
-        return () => clearInterval(interval);
+        // Slow poll so a bot that crashes on its own is still detected
+        const interval = setInterval(loadBotStatus, 10000);
+
+        return () => {
+            clearInterval(interval);
+            unlistenPromise.then(unlisten => unlisten());
+        };
     }, []);
 
+    const applyStatus = (status) => {
+        setBotStatus({
+            online: status === 'running',
+            isRunning: status === 'running',
+            status: status // Include the full status: 'running', 'stopping', 'stopped'
+        });
+    };
+
     const loadBotStatus = async () => {
         try {
             const status = await getBotStatus();
-            setBotStatus({
-                online: status === 'running',
-                isRunning: status === 'running',
-                status: status // Include the full status: 'running', 'stopping', 'stopped'
-            });
+            applyStatus(status);
         } catch (error) {
             console.error('Failed to load bot status:', error);
             setBotStatus({
@@ -1,38 +1,64 @@
-import { useState, useEffect } from 'react';
-import { getLogs } from '../tauriApi';
+import { useState, useEffect, useRef } from 'react';
+import { getLogs, getLogsSince, clearLogs as apiClearLogs } from '../tauriApi';
+
+// Normalize log levels to lowercase for consistency with UI
+const normalizeLogs = (entries) => entries.map(log => ({
+    ...log,
+    level: log.level.toLowerCase()
+}));
 
 /**
  * Custom hook for managing application logs
  */
 function useLogs() {
     const [logs, setLogs] = useState([]);
+    // Byte offset into the log file; null until the initial load is done
+    const offsetRef = useRef(null);
 
     useEffect(() => {
         loadLogs();
 
         // Poll for new logs every 1 second
-        const interval = setInterval(loadLogs, 1000);
+        const interval = setInterval(pollLogs, 1000);
 
         return () => clearInterval(interval);
     }, []);
 
     const loadLogs = async () => {
-        console.log('[useLogs] loadLogs called');
         try {
             const newLogs = await getLogs(500);
-            console.log('[useLogs] Got logs:', newLogs);
-            // Normalize log levels to lowercase for consistency with UI
-            const normalizedLogs = newLogs.map(log => ({
-                ...log,
-                level: log.level.toLowerCase()
-            }));
-            setLogs(normalizedLogs);
+            setLogs(normalizeLogs(newLogs));
+            offsetRef.current = (await getLogsSince(null)).offset;
+        } catch (error) {
+            console.error('Failed to load logs:', error);
+        }
+    };
+
+    // Only fetch what was appended since the last poll
+    const pollLogs = async () => {
+        if (offsetRef.current === null) {
+            return;
+        }
+
+        try {
+            const { entries, offset } = await getLogsSince(offsetRef.current);
+            offsetRef.current = offset;
+            if (entries.length > 0) {
+                setLogs(prev => [...prev, ...normalizeLogs(entries)].slice(-1000));
+            }
         } catch (error) {
             console.error('Failed to load logs:', error);
         }
     };
 
-    const clearLogs = () => {
+    const clearLogs = async () => {
+        try {
+            await apiClearLogs();
+            // The file is empty now, so keep polling from the start
+            offsetRef.current = 0;
+        } catch (error) {
+            console.error('Failed to clear log file:', error);
+        }
         setLogs([]);
     };
 
@@ -2,9 +2,9 @@ import { useState, useEffect } from 'react';
 import { getStats } from '../tauriApi';
 
 /**
- * Custom hook for managing database statistics with optional season filtering
+ * Custom hook for managing database statistics with optional season/region filtering
  */
-function useStats(season = null) {
+function useStats(season = null, region = null) {
     const [stats, setStats] = useState({
         totalCharacters: 0,
         totalRuns: 0,
@@ -20,13 +20,13 @@ function useStats(season = null) {
         const interval = setInterval(loadStats, 30000);
 
         return () => clearInterval(interval);
-    }, [season]); // Re-load when season changes
+    }, [season, region]); // Re-load when the filters change
 
     const loadStats = async () => {
-        console.log('[useStats] loadStats called with season:', season);
+        console.log('[useStats] loadStats called with season:', season, 'region:', region);
         try {
             setLoading(true);
-            const result = await getStats(season);
+            const result = await getStats(season, region);
             console.log('[useStats] Got stats:', result);
             setStats(result);
         } catch (error) {
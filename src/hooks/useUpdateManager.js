@@ -1,6 +1,7 @@
 import { useState, useEffect, useCallback } from 'react';
-import { checkForUpdates, installUpdate } from '../tauriApi';
+import { checkForUpdates, installUpdate, confirmRestart } from '../tauriApi';
 import { ask } from '@tauri-apps/plugin-dialog';
+import { listen } from '@tauri-apps/api/event';
 
 /**
  * Global update manager state
@@ -9,6 +10,7 @@ let globalUpdateState = {
     updateInfo: null,
     checking: false,
     installing: false,
+    downloadProgress: null,
     error: null,
     dismissed: false,
     listeners: new Set()
@@ -29,6 +31,53 @@ function updateGlobalState(updates) {
     notifyListeners();
 }
 
+let eventsRegistered = false;
+
+/**
+ * Subscribe once to the backend's update events; install_update returns as soon
+ * as the download starts, so everything after that arrives here
+ */
+function registerUpdateEvents() {
+    if (eventsRegistered) {
+        return;
+    }
+    eventsRegistered = true;
+
+    listen('update-download-progress', (event) => {
+        updateGlobalState({ downloadProgress: event.payload });
+    });
+
+    listen('update-downloaded', async (event) => {
+        updateGlobalState({ installing: false, downloadProgress: null });
+
+        // Without autoRestartAfterUpdate the backend waits for us to confirm
+        if (!event.payload.restartPending) {
+            return;
+        }
+
+        const restartNow = await ask(`Update v${event.payload.version} is downloaded. Restart DaeBot now to install it?`, {
+            title: 'DaeBot',
+            kind: 'info',
+            okLabel: 'Restart',
+            cancelLabel: 'Later'
+        });
+        if (restartNow) {
+            await confirmRestart().catch(err => {
+                console.error('[UpdateManager] Error restarting:', err);
+                updateGlobalState({ error: err.message || String(err) });
+            });
+        }
+    });
+
+    listen('update-failed', (event) => {
+        updateGlobalState({
+            error: String(event.payload),
+            installing: false,
+            downloadProgress: null
+        });
+    });
+}
+
 /**
  * Custom hook for managing application updates
  * Provides shared state across all components
@@ -37,6 +86,8 @@ function useUpdateManager() {
     const [state, setState] = useState(globalUpdateState);
 
     useEffect(() => {
+        registerUpdateEvents();
+
         // Register listener
         const listener = (newState) => setState({ ...newState });
         globalUpdateState.listeners.add(listener);
@@ -79,11 +130,11 @@ function useUpdateManager() {
             return;
         }
 
-        updateGlobalState({ installing: true, error: null });
+        updateGlobalState({ installing: true, downloadProgress: null, error: null });
 
         try {
+            // Returns once the download starts; see registerUpdateEvents
             await installUpdate();
-            // App will restart automatically
         } catch (err) {
             console.error('[UpdateManager] Error installing update:', err);
             updateGlobalState({
@@ -110,6 +161,7 @@ function useUpdateManager() {
         updateInfo: state.updateInfo,
         checking: state.checking,
         installing: state.installing,
+        downloadProgress: state.downloadProgress,
         error: state.error,
         dismissed: state.dismissed,
         checkUpdates,
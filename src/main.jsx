@@ -2,10 +2,14 @@ import React from 'react';
 import ReactDOM from 'react-dom/client';
 import './mockApi'; // Install mock API for browser testing
 import App from './App';
+import { loadTheme } from './theme';
 import './styles/App.css';
 
-ReactDOM.createRoot(document.getElementById('root')).render(
-    <React.StrictMode>
-        <App />
-    </React.StrictMode>
-);
+// Apply the saved theme before the first render so the page never paints in the wrong palette
+loadTheme().finally(() => {
+    ReactDOM.createRoot(document.getElementById('root')).render(
+        <React.StrictMode>
+            <App />
+        </React.StrictMode>
+    );
+});
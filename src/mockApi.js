@@ -53,7 +53,7 @@ const mockApi = {
                 token: '',
                 clientId: '123456789',
                 guildId: '987654321',
-                tokenChannel: '',
+                tokenChannel: {},
                 characters: [
                     { name: 'Daemourne', realm: 'thrall', region: 'us' },
                     { name: 'TestChar', realm: 'area-52', region: 'us' }
@@ -9,6 +9,24 @@ export async function saveSettings(settings) {
     return await invoke('save_settings', { settings });
 }
 
+// Saved theme preference plus the light/dark palette it resolves to
+export async function getTheme() {
+    return await invoke('get_theme');
+}
+
+export async function isFirstRun() {
+    return await invoke('is_first_run');
+}
+
+export async function completeFirstRun() {
+    return await invoke('complete_first_run');
+}
+
+// type is one of Playing, Streaming, Listening, Watching, Competing
+export async function setBotPresence(text, type) {
+    return await invoke('set_bot_presence', { text, activityType: type });
+}
+
 // Config API
 export async function getConfig() {
     return await invoke('get_config');
@@ -18,11 +36,44 @@ export async function saveConfig(config) {
     return await invoke('save_config', { config });
 }
 
+// Asks a running bot to pick up saved config; { acknowledged, restartRequired, message }
+export async function reloadBotConfig() {
+    return await invoke('reload_bot_config');
+}
+
+// WoW token channels keyed by region, plus 'default'
+export async function getTokenChannels() {
+    return await invoke('get_token_channels');
+}
+
+export async function setTokenChannels(channels) {
+    return await invoke('set_token_channels', { channels });
+}
+
+// Both return the updated character list; duplicates are matched case-insensitively
+export async function addCharacter(character) {
+    return await invoke('add_character', { character });
+}
+
+export async function removeCharacter(name, realm, region) {
+    return await invoke('remove_character', { name, realm, region });
+}
+
+// { exists, name, realm, characterClass, level } from the Blizzard profile API
+export async function verifyCharacter(character) {
+    return await invoke('verify_character', { character });
+}
+
+export async function importGuildRoster(realm, region, guildName) {
+    return await invoke('import_guild_roster', { realm, region, guildName });
+}
+
 // Bot Control API
 export async function startBot() {
     return await invoke('start_bot');
 }
 
+// Returns immediately; the bot gets shutdownGraceSecs to exit cleanly before being killed
 export async function stopBot() {
     return await invoke('stop_bot');
 }
@@ -31,18 +82,74 @@ export async function getBotStatus() {
     return await invoke('get_bot_status');
 }
 
+// Seconds the bot has been running, or null when stopped
+export async function getBotUptime() {
+    return await invoke('get_bot_uptime');
+}
+
+// Exit code of the last bot run (0 = clean shutdown), null while running
+export async function getLastExitCode() {
+    return await invoke('get_last_exit_code');
+}
+
+// Recent bot output lines; new lines arrive as 'bot-output' events
+export async function getBotOutput(limit = null) {
+    return await invoke('get_bot_output', { limit });
+}
+
+export async function killHungBot() {
+    return await invoke('kill_hung_bot');
+}
+
+// Waits for the old process to exit before starting; fails if it won't die
 export async function restartBot() {
-    await stopBot();
-    // Wait a moment before restarting
-    await new Promise(resolve => setTimeout(resolve, 1000));
-    return await startBot();
+    return await invoke('restart_bot');
 }
 
 // Sync operations removed - automatic periodic sync is sufficient
 
+// Pausing skips the bot's scheduled syncs but keeps it connected to Discord
+export async function pauseSync() {
+    return await invoke('pause_sync');
+}
+
+export async function resumeSync() {
+    return await invoke('resume_sync');
+}
+
+export async function getSyncPaused() {
+    return await invoke('get_sync_paused');
+}
+
 // Logs API
-export async function getLogs(limit = 100) {
-    return await invoke('get_logs', { limit });
+// minLevel (TRACE, DEBUG, INFO, WARN, ERROR) keeps that severity and above
+export async function getLogs(limit = null, minLevel = null) {
+    return await invoke('get_logs', { limit, minLevel });
+}
+
+// { error, warn, info, debug, lastErrorAt } for the current log file (end of it if large)
+export async function getLogSummary() {
+    return await invoke('get_log_summary');
+}
+
+// Truncates the current log file (copying it aside first with archive); returns bytes cleared
+export async function clearLogs(archive = false) {
+    return await invoke('clear_logs', { archive });
+}
+
+// Case-insensitive search of log messages and metadata, newest matches last
+export async function searchLogs(query, limit = null, includeRotated = null) {
+    return await invoke('search_logs', { query, limit, includeRotated });
+}
+
+// { entries, offset } written since byteOffset; pass null first to start at the end
+export async function getLogsSince(byteOffset = null) {
+    return await invoke('get_logs_since', { byteOffset });
+}
+
+// ERROR/WARN counts and top recurring messages from the last sinceMinutes
+export async function getErrorSummary(sinceMinutes = null) {
+    return await invoke('get_error_summary', { sinceMinutes });
 }
 
 // Startup error check
@@ -50,33 +157,136 @@ export async function getStartupError() {
     return await invoke('get_startup_error');
 }
 
+// False while startup is still creating AppData files; see initialization-progress events
+export async function isInitializationComplete() {
+    return await invoke('is_initialization_complete');
+}
+
+// Problems startup setup reported with initialization-complete, if any
+export async function getInitializationError() {
+    return await invoke('get_initialization_error');
+}
+
+// Checks that bundled deploy/command files and bot.exe are intact
+export async function verifyResources() {
+    return await invoke('verify_resources');
+}
+
 // Stats API
-export async function getStats(season = null) {
-    return await invoke('get_stats', { season });
+export async function getStats(season = null, region = null) {
+    return await invoke('get_stats', { season, region });
 }
 
 export async function getAvailableSeasons() {
     return await invoke('get_available_seasons');
 }
 
+// Regions that have runs, for the dashboard region filter
+export async function getRegions() {
+    return await invoke('get_regions');
+}
+
+export async function getSeasonBreakdown() {
+    return await invoke('get_season_breakdown');
+}
+
+export async function getCharacterRuns(name, realm, region, season = null, limit = null) {
+    return await invoke('get_character_runs', { name, realm, region, season, limit });
+}
+
+// Full row for one run (affixes, duration, spec, character); ids come from getCharacterRuns
+export async function getRunDetail(runId) {
+    return await invoke('get_run_detail', { runId });
+}
+
+// Run count, average key and timed rate per affix combination
+export async function getAffixStats(season = null) {
+    return await invoke('get_affix_stats', { season });
+}
+
+// Run count, best key and average score (scored runs only) per dungeon
+export async function getDungeonStats(season = null) {
+    return await invoke('get_dungeon_stats', { season });
+}
+
+export async function getRunsTimeline(startTimestamp, endTimestamp, season = null) {
+    return await invoke('get_runs_timeline', { startTimestamp, endTimestamp, season });
+}
+
+// Database diagnostics
+export async function getDatabaseSchema() {
+    return await invoke('get_database_schema');
+}
+
+// 'ok', or one problem per line (corruption reported by SQLite, missing tables)
+export async function checkDatabaseIntegrity() {
+    return await invoke('check_database_integrity');
+}
+
+// VACUUMs the database (bot must be stopped); { sizeBefore, sizeAfter } in bytes
+export async function optimizeDatabase() {
+    return await invoke('optimize_database');
+}
+
 // App Control API
 export async function quitApp() {
     return await invoke('quit_app');
 }
 
+export async function hideToTray() {
+    return await invoke('hide_to_tray');
+}
+
+export async function showWindow() {
+    return await invoke('show_window');
+}
+
+export async function minimizeWindow() {
+    return await invoke('minimize_window');
+}
+
 // Update API
 export async function checkForUpdates() {
     return await invoke('check_for_updates');
 }
 
+export async function getUpdaterConfig() {
+    return await invoke('get_updater_config');
+}
+
+// Newest release notes for the current channel, even if already installed
+export async function getLatestReleaseNotes() {
+    return await invoke('get_latest_release_notes');
+}
+
+// { online, latencyMs, error } from a quick request to GitHub
+export async function checkConnectivity() {
+    return await invoke('check_connectivity');
+}
+
+// Starts the download and returns; watch update-download-progress,
+// update-downloaded and update-failed for the outcome
 export async function installUpdate() {
     return await invoke('install_update');
 }
 
+// Installs the downloaded update and restarts into it
+export async function confirmRestart() {
+    return await invoke('confirm_restart');
+}
+
+export async function getUpdateHistory() {
+    return await invoke('get_update_history');
+}
+
 export async function getAppVersion() {
     return await invoke('get_app_version');
 }
 
+export async function getPaths() {
+    return await invoke('get_paths');
+}
+
 // Blizzard API credentials
 export async function getBlizzardCredentials() {
     return await invoke('get_blizzard_credentials');
@@ -86,16 +296,72 @@ export async function saveBlizzardCredentials(credentials) {
     return await invoke('save_blizzard_credentials', { credentials });
 }
 
+// First-run setup (all-or-nothing)
+export async function applyInitialSetup(config, blizzardCredentials = null, botSettings = null) {
+    return await invoke('apply_initial_setup', { config, blizzardCredentials, botSettings });
+}
+
 // Database import
 export async function importDatabase(filePath) {
     return await invoke('import_database', { filePath });
 }
 
+// Returns the number of bytes written; the destination folder must already exist
+export async function exportDatabase(destPath) {
+    return await invoke('export_database', { destPath });
+}
+
+// Copy with character names and realms replaced, safe to share for bug reports
+export async function exportAnonymizedDatabase(destPath) {
+    return await invoke('export_anonymized_database', { destPath });
+}
+
+export async function listDatabaseBackups() {
+    return await invoke('list_database_backups');
+}
+
+// Bot must be stopped; the current database is backed up before being replaced
+export async function restoreDatabaseBackup(backupName) {
+    return await invoke('restore_database_backup', { backupName });
+}
+
+// Single zip with config, settings, .env, database and commands for moving machines
+export async function exportBackup(destPath) {
+    return await invoke('export_backup', { destPath });
+}
+
+// Bot must be stopped; current files are zipped into data/ before being replaced
+export async function importBackup(srcPath) {
+    return await invoke('import_backup', { srcPath });
+}
+
 // Sync history
-export async function getSyncHistory(limit = 10) {
+
+// Sent instead of an ISO string for corrupt or far-future timestamps
+export const INVALID_TIMESTAMP = 'invalid timestamp';
+export async function getSyncHistory(limit = null) {
     return await invoke('get_sync_history', { limit });
 }
 
+// Diagnostics: times the dashboard queries and reports missing mythic_runs indexes
+export async function benchmarkDatabase() {
+    return await invoke('benchmark_database');
+}
+
+// Repairs drifted tables (e.g. an old sync_history); returns what was changed
+export async function migrateDatabase() {
+    return await invoke('migrate_database');
+}
+
+// Creates missing mythic_runs indexes; returns the names of any that were added
+export async function ensureIndexes() {
+    return await invoke('ensure_indexes');
+}
+
+export async function detectConcurrentBots(windowHours = null) {
+    return await invoke('detect_concurrent_bots', { windowHours });
+}
+
 export async function addSyncHistory(entry) {
     return await invoke('add_sync_history', { entry });
 }
@@ -109,21 +375,58 @@ export async function getBotSettings() {
     return await invoke('get_bot_settings');
 }
 
+export async function getBotSettingsRaw() {
+    return await invoke('get_bot_settings_raw');
+}
+
+export async function getTokenAlertStatus() {
+    return await invoke('get_token_alert_status');
+}
+
+// [{ timestamp, price }] oldest first; the newest `limit` prices (default 500)
+export async function getTokenPrices(limit = null) {
+    return await invoke('get_token_prices', { limit });
+}
+
+// { nextSync, secondsRemaining, ... } for the report schedule, or null if none is set
+export async function getNextSyncEstimate() {
+    return await invoke('get_next_sync_estimate');
+}
+
 export async function updateBotSettings(settings) {
     return await invoke('update_bot_settings', { settings });
 }
 
+export async function resetBotSettings() {
+    return await invoke('reset_bot_settings');
+}
+
 // Discord command deployment
-export async function deployDiscordCommands() {
-    return await invoke('deploy_discord_commands');
+// Fails with a timeout error if Discord doesn't answer within timeoutSecs (default 60);
+// global registers for every server the bot is in instead of the configured guild
+export async function deployDiscordCommands(timeoutSecs = null, global = false) {
+    return await invoke('deploy_discord_commands', { timeoutSecs, global });
 }
 
 export async function deleteDiscordCommands() {
     return await invoke('delete_discord_commands');
 }
 
-export async function copyCommandsFolder() {
-    return await invoke('copy_commands_folder');
+export async function checkChannelAccess(channelId) {
+    return await invoke('check_channel_access', { channelId });
+}
+
+// { id, username } of the bot if Discord accepts the stored token
+export async function validateDiscordToken() {
+    return await invoke('validate_discord_token');
+}
+
+export async function listBotGuilds() {
+    return await invoke('list_bot_guilds');
+}
+
+export async function copyCommandsFolder(clean = false) {
+    return await invoke('copy_commands_folder', { clean });
 }
 
 // Developer Tools
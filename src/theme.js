@@ -0,0 +1,28 @@
+import { getTheme } from './tauriApi';
+
+const systemDark = window.matchMedia('(prefers-color-scheme: dark)');
+let followSystem = false;
+
+// App.css keys its palette off data-theme on <html>
+function setPalette(effective) {
+    document.documentElement.dataset.theme = effective;
+}
+
+systemDark.addEventListener('change', (e) => {
+    if (followSystem) {
+        setPalette(e.matches ? 'dark' : 'light');
+    }
+});
+
+// Re-reads the saved theme from the backend and applies it to the page
+export async function loadTheme() {
+    try {
+        const { preference, effective } = await getTheme();
+        followSystem = preference === 'System';
+        setPalette(effective);
+    } catch (error) {
+        // Browser/mock mode has no backend; keep the dark default
+        console.warn('Failed to load theme:', error);
+        setPalette('dark');
+    }
+}
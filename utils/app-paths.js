@@ -157,6 +157,66 @@ function getCsvLogsPath() {
     }
 }
 
+/**
+ * Get the presence.json file path (bot activity written by the desktop app)
+ * @returns {string} presence.json file path
+ */
+function getPresencePath() {
+    if (isRunningFromTauriApp()) {
+        return path.join(getDaeBotAppData(), 'presence.json');
+    } else {
+        return path.join(getProjectRoot(), 'presence.json');
+    }
+}
+
+/**
+ * Get the sync-paused flag path (created by the desktop app to pause syncing)
+ * @returns {string} sync-paused file path
+ */
+function getSyncPausedPath() {
+    if (isRunningFromTauriApp()) {
+        return path.join(getDaeBotAppData(), 'sync-paused');
+    } else {
+        return path.join(getProjectRoot(), 'sync-paused');
+    }
+}
+
+/**
+ * Get the reload-config.json path (written by the desktop app to request a config reload)
+ * @returns {string} reload-config.json file path
+ */
+function getReloadConfigPath() {
+    if (isRunningFromTauriApp()) {
+        return path.join(getDaeBotAppData(), 'reload-config.json');
+    } else {
+        return path.join(getProjectRoot(), 'reload-config.json');
+    }
+}
+
+/**
+ * Get the reload-config-ack.json path (written by the bot once a reload is done)
+ * @returns {string} reload-config-ack.json file path
+ */
+function getReloadConfigAckPath() {
+    if (isRunningFromTauriApp()) {
+        return path.join(getDaeBotAppData(), 'reload-config-ack.json');
+    } else {
+        return path.join(getProjectRoot(), 'reload-config-ack.json');
+    }
+}
+
+/**
+ * Get the shutdown-requested path (written by the desktop app to stop the bot cleanly)
+ * @returns {string} shutdown-requested file path
+ */
+function getShutdownRequestPath() {
+    if (isRunningFromTauriApp()) {
+        return path.join(getDaeBotAppData(), 'shutdown-requested');
+    } else {
+        return path.join(getProjectRoot(), 'shutdown-requested');
+    }
+}
+
 module.exports = {
     isRunningFromTauriApp,
     getAppDataPath,
@@ -166,5 +226,10 @@ module.exports = {
     getEnvPath,
     getDataPath,
     getLogsPath,
-    getCsvLogsPath
+    getCsvLogsPath,
+    getPresencePath,
+    getSyncPausedPath,
+    getReloadConfigPath,
+    getReloadConfigAckPath,
+    getShutdownRequestPath
 };